@@ -27,7 +27,7 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, EnsureOrigin},
+	traits::{Contains, Currency, EnsureOrigin},
 	transactional,
 	weights::constants::WEIGHT_PER_SECOND,
 	RuntimeDebug,
@@ -83,6 +83,10 @@ pub mod module {
 		/// Evm Bridge for getting info of contracts from the EVM.
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
 
+		/// Currencies still referenced elsewhere, e.g. by DEX trading pairs or fee pools. An
+		/// ERC20 asset can't be deregistered or migrated while it is in use.
+		type AssetInUse: Contains<CurrencyId>;
+
 		/// Required origin for registering asset.
 		type RegisterOrigin: EnsureOrigin<Self::Origin>;
 
@@ -116,6 +120,10 @@ pub mod module {
 		AssetIdNotExists,
 		/// AssetId exists
 		AssetIdExisted,
+		/// The decimals of the new ERC20 contract differ from the registered one
+		Erc20DecimalsMismatch,
+		/// The asset is still used by DEX trading pairs or fee pools
+		AssetInUse,
 	}
 
 	#[pallet::event]
@@ -143,6 +151,14 @@ pub mod module {
 			asset_id: AssetIds,
 			metadata: AssetMetadata<BalanceOf<T>>,
 		},
+		/// The asset deregistered.
+		AssetDeregistered { asset_id: AssetIds },
+		/// The ERC20 asset moved to a new contract address.
+		Erc20AssetMigrated {
+			old_contract: EvmAddress,
+			new_contract: EvmAddress,
+			metadata: AssetMetadata<BalanceOf<T>>,
+		},
 	}
 
 	/// Next available Foreign AssetId ID.
@@ -304,6 +320,42 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		#[pallet::weight(T::WeightInfo::deregister_erc20_asset())]
+		#[transactional]
+		pub fn deregister_erc20_asset(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			Self::do_deregister_erc20_asset(contract)?;
+
+			Self::deposit_event(Event::<T>::AssetDeregistered {
+				asset_id: AssetIds::Erc20(contract),
+			});
+			Ok(())
+		}
+
+		/// Move a registered ERC20 asset to a redeployed contract.
+		///
+		/// The metadata of `new_contract` is read from the EVM, its decimals must match
+		/// the registered asset and the minimal balance is kept.
+		#[pallet::weight(T::WeightInfo::migrate_erc20_asset())]
+		#[transactional]
+		pub fn migrate_erc20_asset(
+			origin: OriginFor<T>,
+			old_contract: EvmAddress,
+			new_contract: EvmAddress,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let metadata = Self::do_migrate_erc20_asset(old_contract, new_contract)?;
+
+			Self::deposit_event(Event::<T>::Erc20AssetMigrated {
+				old_contract,
+				new_contract,
+				metadata,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -428,6 +480,8 @@ impl<T: Config> Pallet<T> {
 			decimals: T::EVMBridge::decimals(invoke_context)?,
 			minimal_balance,
 		};
+		// ensure the contract implements the rest of the ERC20 interface used by currencies.
+		T::EVMBridge::total_supply(invoke_context)?;
 
 		let erc20_id = Into::<Erc20Id>::into(DexShare::Erc20(contract));
 
@@ -456,6 +510,38 @@ impl<T: Config> Pallet<T> {
 			Ok(())
 		})
 	}
+
+	fn do_deregister_erc20_asset(contract: EvmAddress) -> Result<AssetMetadata<BalanceOf<T>>, DispatchError> {
+		ensure!(
+			!T::AssetInUse::contains(&CurrencyId::Erc20(contract)),
+			Error::<T>::AssetInUse
+		);
+
+		let metadata = AssetMetadatas::<T>::take(AssetIds::Erc20(contract)).ok_or(Error::<T>::AssetIdNotExists)?;
+
+		let erc20_id = Into::<Erc20Id>::into(DexShare::Erc20(contract));
+		Erc20IdToAddress::<T>::mutate_exists(erc20_id, |maybe_address| {
+			if *maybe_address == Some(contract) {
+				*maybe_address = None;
+			}
+		});
+
+		Ok(metadata)
+	}
+
+	fn do_migrate_erc20_asset(
+		old_contract: EvmAddress,
+		new_contract: EvmAddress,
+	) -> Result<AssetMetadata<BalanceOf<T>>, DispatchError> {
+		let old_metadata = Self::do_deregister_erc20_asset(old_contract)?;
+		let metadata = Self::do_register_erc20_asset(new_contract, old_metadata.minimal_balance)?;
+		ensure!(
+			metadata.decimals == old_metadata.decimals,
+			Error::<T>::Erc20DecimalsMismatch
+		);
+
+		Ok(metadata)
+	}
 }
 
 pub struct AssetIdMaps<T>(sp_std::marker::PhantomData<T>);
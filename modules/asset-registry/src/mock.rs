@@ -22,8 +22,10 @@
 
 use crate as asset_registry;
 use frame_support::{
-	assert_ok, construct_runtime, ord_parameter_types, pallet_prelude::GenesisBuild, parameter_types,
-	traits::Everything,
+	assert_ok, construct_runtime, ord_parameter_types,
+	pallet_prelude::GenesisBuild,
+	parameter_types,
+	traits::{Contains, Everything},
 };
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockAddressMapping, AddressMapping};
@@ -31,7 +33,8 @@ use primitives::{
 	convert_decimals_to_evm, evm::EvmAddress, AccountId, Balance, CurrencyId, ReserveIdentifier, TokenSymbol,
 };
 use sp_core::{bytes::from_hex, H160, H256};
-use std::str::FromStr;
+use sp_runtime::traits::Identity;
+use std::{cell::RefCell, str::FromStr};
 
 parameter_types!(
 	pub const SomeConst: u64 = 10;
@@ -123,6 +126,11 @@ impl module_evm::Config for Runtime {
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
 	type TreasuryAccount = TreasuryAccount;
+	type StorageRentPerBytePerBlock = ();
+	type StorageRentGracePeriod = ();
+	type BaseFeePerGas = ();
+	type BaseFeeMultiplierUpdate = Identity;
+	type OnChargeBaseFee = ();
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
@@ -133,9 +141,32 @@ impl module_evm::Config for Runtime {
 }
 
 impl module_evm_bridge::Config for Runtime {
+	type Event = Event;
 	type EVM = EVM;
 }
 
+thread_local! {
+	static ASSETS_IN_USE: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
+}
+
+pub struct MockAssetInUse;
+impl MockAssetInUse {
+	pub fn set_in_use(currency_id: CurrencyId, in_use: bool) {
+		ASSETS_IN_USE.with(|v| {
+			let mut assets = v.borrow_mut();
+			assets.retain(|c| *c != currency_id);
+			if in_use {
+				assets.push(currency_id);
+			}
+		});
+	}
+}
+impl Contains<CurrencyId> for MockAssetInUse {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		ASSETS_IN_USE.with(|v| v.borrow().contains(currency_id))
+	}
+}
+
 parameter_types! {
 	pub const KSMCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
 }
@@ -144,6 +175,7 @@ impl asset_registry::Config for Runtime {
 	type Currency = Balances;
 	type LiquidCroadloanCurrencyId = KSMCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
+	type AssetInUse = MockAssetInUse;
 	type RegisterOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type WeightInfo = ();
 }
@@ -161,7 +193,7 @@ construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		AssetRegistry: asset_registry::{Pallet, Call, Event<T>, Storage},
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>},
-		EVMBridge: module_evm_bridge::{Pallet},
+		EVMBridge: module_evm_bridge::{Pallet, Event<T>},
 	}
 );
 
@@ -24,7 +24,8 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
 	alice, deploy_contracts, deploy_contracts_same_prefix, erc20_address, erc20_address_not_exists,
-	erc20_address_same_prefix, AssetRegistry, CouncilAccount, Event, ExtBuilder, Origin, Runtime, System,
+	erc20_address_same_prefix, AssetRegistry, CouncilAccount, Event, ExtBuilder, MockAssetInUse, Origin, Runtime,
+	System,
 };
 use primitives::TokenSymbol;
 use sp_core::H160;
@@ -598,6 +599,187 @@ fn update_erc20_asset_should_not_work() {
 	});
 }
 
+#[test]
+fn deregister_erc20_asset_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1
+			));
+
+			assert_ok!(AssetRegistry::deregister_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+			));
+
+			System::assert_last_event(Event::AssetRegistry(crate::Event::AssetDeregistered {
+				asset_id: AssetIds::Erc20(erc20_address()),
+			}));
+
+			assert_eq!(Erc20IdToAddress::<Runtime>::get(0x5dddfce5), None);
+			assert_eq!(AssetMetadatas::<Runtime>::get(AssetIds::Erc20(erc20_address())), None);
+
+			// can be registered again
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1
+			));
+		});
+}
+
+#[test]
+fn deregister_erc20_asset_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::deregister_erc20_asset(Origin::signed(CouncilAccount::get()), erc20_address()),
+			Error::<Runtime>::AssetIdNotExists
+		);
+	});
+}
+
+#[test]
+fn deregister_erc20_asset_in_use_should_not_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1
+			));
+
+			MockAssetInUse::set_in_use(CurrencyId::Erc20(erc20_address()), true);
+			assert_noop!(
+				AssetRegistry::deregister_erc20_asset(Origin::signed(CouncilAccount::get()), erc20_address()),
+				Error::<Runtime>::AssetInUse
+			);
+
+			MockAssetInUse::set_in_use(CurrencyId::Erc20(erc20_address()), false);
+			assert_ok!(AssetRegistry::deregister_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+			));
+		});
+}
+
+#[test]
+fn migrate_erc20_asset_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			deploy_contracts_same_prefix();
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				5
+			));
+
+			assert_ok!(AssetRegistry::migrate_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				erc20_address_same_prefix(),
+			));
+
+			let metadata = AssetMetadata {
+				name: b"long string name, long string name, long string name, long string name, long string name"
+					.to_vec(),
+				symbol: b"TestToken".to_vec(),
+				decimals: 17,
+				minimal_balance: 5,
+			};
+			System::assert_last_event(Event::AssetRegistry(crate::Event::Erc20AssetMigrated {
+				old_contract: erc20_address(),
+				new_contract: erc20_address_same_prefix(),
+				metadata: metadata.clone(),
+			}));
+
+			assert_eq!(
+				Erc20IdToAddress::<Runtime>::get(0x5dddfce5),
+				Some(erc20_address_same_prefix())
+			);
+			assert_eq!(AssetMetadatas::<Runtime>::get(AssetIds::Erc20(erc20_address())), None);
+			assert_eq!(
+				AssetMetadatas::<Runtime>::get(AssetIds::Erc20(erc20_address_same_prefix())),
+				Some(metadata)
+			);
+		});
+}
+
+#[test]
+fn migrate_erc20_asset_should_not_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_noop!(
+				AssetRegistry::migrate_erc20_asset(
+					Origin::signed(CouncilAccount::get()),
+					erc20_address(),
+					erc20_address_same_prefix(),
+				),
+				Error::<Runtime>::AssetIdNotExists
+			);
+
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1
+			));
+			assert_noop!(
+				AssetRegistry::migrate_erc20_asset(
+					Origin::signed(CouncilAccount::get()),
+					erc20_address(),
+					erc20_address_not_exists(),
+				),
+				module_evm_bridge::Error::<Runtime>::InvalidReturnValue,
+			);
+		});
+}
+
+#[test]
+fn migrate_erc20_asset_should_not_work_when_in_use() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			deploy_contracts_same_prefix();
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1
+			));
+
+			MockAssetInUse::set_in_use(CurrencyId::Erc20(erc20_address()), true);
+			assert_noop!(
+				AssetRegistry::migrate_erc20_asset(
+					Origin::signed(CouncilAccount::get()),
+					erc20_address(),
+					erc20_address_same_prefix(),
+				),
+				Error::<Runtime>::AssetInUse
+			);
+
+			MockAssetInUse::set_in_use(CurrencyId::Erc20(erc20_address()), false);
+			assert_ok!(AssetRegistry::migrate_erc20_asset(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address(),
+				erc20_address_same_prefix(),
+			));
+		});
+}
+
 #[test]
 fn name_works() {
 	ExtBuilder::default()
@@ -52,6 +52,8 @@ pub trait WeightInfo {
 	fn update_stable_asset() -> Weight;
 	fn register_erc20_asset() -> Weight;
 	fn update_erc20_asset() -> Weight;
+	fn deregister_erc20_asset() -> Weight;
+	fn migrate_erc20_asset() -> Weight;
 }
 
 /// Weights for module_asset_registry using the Acala node and recommended hardware.
@@ -87,6 +89,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn deregister_erc20_asset() -> Weight {
+		(21_953_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn migrate_erc20_asset() -> Weight {
+		(41_286_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -121,4 +133,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn deregister_erc20_asset() -> Weight {
+		(21_953_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn migrate_erc20_asset() -> Weight {
+		(41_286_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
 }
@@ -740,6 +740,11 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 			frame_system::Pallet::<T>::dec_consumers(bidder);
 		}
 	}
+
+	fn on_losing_bid(_id: AuctionId, _bid: (T::AccountId, Balance)) {
+		// collateral auctions are never candle auctions, each bid refunds the last
+		// one in `collateral_auction_bid_handler`
+	}
 }
 
 impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
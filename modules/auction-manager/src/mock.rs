@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	construct_runtime, ord_parameter_types, parameter_types,
-	traits::{Everything, Nothing},
+	traits::{Everything, Nothing, Randomness},
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
@@ -32,7 +32,7 @@ use primitives::{TokenSymbol, TradingPair};
 use sp_core::H256;
 use sp_runtime::{
 	testing::{Header, TestXt},
-	traits::{AccountIdConversion, IdentityLookup, One as OneT},
+	traits::{AccountIdConversion, BlakeTwo256, Hash, IdentityLookup, One as OneT},
 };
 use sp_std::cell::RefCell;
 pub use support::Price;
@@ -89,6 +89,10 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -97,15 +101,28 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
+pub struct MockRandomness;
+impl Randomness<H256, u64> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), 0)
+	}
+}
+
 impl orml_auction::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
 	type AuctionId = AuctionId;
 	type Handler = AuctionManagerModule;
+	type Randomness = MockRandomness;
 	type WeightInfo = ();
 }
 
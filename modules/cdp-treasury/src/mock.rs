@@ -85,6 +85,10 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -93,7 +97,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
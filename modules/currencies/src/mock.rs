@@ -30,7 +30,7 @@ use primitives::{convert_decimals_to_evm, CurrencyId, ReserveIdentifier, TokenSy
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{AccountIdConversion, IdentityLookup},
+	traits::{AccountIdConversion, Identity, IdentityLookup},
 	AccountId32, Perbill,
 };
 use support::{mocks::MockAddressMapping, AddressMapping};
@@ -172,6 +172,11 @@ impl module_evm::Config for Runtime {
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
 	type TreasuryAccount = TreasuryAccount;
+	type StorageRentPerBytePerBlock = ();
+	type StorageRentGracePeriod = ();
+	type BaseFeePerGas = ();
+	type BaseFeeMultiplierUpdate = Identity;
+	type OnChargeBaseFee = ();
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
@@ -182,6 +187,7 @@ impl module_evm::Config for Runtime {
 }
 
 impl module_evm_bridge::Config for Runtime {
+	type Event = Event;
 	type EVM = EVM;
 }
 
@@ -216,7 +222,7 @@ frame_support::construct_runtime!(
 		Tokens: tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Currencies: currencies::{Pallet, Call, Event<T>},
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>},
-		EVMBridge: module_evm_bridge::{Pallet},
+		EVMBridge: module_evm_bridge::{Pallet, Event<T>},
 	}
 );
 
@@ -34,7 +34,7 @@
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{log, pallet_prelude::*, traits::Contains, transactional, PalletId};
 use frame_system::pallet_prelude::*;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId, TradingPair};
@@ -254,6 +254,13 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// Number of trading pairs of a currency which are not disabled or still have liquidity.
+	///
+	/// TradingPairCount: map CurrencyId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn trading_pair_count)]
+	pub type TradingPairCount<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), T::BlockNumber)>,
@@ -277,6 +284,7 @@ pub mod module {
 		fn build(&self) {
 			self.initial_listing_trading_pairs.iter().for_each(
 				|(trading_pair, min_contribution, target_provision, not_before)| {
+					let was_in_use = <Pallet<T>>::is_trading_pair_in_use(trading_pair);
 					TradingPairStatuses::<T>::insert(
 						trading_pair,
 						TradingPairStatus::Provisioning(ProvisioningParameters {
@@ -286,11 +294,14 @@ pub mod module {
 							not_before: *not_before,
 						}),
 					);
+					<Pallet<T>>::update_trading_pair_count(*trading_pair, was_in_use);
 				},
 			);
 
 			self.initial_enabled_trading_pairs.iter().for_each(|trading_pair| {
+				let was_in_use = <Pallet<T>>::is_trading_pair_in_use(trading_pair);
 				TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+				<Pallet<T>>::update_trading_pair_count(*trading_pair, was_in_use);
 			});
 
 			self.initial_added_liquidity_pools
@@ -535,6 +546,7 @@ pub mod module {
 				)
 			};
 
+			let was_in_use = Self::is_trading_pair_in_use(&trading_pair);
 			TradingPairStatuses::<T>::insert(
 				trading_pair,
 				TradingPairStatus::Provisioning(ProvisioningParameters {
@@ -544,6 +556,7 @@ pub mod module {
 					not_before,
 				}),
 			);
+			Self::update_trading_pair_count(trading_pair, was_in_use);
 			Self::deposit_event(Event::ListProvisioning { trading_pair });
 			Ok(())
 		}
@@ -697,7 +710,9 @@ pub mod module {
 				TradingPairStatus::<_, _>::Enabled => return Err(Error::<T>::AlreadyEnabled.into()),
 			}
 
+			let was_in_use = Self::is_trading_pair_in_use(&trading_pair);
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+			Self::update_trading_pair_count(trading_pair, was_in_use);
 			Self::deposit_event(Event::EnableTradingPair { trading_pair });
 			Ok(())
 		}
@@ -722,6 +737,8 @@ pub mod module {
 			);
 
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Disabled);
+			// an enabled pair was in use, it stays in use while it has liquidity
+			Self::update_trading_pair_count(trading_pair, true);
 			Self::deposit_event(Event::DisableTradingPair { trading_pair });
 			Ok(())
 		}
@@ -733,6 +750,36 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
+	/// Whether the trading pair is not disabled or still has liquidity.
+	fn is_trading_pair_in_use(trading_pair: &TradingPair) -> bool {
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		!matches!(
+			Self::trading_pair_statuses(trading_pair),
+			TradingPairStatus::<_, _>::Disabled
+		) || !pool_0.is_zero()
+			|| !pool_1.is_zero()
+	}
+
+	/// Updates `TradingPairCount` of both currencies of the trading pair after its status or
+	/// liquidity changed, `was_in_use` is whether it was in use before the change.
+	fn update_trading_pair_count(trading_pair: TradingPair, was_in_use: bool) {
+		let is_in_use = Self::is_trading_pair_in_use(&trading_pair);
+		if is_in_use == was_in_use {
+			return;
+		}
+		for currency_id in [trading_pair.first(), trading_pair.second()] {
+			TradingPairCount::<T>::mutate_exists(currency_id, |maybe_count| {
+				let count = maybe_count.unwrap_or_default();
+				let count = if is_in_use {
+					count.saturating_add(1)
+				} else {
+					count.saturating_sub(1)
+				};
+				*maybe_count = Some(count).filter(|count| !count.is_zero());
+			});
+		}
+	}
+
 	fn do_claim_dex_share(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
@@ -984,6 +1031,7 @@ impl<T: Config> Pallet<T> {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 		let dex_share_currency_id = trading_pair.dex_share_currency_id();
+		let was_in_use = Self::is_trading_pair_in_use(&trading_pair);
 
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
 			let (min_withdrawn_0, min_withdrawn_1) = if currency_id_a == trading_pair.first() {
@@ -1022,7 +1070,10 @@ impl<T: Config> Pallet<T> {
 				share_decrement: remove_share,
 			});
 			Ok(())
-		})
+		})?;
+
+		Self::update_trading_pair_count(trading_pair, was_in_use);
+		Ok(())
 	}
 
 	fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
@@ -1255,6 +1306,14 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+/// Currencies that are part of a trading pair which is not disabled or still has liquidity.
+pub struct TradingPairCurrencies<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Contains<CurrencyId> for TradingPairCurrencies<T> {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		TradingPairCount::<T>::contains_key(currency_id)
+	}
+}
+
 impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		Self::get_liquidity(currency_id_a, currency_id_b)
@@ -406,6 +406,72 @@ fn end_provisioning_trading_work() {
 	});
 }
 
+#[test]
+fn trading_pair_currencies_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(!TradingPairCurrencies::<Runtime>::contains(&AUSD));
+		assert!(!TradingPairCurrencies::<Runtime>::contains(&DOT));
+
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC
+		));
+		assert_eq!(DexModule::trading_pair_count(AUSD), 2);
+		assert_eq!(DexModule::trading_pair_count(DOT), 1);
+		assert!(TradingPairCurrencies::<Runtime>::contains(&AUSD));
+		assert!(TradingPairCurrencies::<Runtime>::contains(&DOT));
+		assert!(TradingPairCurrencies::<Runtime>::contains(&BTC));
+		assert!(!TradingPairCurrencies::<Runtime>::contains(&ACA));
+
+		// a disabled pair still counts while its pool holds liquidity
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			5_000_000_000_000,
+			1_000_000_000_000,
+			0,
+			false
+		));
+		assert_ok!(DexModule::disable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_eq!(DexModule::trading_pair_count(DOT), 1);
+		assert!(TradingPairCurrencies::<Runtime>::contains(&DOT));
+
+		assert_ok!(DexModule::remove_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			10_000_000_000_000,
+			0,
+			0,
+			false,
+		));
+		assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
+		assert_eq!(DexModule::trading_pair_count(AUSD), 1);
+		assert!(!TradingPairCount::<Runtime>::contains_key(DOT));
+		assert!(TradingPairCurrencies::<Runtime>::contains(&AUSD));
+		assert!(!TradingPairCurrencies::<Runtime>::contains(&DOT));
+
+		assert_ok!(DexModule::disable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC
+		));
+		assert!(!TradingPairCurrencies::<Runtime>::contains(&AUSD));
+		assert!(!TradingPairCurrencies::<Runtime>::contains(&BTC));
+	});
+}
+
 #[test]
 fn disable_trading_pair_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -24,13 +24,13 @@ use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	pallet_prelude::*,
 };
-use module_evm::{ExitReason, ExitSucceed};
+use module_evm::{ExitReason, ExitSucceed, Log};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitive_types::H256;
 use sp_core::{H160, U256};
 use sp_runtime::{ArithmeticError, SaturatedConversion};
 use sp_std::vec::Vec;
-use support::{EVMBridge as EVMBridgeTrait, ExecutionMode, InvokeContext, EVM};
+use support::{EVMBridge as EVMBridgeTrait, EVMNFTBridge as EVMNFTBridgeTrait, ExecutionMode, InvokeContext, EVM};
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::EVM as EVM<AccountIdOf<T>>>::Balance;
@@ -45,6 +45,11 @@ pub enum Action {
 	TotalSupply = "totalSupply()",
 	BalanceOf = "balanceOf(address)",
 	Transfer = "transfer(address,uint256)",
+	Allowance = "allowance(address,address)",
+	Approve = "approve(address,uint256)",
+	TransferFrom = "transferFrom(address,address,uint256)",
+	OwnerOf = "ownerOf(uint256)",
+	TokenURI = "tokenURI(uint256)",
 }
 
 mod mock;
@@ -59,6 +64,8 @@ pub mod module {
 	/// EvmBridge module trait
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
 		type EVM: EVM<AccountIdOf<Self>>;
 	}
 
@@ -76,6 +83,32 @@ pub mod module {
 		InvalidReturnValue,
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// ERC20 tokens were transferred by the bridge.
+		Transfer {
+			contract: H160,
+			from: H160,
+			to: H160,
+			value: U256,
+		},
+		/// ERC20 allowance was changed by the bridge.
+		Approval {
+			contract: H160,
+			owner: H160,
+			spender: H160,
+			value: U256,
+		},
+		/// ERC721 token was transferred by the bridge.
+		NFTTransfer {
+			contract: H160,
+			from: H160,
+			to: H160,
+			token_id: U256,
+		},
+	}
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -188,16 +221,87 @@ impl<T: Config> EVMBridgeTrait<AccountIdOf<T>, BalanceOf<T>> for EVMBridge<T> {
 		)?;
 
 		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::ensure_returned_true(&info.value)?;
 
-		// return value is true.
-		let mut bytes = [0u8; 32];
-		U256::from(1).to_big_endian(&mut bytes);
+		Pallet::<T>::deposit_logs(context.contract, info.logs);
+		Ok(())
+	}
 
-		// Check return value to make sure not calling on empty contracts.
-		ensure!(
-			!info.value.is_empty() && info.value == bytes,
-			Error::<T>::InvalidReturnValue
-		);
+	// Calls the allowance method on an ERC20 contract using the given context
+	// and returns the remaining amount the spender can withdraw from the owner.
+	fn allowance(context: InvokeContext, owner: H160, spender: H160) -> Result<BalanceOf<T>, DispatchError> {
+		// ERC20.allowance method hash
+		let mut input = Into::<u32>::into(Action::Allowance).to_be_bytes().to_vec();
+		// append owner address
+		input.extend_from_slice(H256::from(owner).as_bytes());
+		// append spender address
+		input.extend_from_slice(H256::from(spender).as_bytes());
+
+		let info = T::EVM::execute(context, input, Default::default(), 2_100_000, 0, ExecutionMode::View)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		ensure!(info.value.len() == 32, Error::<T>::InvalidReturnValue);
+		let value: u128 = U256::from(info.value.as_slice())
+			.try_into()
+			.map_err(|_| ArithmeticError::Overflow)?;
+		let allowance = value.try_into().map_err(|_| ArithmeticError::Overflow)?;
+		Ok(allowance)
+	}
+
+	// Calls the approve method on an ERC20 contract using the given context.
+	fn approve(context: InvokeContext, spender: H160, value: BalanceOf<T>) -> DispatchResult {
+		// ERC20.approve method hash
+		let mut input = Into::<u32>::into(Action::Approve).to_be_bytes().to_vec();
+		// append spender address
+		input.extend_from_slice(H256::from(spender).as_bytes());
+		// append amount to be approved
+		input.extend_from_slice(H256::from_uint(&U256::from(value.saturated_into::<u128>())).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() { 0 } else { 1_000 };
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			2_100_000,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::ensure_returned_true(&info.value)?;
+
+		Pallet::<T>::deposit_logs(context.contract, info.logs);
+		Ok(())
+	}
+
+	// Calls the transferFrom method on an ERC20 contract using the given context.
+	fn transfer_from(context: InvokeContext, from: H160, to: H160, value: BalanceOf<T>) -> DispatchResult {
+		// ERC20.transferFrom method hash
+		let mut input = Into::<u32>::into(Action::TransferFrom).to_be_bytes().to_vec();
+		// append owner address
+		input.extend_from_slice(H256::from(from).as_bytes());
+		// append receiver address
+		input.extend_from_slice(H256::from(to).as_bytes());
+		// append amount to be transferred
+		input.extend_from_slice(H256::from_uint(&U256::from(value.saturated_into::<u128>())).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() { 0 } else { 1_000 };
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			2_100_000,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::ensure_returned_true(&info.value)?;
+
+		Pallet::<T>::deposit_logs(context.contract, info.logs);
 		Ok(())
 	}
 
@@ -210,6 +314,109 @@ impl<T: Config> EVMBridgeTrait<AccountIdOf<T>, BalanceOf<T>> for EVMBridge<T> {
 	}
 }
 
+pub struct EVMNFTBridge<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> EVMNFTBridgeTrait for EVMNFTBridge<T> {
+	// Calls the name method on an ERC721 contract using the given context
+	// and returns the collection name.
+	fn name(context: InvokeContext) -> Result<Vec<u8>, DispatchError> {
+		// ERC721.name method hash
+		let input = Into::<u32>::into(Action::Name).to_be_bytes().to_vec();
+
+		let info = T::EVM::execute(context, input, Default::default(), 2_100_000, 0, ExecutionMode::View)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::decode_string(info.value.as_slice().to_vec())
+	}
+
+	// Calls the symbol method on an ERC721 contract using the given context
+	// and returns the collection symbol.
+	fn symbol(context: InvokeContext) -> Result<Vec<u8>, DispatchError> {
+		// ERC721.symbol method hash
+		let input = Into::<u32>::into(Action::Symbol).to_be_bytes().to_vec();
+
+		let info = T::EVM::execute(context, input, Default::default(), 2_100_000, 0, ExecutionMode::View)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::decode_string(info.value.as_slice().to_vec())
+	}
+
+	// Calls the balanceOf method on an ERC721 contract using the given context
+	// and returns the number of tokens owned by the address.
+	fn balance_of(context: InvokeContext, owner: H160) -> Result<U256, DispatchError> {
+		// ERC721.balanceOf method hash
+		let mut input = Into::<u32>::into(Action::BalanceOf).to_be_bytes().to_vec();
+		// append owner address
+		input.extend_from_slice(H256::from(owner).as_bytes());
+
+		let info = T::EVM::execute(context, input, Default::default(), 2_100_000, 0, ExecutionMode::View)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		ensure!(info.value.len() == 32, Error::<T>::InvalidReturnValue);
+		Ok(U256::from(info.value.as_slice()))
+	}
+
+	// Calls the ownerOf method on an ERC721 contract using the given context
+	// and returns the owner of the token.
+	fn owner_of(context: InvokeContext, token_id: U256) -> Result<H160, DispatchError> {
+		// ERC721.ownerOf method hash
+		let mut input = Into::<u32>::into(Action::OwnerOf).to_be_bytes().to_vec();
+		// append token id
+		input.extend_from_slice(H256::from_uint(&token_id).as_bytes());
+
+		let info = T::EVM::execute(context, input, Default::default(), 2_100_000, 0, ExecutionMode::View)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		ensure!(info.value.len() == 32, Error::<T>::InvalidReturnValue);
+		Ok(H160::from(H256::from_slice(info.value.as_slice())))
+	}
+
+	// Calls the tokenURI method on an ERC721 contract using the given context
+	// and returns the metadata URI of the token.
+	fn token_uri(context: InvokeContext, token_id: U256) -> Result<Vec<u8>, DispatchError> {
+		// ERC721.tokenURI method hash
+		let mut input = Into::<u32>::into(Action::TokenURI).to_be_bytes().to_vec();
+		// append token id
+		input.extend_from_slice(H256::from_uint(&token_id).as_bytes());
+
+		let info = T::EVM::execute(context, input, Default::default(), 2_100_000, 0, ExecutionMode::View)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::decode_string(info.value.as_slice().to_vec())
+	}
+
+	// Calls the transferFrom method on an ERC721 contract using the given context.
+	fn transfer_from(context: InvokeContext, from: H160, to: H160, token_id: U256) -> DispatchResult {
+		// ERC721.transferFrom method hash
+		let mut input = Into::<u32>::into(Action::TransferFrom).to_be_bytes().to_vec();
+		// append owner address
+		input.extend_from_slice(H256::from(from).as_bytes());
+		// append receiver address
+		input.extend_from_slice(H256::from(to).as_bytes());
+		// append token id
+		input.extend_from_slice(H256::from_uint(&token_id).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() { 0 } else { 1_000 };
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			2_100_000,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		// ERC721.transferFrom does not return a value.
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		Pallet::<T>::deposit_logs(context.contract, info.logs);
+		Ok(())
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	fn handle_exit_reason(exit_reason: ExitReason) -> Result<(), DispatchError> {
 		match exit_reason {
@@ -246,4 +453,62 @@ impl<T: Config> Pallet<T> {
 
 		Ok(data.to_vec())
 	}
+
+	fn ensure_returned_true(output: &[u8]) -> DispatchResult {
+		// return value is true.
+		let mut bytes = [0u8; 32];
+		U256::from(1).to_big_endian(&mut bytes);
+
+		// Check return value to make sure not calling on empty contracts.
+		ensure!(!output.is_empty() && output == bytes, Error::<T>::InvalidReturnValue);
+		Ok(())
+	}
+
+	/// Decodes the `Transfer` and `Approval` logs emitted by `contract` into
+	/// module events. Logs emitted by other contracts are ignored.
+	pub fn decode_logs(contract: H160, logs: &[Log]) -> Vec<Event<T>> {
+		let transfer_topic = H256::from(sp_io::hashing::keccak_256(b"Transfer(address,address,uint256)"));
+		let approval_topic = H256::from(sp_io::hashing::keccak_256(b"Approval(address,address,uint256)"));
+
+		logs.iter()
+			.filter(|log| log.address == contract && !log.topics.is_empty())
+			.filter_map(|log| match log.topics.len() {
+				// ERC20: the value is not indexed and is passed in data.
+				3 if log.data.len() == 32 => {
+					let value = U256::from(log.data.as_slice());
+					if log.topics[0] == transfer_topic {
+						Some(Event::Transfer {
+							contract,
+							from: H160::from(log.topics[1]),
+							to: H160::from(log.topics[2]),
+							value,
+						})
+					} else if log.topics[0] == approval_topic {
+						Some(Event::Approval {
+							contract,
+							owner: H160::from(log.topics[1]),
+							spender: H160::from(log.topics[2]),
+							value,
+						})
+					} else {
+						None
+					}
+				}
+				// ERC721: the token id is indexed.
+				4 if log.topics[0] == transfer_topic => Some(Event::NFTTransfer {
+					contract,
+					from: H160::from(log.topics[1]),
+					to: H160::from(log.topics[2]),
+					token_id: log.topics[3].into_uint(),
+				}),
+				_ => None,
+			})
+			.collect()
+	}
+
+	fn deposit_logs(contract: H160, logs: Vec<Log>) {
+		for event in Self::decode_logs(contract, &logs) {
+			Self::deposit_event(event);
+		}
+	}
 }
@@ -25,7 +25,10 @@ use frame_support::{assert_ok, construct_runtime, ord_parameter_types, parameter
 use frame_system::EnsureSignedBy;
 use primitives::{convert_decimals_to_evm, evm::EvmAddress, ReserveIdentifier};
 use sp_core::{bytes::from_hex, crypto::AccountId32, H256};
-use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_runtime::{
+	testing::Header,
+	traits::{Identity, IdentityLookup},
+};
 use sp_std::str::FromStr;
 use support::{mocks::MockAddressMapping, AddressMapping};
 
@@ -128,6 +131,11 @@ impl module_evm::Config for Runtime {
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
 	type TreasuryAccount = TreasuryAccount;
+	type StorageRentPerBytePerBlock = ();
+	type StorageRentGracePeriod = ();
+	type BaseFeePerGas = ();
+	type BaseFeeMultiplierUpdate = Identity;
+	type OnChargeBaseFee = ();
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
@@ -138,6 +146,7 @@ impl module_evm::Config for Runtime {
 }
 
 impl Config for Runtime {
+	type Event = Event;
 	type EVM = EVM;
 }
 
@@ -151,7 +160,7 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
-		EVMBridge: evm_bridge::{Pallet},
+		EVMBridge: evm_bridge::{Pallet, Event<T>},
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
@@ -171,6 +180,10 @@ pub fn erc20_address() -> EvmAddress {
 	EvmAddress::from_str("5dddfce53ee040d9eb21afbc0ae1bb4dbb0ba643").unwrap()
 }
 
+pub fn erc721_address() -> EvmAddress {
+	EvmAddress::from_str("930141fd5e0cdf1f12987dc153ec6aa5cbedfde4").unwrap()
+}
+
 pub fn alice() -> AccountId {
 	<Runtime as module_evm::Config>::AddressMapping::get_account_id(&alice_evm_addr())
 }
@@ -208,6 +221,32 @@ pub fn deploy_contracts() {
 	assert_ok!(EVM::deploy_free(Origin::signed(CouncilAccount::get()), erc20_address()));
 }
 
+pub fn deploy_erc721_contract() {
+	let code = from_hex(include!("./erc721_demo_contract")).unwrap();
+	assert_ok!(EVM::create(Origin::signed(bob()), code, 0, 2_100_000, 10000));
+
+	let mint_log = |token_id: u64| module_evm::Log {
+		address: erc721_address(),
+		topics: vec![
+			H256::from_str("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").unwrap(),
+			H256::from_str("0x0000000000000000000000000000000000000000000000000000000000000000").unwrap(),
+			H256::from_str("0x0000000000000000000000001000000000000000000000000000000000000002").unwrap(),
+			H256::from_low_u64_be(token_id),
+		],
+		data: vec![],
+	};
+	System::assert_last_event(Event::EVM(module_evm::Event::Created {
+		from: bob_evm_addr(),
+		contract: erc721_address(),
+		logs: vec![mint_log(1), mint_log(2)],
+	}));
+
+	assert_ok!(EVM::deploy_free(
+		Origin::signed(CouncilAccount::get()),
+		erc721_address()
+	));
+}
+
 impl ExtBuilder {
 	pub fn balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
 		self.balances = balances;
@@ -22,7 +22,10 @@
 
 use super::*;
 use frame_support::{assert_err, assert_ok};
-use mock::{alice, alice_evm_addr, bob, bob_evm_addr, deploy_contracts, erc20_address, ExtBuilder, Runtime};
+use mock::{
+	alice, alice_evm_addr, bob, bob_evm_addr, deploy_contracts, deploy_erc721_contract, erc20_address, erc721_address,
+	Event, ExtBuilder, Runtime, System,
+};
 
 #[test]
 fn should_read_name() {
@@ -197,3 +200,320 @@ fn should_transfer() {
 			);
 		});
 }
+
+#[test]
+fn should_deposit_transfer_event() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_ok!(EVMBridge::<Runtime>::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				bob_evm_addr(),
+				100
+			));
+			System::assert_last_event(Event::EVMBridge(crate::Event::Transfer {
+				contract: erc20_address(),
+				from: alice_evm_addr(),
+				to: bob_evm_addr(),
+				value: 100.into(),
+			}));
+		});
+}
+
+#[test]
+fn should_approve_and_transfer_from() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000), (bob(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let context = InvokeContext {
+				contract: erc20_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(0)
+			);
+
+			// bob can't pull tokens without an allowance
+			assert_err!(
+				EVMBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc20_address(),
+						sender: bob_evm_addr(),
+						origin: bob_evm_addr(),
+					},
+					alice_evm_addr(),
+					bob_evm_addr(),
+					10
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+
+			assert_ok!(EVMBridge::<Runtime>::approve(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				bob_evm_addr(),
+				100
+			));
+			System::assert_last_event(Event::EVMBridge(crate::Event::Approval {
+				contract: erc20_address(),
+				owner: alice_evm_addr(),
+				spender: bob_evm_addr(),
+				value: 100.into(),
+			}));
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(100)
+			);
+
+			assert_ok!(EVMBridge::<Runtime>::transfer_from(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: bob_evm_addr(),
+					origin: bob_evm_addr(),
+				},
+				alice_evm_addr(),
+				bob_evm_addr(),
+				60
+			));
+			System::assert_has_event(Event::EVMBridge(crate::Event::Transfer {
+				contract: erc20_address(),
+				from: alice_evm_addr(),
+				to: bob_evm_addr(),
+				value: 60.into(),
+			}));
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(40)
+			);
+			assert_eq!(EVMBridge::<Runtime>::balance_of(context, bob_evm_addr()), Ok(60));
+			assert_eq!(EVMBridge::<Runtime>::balance_of(context, alice_evm_addr()), Ok(9940));
+
+			// exceeds the remaining allowance
+			assert_err!(
+				EVMBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc20_address(),
+						sender: bob_evm_addr(),
+						origin: bob_evm_addr(),
+					},
+					alice_evm_addr(),
+					bob_evm_addr(),
+					41
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+		});
+}
+
+#[test]
+fn should_decode_logs() {
+	let transfer_topic = H256::from(sp_io::hashing::keccak_256(b"Transfer(address,address,uint256)"));
+	let approval_topic = H256::from(sp_io::hashing::keccak_256(b"Approval(address,address,uint256)"));
+	let log = |address: H160, topics: Vec<H256>| Log {
+		address,
+		topics,
+		data: H256::from_low_u64_be(100).as_bytes().to_vec(),
+	};
+	let logs = vec![
+		log(
+			erc20_address(),
+			vec![transfer_topic, alice_evm_addr().into(), bob_evm_addr().into()],
+		),
+		log(
+			erc20_address(),
+			vec![approval_topic, alice_evm_addr().into(), bob_evm_addr().into()],
+		),
+		// emitted by another contract
+		log(
+			alice_evm_addr(),
+			vec![transfer_topic, alice_evm_addr().into(), bob_evm_addr().into()],
+		),
+		// unknown event
+		log(
+			erc20_address(),
+			vec![H256::repeat_byte(1), alice_evm_addr().into(), bob_evm_addr().into()],
+		),
+		// ERC721 transfer with an indexed token id
+		log(
+			erc20_address(),
+			vec![
+				transfer_topic,
+				alice_evm_addr().into(),
+				bob_evm_addr().into(),
+				H256::from_low_u64_be(100),
+			],
+		),
+	];
+
+	assert_eq!(
+		Pallet::<Runtime>::decode_logs(erc20_address(), &logs),
+		vec![
+			crate::Event::Transfer {
+				contract: erc20_address(),
+				from: alice_evm_addr(),
+				to: bob_evm_addr(),
+				value: 100.into(),
+			},
+			crate::Event::Approval {
+				contract: erc20_address(),
+				owner: alice_evm_addr(),
+				spender: bob_evm_addr(),
+				value: 100.into(),
+			},
+			crate::Event::NFTTransfer {
+				contract: erc20_address(),
+				from: alice_evm_addr(),
+				to: bob_evm_addr(),
+				token_id: 100.into(),
+			},
+		]
+	);
+}
+
+#[test]
+fn should_read_nft_metadata() {
+	ExtBuilder::default()
+		.balances(vec![(bob(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_erc721_contract();
+			let context = InvokeContext {
+				contract: erc721_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			assert_eq!(EVMNFTBridge::<Runtime>::name(context), Ok(b"Demo NFT".to_vec()));
+			assert_eq!(EVMNFTBridge::<Runtime>::symbol(context), Ok(b"DNFT".to_vec()));
+			assert_eq!(
+				EVMNFTBridge::<Runtime>::balance_of(context, bob_evm_addr()),
+				Ok(2.into())
+			);
+			assert_eq!(
+				EVMNFTBridge::<Runtime>::balance_of(context, alice_evm_addr()),
+				Ok(0.into())
+			);
+		});
+}
+
+#[test]
+fn should_read_owner_of_and_token_uri() {
+	ExtBuilder::default()
+		.balances(vec![(bob(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_erc721_contract();
+			let context = InvokeContext {
+				contract: erc721_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			assert_eq!(EVMNFTBridge::<Runtime>::owner_of(context, 1.into()), Ok(bob_evm_addr()));
+			assert_eq!(EVMNFTBridge::<Runtime>::owner_of(context, 2.into()), Ok(bob_evm_addr()));
+			assert_eq!(
+				EVMNFTBridge::<Runtime>::token_uri(context, 1.into()),
+				Ok(b"demo://token/1".to_vec())
+			);
+			assert_eq!(
+				EVMNFTBridge::<Runtime>::token_uri(context, 2.into()),
+				Ok(b"demo://token/2".to_vec())
+			);
+
+			// unknown token
+			assert_err!(
+				EVMNFTBridge::<Runtime>::owner_of(context, 3.into()),
+				Error::<Runtime>::ExecutionRevert
+			);
+			assert_err!(
+				EVMNFTBridge::<Runtime>::token_uri(context, 3.into()),
+				Error::<Runtime>::ExecutionRevert
+			);
+		});
+}
+
+#[test]
+fn should_transfer_nft_from() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000), (bob(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_erc721_contract();
+			let context = InvokeContext {
+				contract: erc721_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+
+			// alice can't move bob's token
+			assert_err!(
+				EVMNFTBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc721_address(),
+						sender: alice_evm_addr(),
+						origin: alice_evm_addr(),
+					},
+					bob_evm_addr(),
+					alice_evm_addr(),
+					1.into()
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+
+			assert_ok!(EVMNFTBridge::<Runtime>::transfer_from(
+				InvokeContext {
+					contract: erc721_address(),
+					sender: bob_evm_addr(),
+					origin: bob_evm_addr(),
+				},
+				bob_evm_addr(),
+				alice_evm_addr(),
+				1.into()
+			));
+			System::assert_last_event(Event::EVMBridge(crate::Event::NFTTransfer {
+				contract: erc721_address(),
+				from: bob_evm_addr(),
+				to: alice_evm_addr(),
+				token_id: 1.into(),
+			}));
+			assert_eq!(
+				EVMNFTBridge::<Runtime>::owner_of(context, 1.into()),
+				Ok(alice_evm_addr())
+			);
+			assert_eq!(EVMNFTBridge::<Runtime>::owner_of(context, 2.into()), Ok(bob_evm_addr()));
+			assert_eq!(
+				EVMNFTBridge::<Runtime>::balance_of(context, alice_evm_addr()),
+				Ok(1.into())
+			);
+			assert_eq!(
+				EVMNFTBridge::<Runtime>::balance_of(context, bob_evm_addr()),
+				Ok(1.into())
+			);
+
+			// bob no longer owns the token
+			assert_err!(
+				EVMNFTBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc721_address(),
+						sender: bob_evm_addr(),
+						origin: bob_evm_addr(),
+					},
+					bob_evm_addr(),
+					alice_evm_addr(),
+					1.into()
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+		});
+}
@@ -50,4 +50,8 @@ pub struct EstimateResourcesResponse {
 	pub storage: i32,
 	/// Adjusted weight fee
 	pub weight_fee: U256,
+	/// Base fee per gas
+	pub base_fee_per_gas: U256,
+	/// Suggested total fee, weight fee plus base fee of the estimated gas
+	pub suggested_fee: U256,
 }
@@ -24,7 +24,7 @@ use jsonrpc_core::{Error, ErrorCode, Result, Value};
 use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
 use rustc_hex::ToHex;
 use sc_rpc_api::DenyUnsafe;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::{Bytes, Decode};
 use sp_rpc::number::NumberOrHex;
@@ -406,11 +406,28 @@ where
 		let adjusted_weight_fee = fee
 			.inclusion_fee
 			.map_or_else(Default::default, |inclusion| inclusion.adjusted_weight_fee);
+		let weight_fee: U256 = adjusted_weight_fee.into();
+
+		let api = self.client.runtime_api();
+		let api_version = api
+			.api_version::<dyn EVMRuntimeRPCApi<B, Balance>>(&BlockId::Hash(hash))
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+			.ok_or_else(|| internal_err("EVMRuntimeRPCApi not found"))?;
+		// the base fee is only available since version 2
+		let base_fee_per_gas: U256 = if api_version >= 2 {
+			api.base_fee_per_gas(&BlockId::Hash(hash))
+				.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+				.into()
+		} else {
+			U256::zero()
+		};
 
 		Ok(EstimateResourcesResponse {
 			gas: highest,
 			storage: used_storage,
-			weight_fee: adjusted_weight_fee.into(),
+			weight_fee,
+			base_fee_per_gas,
+			suggested_fee: weight_fee.saturating_add(highest.saturating_mul(base_fee_per_gas)),
 		})
 	}
 }
@@ -40,8 +40,8 @@ use frame_support::{
 	pallet_prelude::*,
 	parameter_types,
 	traits::{
-		BalanceStatus, Currency, EnsureOrigin, ExistenceRequirement, FindAuthor, Get, NamedReservableCurrency,
-		OnKilledAccount,
+		BalanceStatus, Currency, EnsureOrigin, ExistenceRequirement, FindAuthor, Get, Imbalance,
+		NamedReservableCurrency, OnKilledAccount, OnUnbalanced, WithdrawReasons,
 	},
 	transactional,
 	weights::{Pays, PostDispatchInfo, Weight},
@@ -63,7 +63,7 @@ use primitive_types::{H160, H256, U256};
 pub use primitives::{
 	convert_decimals_from_evm, convert_decimals_to_evm,
 	evm::{
-		CallInfo, CreateInfo, EvmAddress, ExecutionInfo, Vicinity, MIRRORED_NFT_ADDRESS_START,
+		is_system_contract, CallInfo, CreateInfo, EvmAddress, ExecutionInfo, Vicinity, MIRRORED_NFT_ADDRESS_START,
 		MIRRORED_TOKENS_ADDRESS_START,
 	},
 	task::TaskResult,
@@ -76,10 +76,11 @@ use sha3::{Digest, Keccak256};
 use sp_io::KillStorageResult::{AllRemoved, SomeRemaining};
 use sp_runtime::{
 	traits::{
-		Convert, DispatchInfoOf, One, PostDispatchInfoOf, Saturating, SignedExtension, UniqueSaturatedInto, Zero,
+		CheckedDiv, Convert, DispatchInfoOf, One, PostDispatchInfoOf, Saturating, SignedExtension, UniqueSaturatedInto,
+		Zero,
 	},
 	transaction_validity::TransactionValidityError,
-	Either, TransactionOutcome,
+	ArithmeticError, Either, FixedPointNumber, FixedU128, TransactionOutcome,
 };
 use sp_std::{
 	cmp,
@@ -102,6 +103,12 @@ pub use weights::WeightInfo;
 /// Storage key size and storage value size.
 pub const STORAGE_SIZE: u32 = 64;
 
+/// Base fee multiplier.
+pub type Multiplier = FixedU128;
+
+/// Id of a scheduled EVM call.
+pub type ScheduledCallId = u64;
+
 /// Type alias for currency balance.
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 pub type NegativeImbalanceOf<T> =
@@ -229,6 +236,27 @@ pub mod module {
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
 
+		/// Rent charged per byte of contract storage per block. Zero disables the
+		/// state expiry mode.
+		#[pallet::constant]
+		type StorageRentPerBytePerBlock: Get<BalanceOf<Self>>;
+
+		/// Number of blocks after the paid rent runs out before the contract can be
+		/// archived.
+		#[pallet::constant]
+		type StorageRentGracePeriod: Get<Self::BlockNumber>;
+
+		/// Base fee per gas when the multiplier is one. Zero disables the base fee.
+		#[pallet::constant]
+		type BaseFeePerGas: Get<BalanceOf<Self>>;
+
+		/// Update the base fee multiplier from the fullness of the block, e.g.
+		/// `module_transaction_payment::TargetedFeeAdjustment`.
+		type BaseFeeMultiplierUpdate: Convert<Multiplier, Multiplier>;
+
+		/// Handler for the charged base fee. The base fee is burned if `()`.
+		type OnChargeBaseFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
 		type FreeDeploymentOrigin: EnsureOrigin<Self::Origin>;
 
 		/// EVM execution runner.
@@ -266,6 +294,38 @@ pub mod module {
 		}
 	}
 
+	#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
+	pub struct ContractTombstone {
+		/// The contract info at the time of archiving. The code is kept alive by it.
+		pub contract_info: ContractInfo,
+		/// Keccak256 hash of the encoded storage, sorted by key.
+		pub storage_root: H256,
+		/// Number of the storage items.
+		pub storage_items: u32,
+		/// The `ContractStorageSizes` value at the time of archiving.
+		pub storage_size: u32,
+	}
+
+	#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
+	pub struct ScheduledCallInfo<Balance, BlockNumber> {
+		pub from: EvmAddress,
+		pub target: EvmAddress,
+		pub input: Vec<u8>,
+		pub value: Balance,
+		pub gas_limit: u64,
+		pub storage_limit: u32,
+		/// The call is executed on idle at or after this block.
+		pub next_execution: BlockNumber,
+		/// The number of blocks between two executions.
+		pub interval: BlockNumber,
+		/// The number of executions left, including the next one.
+		pub remaining_executions: u32,
+		/// The transaction fee reserved for the next execution.
+		pub reserved_fee: Balance,
+		/// The storage deposit reserved for the next execution.
+		pub reserved_storage: Balance,
+	}
+
 	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub struct CodeInfo {
 		pub code_size: u32,
@@ -327,6 +387,37 @@ pub mod module {
 	#[pallet::getter(fn code_infos)]
 	pub type CodeInfos<T: Config> = StorageMap<_, Identity, H256, CodeInfo, OptionQuery>;
 
+	/// The block number until which the storage rent of the contract is paid. Contracts
+	/// without an entry, e.g. system contracts or contracts deployed before storage rent,
+	/// are not subject to rent.
+	///
+	/// RentPaidUntil: map EvmAddress => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn rent_paid_until)]
+	pub type RentPaidUntil<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, T::BlockNumber, OptionQuery>;
+
+	/// The tombstones of archived contracts.
+	///
+	/// ContractTombstones: map EvmAddress => Option<ContractTombstone>
+	#[pallet::storage]
+	#[pallet::getter(fn contract_tombstones)]
+	pub type ContractTombstones<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, ContractTombstone, OptionQuery>;
+
+	/// Next available scheduled call id.
+	///
+	/// NextScheduledCallId: ScheduledCallId
+	#[pallet::storage]
+	#[pallet::getter(fn next_scheduled_call_id)]
+	pub type NextScheduledCallId<T: Config> = StorageValue<_, ScheduledCallId, ValueQuery>;
+
+	/// The EVM calls scheduled by users.
+	///
+	/// ScheduledCalls: map ScheduledCallId => Option<ScheduledCallInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_calls)]
+	pub type ScheduledCalls<T: Config> =
+		StorageMap<_, Twox64Concat, ScheduledCallId, ScheduledCallInfo<BalanceOf<T>, T::BlockNumber>, OptionQuery>;
+
 	/// Next available system contract address.
 	///
 	/// NetworkContractIndex: u64
@@ -334,6 +425,18 @@ pub mod module {
 	#[pallet::getter(fn network_contract_index)]
 	pub type NetworkContractIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	#[pallet::type_value]
+	pub fn DefaultBaseFeeMultiplier() -> Multiplier {
+		Multiplier::one()
+	}
+
+	/// The multiplier of `BaseFeePerGas`, updated at the end of every block.
+	///
+	/// BaseFeeMultiplier: Multiplier
+	#[pallet::storage]
+	#[pallet::getter(fn base_fee_multiplier)]
+	pub type BaseFeeMultiplier<T: Config> = StorageValue<_, Multiplier, ValueQuery, DefaultBaseFeeMultiplier>;
+
 	/// Extrinsics origin for the current transaction.
 	///
 	/// ExtrinsicOrigin: Option<AccountId>
@@ -476,6 +579,36 @@ pub mod module {
 		ContractSetCode { contract: EvmAddress },
 		/// Selfdestructed contract code.
 		ContractSelfdestructed { contract: EvmAddress },
+		/// Storage rent of the contract has been paid.
+		RentToppedUp {
+			contract: EvmAddress,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			paid_until: T::BlockNumber,
+		},
+		/// An expired contract has been archived into a tombstone.
+		ContractArchived { contract: EvmAddress, storage_root: H256 },
+		/// An archived contract has been restored.
+		ContractRestored { contract: EvmAddress, who: T::AccountId },
+		/// An EVM call has been scheduled.
+		CallScheduled {
+			id: ScheduledCallId,
+			from: EvmAddress,
+			target: EvmAddress,
+			when: T::BlockNumber,
+			interval: T::BlockNumber,
+			executions: u32,
+		},
+		/// A scheduled EVM call has been executed.
+		ScheduledCallExecuted {
+			id: ScheduledCallId,
+			exit_reason: ExitReason,
+			used_gas: u64,
+			used_storage: i32,
+			refunded_gas: u64,
+		},
+		/// A scheduled EVM call has been cancelled.
+		ScheduledCallCancelled { id: ScheduledCallId },
 	}
 
 	#[pallet::error]
@@ -510,6 +643,24 @@ pub mod module {
 		ChargeStorageFailed,
 		/// Invalid decimals
 		InvalidDecimals,
+		/// Storage rent is disabled
+		StorageRentDisabled,
+		/// The rent is too low to pay for a single block
+		RentTooLow,
+		/// The rent of the contract has not expired
+		RentNotExpired,
+		/// The contract is not subject to storage rent
+		RentNotApplicable,
+		/// The number of storage items does not match
+		InvalidStorageWitness,
+		/// Tombstone not found
+		TombstoneNotFound,
+		/// The supplied storage does not match the tombstone
+		TombstoneMismatch,
+		/// Invalid schedule
+		InvalidSchedule,
+		/// Scheduled call not found
+		ScheduledCallNotFound,
 	}
 
 	#[pallet::pallet]
@@ -517,6 +668,17 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// `on_initialize` to return the weight used in `on_finalize`.
+		fn on_initialize(_: T::BlockNumber) -> Weight {
+			<T as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+		}
+
+		fn on_finalize(_: T::BlockNumber) {
+			BaseFeeMultiplier::<T>::mutate(|multiplier| {
+				*multiplier = T::BaseFeeMultiplierUpdate::convert(*multiplier);
+			});
+		}
+
 		fn integrity_test() {
 			assert!(convert_decimals_from_evm(T::StorageDepositPerByte::get()).is_some());
 		}
@@ -561,6 +723,7 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let source = T::AddressMapping::get_or_create_evm_address(&who);
+			let base_fee = Self::withdraw_base_fee(&who, gas_limit)?;
 
 			let info = T::Runner::call(
 				source,
@@ -574,6 +737,7 @@ pub mod module {
 			)?;
 
 			let used_gas: u64 = info.used_gas.unique_saturated_into();
+			Self::charge_base_fee(&who, base_fee, used_gas);
 
 			Ok(PostDispatchInfo {
 				actual_weight: Some(T::GasToWeight::convert(used_gas)),
@@ -659,9 +823,12 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			let source = T::AddressMapping::get_or_create_evm_address(&who);
 
+			let base_fee = Self::withdraw_base_fee(&who, gas_limit)?;
+
 			let info = T::Runner::create(source, init, value, gas_limit, storage_limit, T::config())?;
 
 			let used_gas: u64 = info.used_gas.unique_saturated_into();
+			Self::charge_base_fee(&who, base_fee, used_gas);
 
 			Ok(PostDispatchInfo {
 				actual_weight: Some(T::GasToWeight::convert(used_gas)),
@@ -690,9 +857,12 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			let source = T::AddressMapping::get_or_create_evm_address(&who);
 
+			let base_fee = Self::withdraw_base_fee(&who, gas_limit)?;
+
 			let info = T::Runner::create2(source, init, salt, value, gas_limit, storage_limit, T::config())?;
 
 			let used_gas: u64 = info.used_gas.unique_saturated_into();
+			Self::charge_base_fee(&who, base_fee, used_gas);
 
 			Ok(PostDispatchInfo {
 				actual_weight: Some(T::GasToWeight::convert(used_gas)),
@@ -778,6 +948,8 @@ pub mod module {
 			} else {
 				T::Runner::create_at_address(source, target, init, value, gas_limit, storage_limit, T::config())?
 			};
+			// predeployed contracts are not subject to rent
+			RentPaidUntil::<T>::remove(target);
 
 			let used_gas: u64 = info.used_gas.unique_saturated_into();
 
@@ -902,6 +1074,147 @@ pub mod module {
 
 			Ok(().into())
 		}
+
+		/// Pay storage rent for a contract. Anyone can pay for any contract.
+		///
+		/// The paid rent goes to the treasury and extends the rent of the contract by the
+		/// number of whole blocks it covers.
+		///
+		/// - `contract`: the contract to pay the rent for
+		/// - `amount`: the maximum amount to pay
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_rent())]
+		#[transactional]
+		pub fn top_up_rent(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let (paid, paid_until) = Self::do_top_up_rent(&who, &contract, amount)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::RentToppedUp {
+				contract,
+				who,
+				amount: paid,
+				paid_until,
+			});
+
+			Ok(().into())
+		}
+
+		/// Archive a contract whose rent has expired. The storage of the contract is
+		/// removed and a tombstone is kept so that it can be restored later. The storage
+		/// deposit of the contract is returned to its maintainer.
+		///
+		/// - `contract`: the contract to archive
+		/// - `storage_items`: the number of storage items of the contract
+		#[pallet::weight(<T as Config>::WeightInfo::archive_contract(*storage_items))]
+		#[transactional]
+		pub fn archive_contract(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			storage_items: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let storage_root = Self::do_archive_contract(&contract, storage_items)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::ContractArchived { contract, storage_root });
+
+			Ok(().into())
+		}
+
+		/// Restore an archived contract. The caller pays the storage deposit again.
+		///
+		/// - `contract`: the archived contract
+		/// - `storage`: the storage of the contract at the time it was archived
+		#[pallet::weight(<T as Config>::WeightInfo::restore_contract(storage.len() as u32))]
+		#[transactional]
+		pub fn restore_contract(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			storage: Vec<(H256, H256)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let source = T::AddressMapping::get_or_create_evm_address(&who);
+			Self::do_restore_contract(&source, &contract, storage)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::ContractRestored { contract, who });
+
+			Ok(().into())
+		}
+
+		/// Schedule an EVM call. The call is executed when the chain is idle at or after
+		/// block `when`, and then every `interval` blocks until `executions` calls are done.
+		/// The transaction fee of `gas_limit` and the storage deposit of `storage_limit` are
+		/// reserved before each execution.
+		///
+		/// - `target`: the contract address to call
+		/// - `input`: the data supplied for the call
+		/// - `value`: the amount sent for payable calls
+		/// - `gas_limit`: the maximum gas each call can use
+		/// - `storage_limit`: the total bytes the contract's storage can increase by in each call
+		/// - `when`: the block of the first execution
+		/// - `interval`: the number of blocks between two executions
+		/// - `executions`: the number of executions
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_call())]
+		#[transactional]
+		pub fn schedule_call(
+			origin: OriginFor<T>,
+			target: EvmAddress,
+			input: Vec<u8>,
+			#[pallet::compact] value: BalanceOf<T>,
+			#[pallet::compact] gas_limit: u64,
+			#[pallet::compact] storage_limit: u32,
+			when: T::BlockNumber,
+			interval: T::BlockNumber,
+			executions: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let from = T::AddressMapping::get_or_create_evm_address(&who);
+			let id = Self::do_schedule_call(
+				&who,
+				from,
+				target,
+				input,
+				value,
+				gas_limit,
+				storage_limit,
+				when,
+				interval,
+				executions,
+			)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::CallScheduled {
+				id,
+				from,
+				target,
+				when,
+				interval,
+				executions,
+			});
+
+			Ok(().into())
+		}
+
+		/// Cancel a scheduled EVM call and release its reserved fee and storage deposit.
+		///
+		/// - `id`: the id of the scheduled call
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_scheduled_call())]
+		#[transactional]
+		pub fn cancel_scheduled_call(origin: OriginFor<T>, id: ScheduledCallId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let info = ScheduledCalls::<T>::get(id).ok_or(Error::<T>::ScheduledCallNotFound)?;
+			ensure!(
+				T::AddressMapping::get_account_id(&info.from) == who,
+				Error::<T>::NoPermission
+			);
+
+			Self::do_cancel_scheduled_call(id, &info);
+
+			Pallet::<T>::deposit_event(Event::<T>::ScheduledCallCancelled { id });
+
+			Ok(().into())
+		}
 	}
 }
 
@@ -912,6 +1225,37 @@ impl<T: Config> Pallet<T> {
 		convert_decimals_from_evm(T::StorageDepositPerByte::get()).expect("checked in integrity_test; qed")
 	}
 
+	/// Get the current base fee per gas.
+	pub fn base_fee_per_gas() -> BalanceOf<T> {
+		let base_fee: u128 = T::BaseFeePerGas::get().unique_saturated_into();
+		Self::base_fee_multiplier()
+			.saturating_mul_int(base_fee)
+			.unique_saturated_into()
+	}
+
+	/// Withdraw the base fee of `gas_limit` from `who` before the execution, so that an
+	/// execution which can't be paid for is rejected upfront.
+	fn withdraw_base_fee(who: &T::AccountId, gas_limit: u64) -> Result<NegativeImbalanceOf<T>, DispatchError> {
+		let fee = Self::base_fee_per_gas().saturating_mul(gas_limit.unique_saturated_into());
+		if fee.is_zero() {
+			return Ok(NegativeImbalanceOf::<T>::zero());
+		}
+
+		T::Currency::withdraw(who, fee, WithdrawReasons::FEE, ExistenceRequirement::KeepAlive)
+			.map_err(|_| Error::<T>::ChargeFeeFailed.into())
+	}
+
+	/// Charge the base fee of the used gas out of the `withdrawn` fee and refund the rest
+	/// to `who`. The charged fee is handled by `OnChargeBaseFee`.
+	fn charge_base_fee(who: &T::AccountId, withdrawn: NegativeImbalanceOf<T>, used_gas: u64) {
+		let fee = Self::base_fee_per_gas().saturating_mul(used_gas.unique_saturated_into());
+		let (fee, refund) = withdrawn.split(fee);
+		if !refund.peek().is_zero() {
+			T::Currency::resolve_creating(who, refund);
+		}
+		T::OnChargeBaseFee::on_unbalanced(fee);
+	}
+
 	/// Check whether an account is empty.
 	pub fn is_account_empty(address: &H160) -> bool {
 		let account_id = T::AddressMapping::get_account_id(address);
@@ -959,6 +1303,7 @@ impl<T: Config> Pallet<T> {
 			});
 
 			ContractStorageSizes::<T>::take(contract);
+			RentPaidUntil::<T>::remove(contract);
 
 			T::IdleScheduler::schedule(
 				EvmTask::Remove {
@@ -1065,6 +1410,11 @@ impl<T: Config> Pallet<T> {
 			}
 		});
 
+		// system contracts are not subject to rent
+		if !is_system_contract(address) {
+			RentPaidUntil::<T>::insert(address, frame_system::Pallet::<T>::block_number());
+		}
+
 		frame_system::Pallet::<T>::inc_providers(&T::AddressMapping::get_account_id(&address));
 	}
 
@@ -1262,6 +1612,333 @@ impl<T: Config> Pallet<T> {
 		Self::remove_contract(caller, contract)
 	}
 
+	/// The storage rent of the contract per block. A contract is charged for at least one
+	/// byte, so that a contract without storage can still pay its rent before it expires.
+	pub fn rent_per_block(contract: &EvmAddress) -> BalanceOf<T> {
+		T::StorageRentPerBytePerBlock::get().saturating_mul(Self::contract_storage_sizes(contract).max(1).into())
+	}
+
+	/// Check whether the rent of the contract has expired, including the grace period.
+	/// Contracts that are not subject to rent never expire.
+	pub fn is_rent_expired(contract: &EvmAddress) -> bool {
+		Self::rent_paid_until(contract).map_or(false, |paid_until| {
+			frame_system::Pallet::<T>::block_number() > paid_until.saturating_add(T::StorageRentGracePeriod::get())
+		})
+	}
+
+	/// Root of the contract storage kept in the tombstone.
+	pub fn storage_root(mut storage: Vec<(H256, H256)>) -> H256 {
+		storage.sort_by_key(|(key, _)| *key);
+		code_hash(&storage.encode())
+	}
+
+	fn do_top_up_rent(
+		who: &T::AccountId,
+		contract: &EvmAddress,
+		amount: BalanceOf<T>,
+	) -> Result<(BalanceOf<T>, T::BlockNumber), DispatchError> {
+		ensure!(
+			!T::StorageRentPerBytePerBlock::get().is_zero(),
+			Error::<T>::StorageRentDisabled
+		);
+		ensure!(
+			Self::accounts(contract).map_or(false, |account_info| account_info.contract_info.is_some()),
+			Error::<T>::ContractNotFound
+		);
+		ensure!(
+			RentPaidUntil::<T>::contains_key(contract),
+			Error::<T>::RentNotApplicable
+		);
+
+		let rent_per_block = Self::rent_per_block(contract);
+		let blocks = amount.checked_div(&rent_per_block).unwrap_or_default();
+		ensure!(!blocks.is_zero(), Error::<T>::RentTooLow);
+		// only charge the whole blocks
+		let paid = rent_per_block.saturating_mul(blocks);
+
+		T::Currency::transfer(who, &T::TreasuryAccount::get(), paid, ExistenceRequirement::AllowDeath)?;
+
+		let blocks: u128 = blocks.unique_saturated_into();
+		let blocks: T::BlockNumber = blocks.unique_saturated_into();
+		let paid_until = RentPaidUntil::<T>::mutate(contract, |maybe_paid_until| {
+			// rent that has run out is not paid retroactively
+			let paid_until = maybe_paid_until.get_or_insert_with(Zero::zero);
+			*paid_until = cmp::max(*paid_until, frame_system::Pallet::<T>::block_number()).saturating_add(blocks);
+			*paid_until
+		});
+
+		Ok((paid, paid_until))
+	}
+
+	fn do_archive_contract(contract: &EvmAddress, storage_items: u32) -> Result<H256, DispatchError> {
+		ensure!(
+			!T::StorageRentPerBytePerBlock::get().is_zero(),
+			Error::<T>::StorageRentDisabled
+		);
+		ensure!(Self::is_rent_expired(contract), Error::<T>::RentNotExpired);
+
+		Accounts::<T>::try_mutate(contract, |maybe_account_info| -> Result<H256, DispatchError> {
+			let account_info = maybe_account_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
+			let contract_info = account_info.contract_info.take().ok_or(Error::<T>::ContractNotFound)?;
+
+			// bounded by the witness
+			let storage: Vec<(H256, H256)> = AccountStorages::<T>::iter_prefix(contract)
+				.take(storage_items.saturating_add(1) as usize)
+				.collect();
+			ensure!(
+				storage.len() == storage_items as usize,
+				Error::<T>::InvalidStorageWitness
+			);
+			let storage_root = Self::storage_root(storage);
+
+			let _ = AccountStorages::<T>::remove_prefix(contract, None);
+			let storage_size = ContractStorageSizes::<T>::take(contract);
+			RentPaidUntil::<T>::remove(contract);
+
+			// the storage deposit is returned to the maintainer
+			let contract_acc = T::AddressMapping::get_account_id(contract);
+			let maintainer_acc = T::AddressMapping::get_account_id(&contract_info.maintainer);
+			let amount = T::Currency::reserved_balance_named(&RESERVE_ID_STORAGE_DEPOSIT, &contract_acc);
+			let val = T::Currency::repatriate_reserved_named(
+				&RESERVE_ID_STORAGE_DEPOSIT,
+				&contract_acc,
+				&maintainer_acc,
+				amount,
+				BalanceStatus::Free,
+			)?;
+			debug_assert!(val.is_zero());
+
+			// the code is kept by the tombstone, the provider of the contract account is kept as well.
+			ContractTombstones::<T>::insert(
+				contract,
+				ContractTombstone {
+					contract_info,
+					storage_root,
+					storage_items,
+					storage_size,
+				},
+			);
+
+			Ok(storage_root)
+		})
+	}
+
+	fn do_restore_contract(caller: &EvmAddress, contract: &EvmAddress, storage: Vec<(H256, H256)>) -> DispatchResult {
+		let tombstone = Self::contract_tombstones(contract).ok_or(Error::<T>::TombstoneNotFound)?;
+		ensure!(
+			storage.len() == tombstone.storage_items as usize
+				&& Self::storage_root(storage.clone()) == tombstone.storage_root,
+			Error::<T>::TombstoneMismatch
+		);
+
+		Accounts::<T>::try_mutate(contract, |maybe_account_info| -> DispatchResult {
+			let account_info = maybe_account_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
+			ensure!(account_info.contract_info.is_none(), Error::<T>::ContractAlreadyExisted);
+			account_info.contract_info = Some(tombstone.contract_info);
+			Ok(())
+		})?;
+
+		Self::reserve_storage(caller, tombstone.storage_size)?;
+		Self::charge_storage(caller, contract, tombstone.storage_size as i32)?;
+
+		for (key, value) in storage {
+			AccountStorages::<T>::insert(contract, key, value);
+		}
+		ContractStorageSizes::<T>::insert(contract, tombstone.storage_size);
+		RentPaidUntil::<T>::insert(contract, frame_system::Pallet::<T>::block_number());
+		ContractTombstones::<T>::remove(contract);
+
+		Ok(())
+	}
+
+	fn do_schedule_call(
+		who: &T::AccountId,
+		from: EvmAddress,
+		target: EvmAddress,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		when: T::BlockNumber,
+		interval: T::BlockNumber,
+		executions: u32,
+	) -> Result<ScheduledCallId, DispatchError> {
+		ensure!(
+			!executions.is_zero()
+				&& when >= frame_system::Pallet::<T>::block_number()
+				&& (executions == 1 || !interval.is_zero()),
+			Error::<T>::InvalidSchedule
+		);
+
+		let id = NextScheduledCallId::<T>::try_mutate(|current| -> Result<ScheduledCallId, DispatchError> {
+			let id = *current;
+			*current = current.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			Ok(id)
+		})?;
+
+		let (reserved_fee, reserved_storage) = Self::reserve_scheduled_call(who, gas_limit, storage_limit)?;
+		ScheduledCalls::<T>::insert(
+			id,
+			ScheduledCallInfo {
+				from,
+				target,
+				input,
+				value,
+				gas_limit,
+				storage_limit,
+				next_execution: when,
+				interval,
+				remaining_executions: executions,
+				reserved_fee,
+				reserved_storage,
+			},
+		);
+		T::IdleScheduler::schedule(EvmTask::Schedule { id, when }.into())?;
+
+		Ok(id)
+	}
+
+	/// Reserve the transaction fee and the storage deposit for one execution of a scheduled
+	/// call.
+	#[transactional]
+	fn reserve_scheduled_call(
+		who: &T::AccountId,
+		gas_limit: u64,
+		storage_limit: u32,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let reserved_fee = T::ChargeTransactionPayment::reserve_fee(who, T::GasToWeight::convert(gas_limit))?;
+		let reserved_storage = Self::get_storage_deposit_per_byte().saturating_mul(storage_limit.into());
+		T::Currency::reserve_named(&RESERVE_ID_STORAGE_DEPOSIT, who, reserved_storage)?;
+		Ok((reserved_fee, reserved_storage))
+	}
+
+	fn do_cancel_scheduled_call(id: ScheduledCallId, info: &ScheduledCallInfo<BalanceOf<T>, T::BlockNumber>) {
+		let who = T::AddressMapping::get_account_id(&info.from);
+		T::ChargeTransactionPayment::unreserve_fee(&who, info.reserved_fee);
+		T::Currency::unreserve_named(&RESERVE_ID_STORAGE_DEPOSIT, &who, info.reserved_storage);
+		ScheduledCalls::<T>::remove(id);
+	}
+
+	/// Execute the scheduled call `id` if it is due at `when` and there is enough weight
+	/// left. The next execution of a recurring call is scheduled as a new task.
+	fn dispatch_scheduled_call(id: ScheduledCallId, when: T::BlockNumber, weight: Weight) -> TaskResult {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let mut info = match ScheduledCalls::<T>::get(id) {
+			Some(info) if info.next_execution == when => info,
+			// cancelled
+			_ => {
+				return TaskResult {
+					result: Ok(()),
+					used_weight: db_weight.reads(1),
+					finished: true,
+				}
+			}
+		};
+
+		if frame_system::Pallet::<T>::block_number() < when
+			|| weight < T::GasToWeight::convert(info.gas_limit).saturating_add(db_weight.reads_writes(3, 3))
+		{
+			return TaskResult {
+				result: Ok(()),
+				used_weight: db_weight.reads(1),
+				finished: false,
+			};
+		}
+
+		let (result, used_gas) = match Self::execute_scheduled_call(&info) {
+			Ok(call_info) => {
+				let used_gas: u64 = call_info.used_gas.unique_saturated_into();
+				Pallet::<T>::deposit_event(Event::<T>::ScheduledCallExecuted {
+					id,
+					exit_reason: call_info.exit_reason,
+					used_gas,
+					used_storage: call_info.used_storage,
+					refunded_gas: info.gas_limit.saturating_sub(used_gas),
+				});
+				(Ok(()), used_gas)
+			}
+			Err(e) => (Err(e), 0),
+		};
+
+		// The reserves of a successful execution have been consumed, a failed execution
+		// has been rolled back and still holds them.
+		let who = T::AddressMapping::get_account_id(&info.from);
+		let next = if result.is_ok() && info.remaining_executions > 1 {
+			Self::reserve_scheduled_call(&who, info.gas_limit, info.storage_limit).ok()
+		} else {
+			None
+		};
+
+		match next {
+			Some((reserved_fee, reserved_storage)) => {
+				info.next_execution = when.saturating_add(info.interval);
+				info.remaining_executions -= 1;
+				info.reserved_fee = reserved_fee;
+				info.reserved_storage = reserved_storage;
+				let when = info.next_execution;
+				if T::IdleScheduler::schedule(EvmTask::Schedule { id, when }.into()).is_ok() {
+					ScheduledCalls::<T>::insert(id, info);
+				} else {
+					Self::do_cancel_scheduled_call(id, &info);
+					Pallet::<T>::deposit_event(Event::<T>::ScheduledCallCancelled { id });
+				}
+			}
+			None => {
+				if result.is_err() {
+					Self::do_cancel_scheduled_call(id, &info);
+				} else {
+					ScheduledCalls::<T>::remove(id);
+				}
+				if result.is_err() || info.remaining_executions > 1 {
+					Pallet::<T>::deposit_event(Event::<T>::ScheduledCallCancelled { id });
+				}
+			}
+		}
+
+		TaskResult {
+			result,
+			used_weight: T::GasToWeight::convert(used_gas).saturating_add(db_weight.reads_writes(3, 3)),
+			finished: true,
+		}
+	}
+
+	#[transactional]
+	fn execute_scheduled_call(
+		info: &ScheduledCallInfo<BalanceOf<T>, T::BlockNumber>,
+	) -> Result<CallInfo, DispatchError> {
+		let who = T::AddressMapping::get_account_id(&info.from);
+
+		// the runner reserves the storage deposit again
+		T::Currency::unreserve_named(&RESERVE_ID_STORAGE_DEPOSIT, &who, info.reserved_storage);
+		let (_, payed) =
+			T::ChargeTransactionPayment::unreserve_and_charge_fee(&who, T::GasToWeight::convert(info.gas_limit))
+				.map_err(|_| Error::<T>::ChargeFeeFailed)?;
+		let base_fee = Self::withdraw_base_fee(&who, info.gas_limit)?;
+
+		let call_info = T::Runner::call(
+			info.from,
+			info.from,
+			info.target,
+			info.input.clone(),
+			info.value,
+			info.gas_limit,
+			info.storage_limit,
+			T::config(),
+		)?;
+
+		let used_gas: u64 = call_info.used_gas.unique_saturated_into();
+		let refund_gas = info.gas_limit.saturating_sub(used_gas);
+		if !refund_gas.is_zero() {
+			// ignore the result to continue. if it fails, just the user will not
+			// be refunded, there will not increase user balance.
+			let res = T::ChargeTransactionPayment::refund_fee(&who, T::GasToWeight::convert(refund_gas), payed);
+			debug_assert!(res.is_ok());
+		}
+		Self::charge_base_fee(&who, base_fee, used_gas);
+
+		Ok(call_info)
+	}
+
 	fn ensure_root_or_signed(o: T::Origin) -> Result<Either<(), T::AccountId>, BadOrigin> {
 		EnsureOneOf::<T::AccountId, EnsureRoot<T::AccountId>, EnsureSigned<T::AccountId>>::try_origin(o)
 			.map_or(Err(BadOrigin), Ok)
@@ -1581,14 +2258,9 @@ impl<T: Config + Send + Sync> SignedExtension for SetEvmOrigin<T> {
 
 #[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
 pub enum EvmTask<T: Config> {
-	// TODO: update
 	Schedule {
-		from: EvmAddress,
-		target: EvmAddress,
-		input: Vec<u8>,
-		value: BalanceOf<T>,
-		gas_limit: u64,
-		storage_limit: u32,
+		id: ScheduledCallId,
+		when: T::BlockNumber,
 	},
 	Remove {
 		caller: EvmAddress,
@@ -1600,15 +2272,7 @@ pub enum EvmTask<T: Config> {
 impl<T: Config> DispatchableTask for EvmTask<T> {
 	fn dispatch(self, weight: Weight) -> TaskResult {
 		match self {
-			// TODO: update
-			EvmTask::Schedule { .. } => {
-				// check weight and call `scheduled_call`
-				TaskResult {
-					result: Ok(()),
-					used_weight: 0,
-					finished: false,
-				}
-			}
+			EvmTask::Schedule { id, when } => Pallet::<T>::dispatch_scheduled_call(id, when, weight),
 			EvmTask::Remove {
 				caller,
 				contract,
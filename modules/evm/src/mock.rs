@@ -105,6 +105,10 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -113,7 +117,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -168,6 +177,16 @@ impl FindAuthor<AccountId32> for AuthorGiven {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice();
+	pub const StorageRentPerBytePerBlock: Balance = 1;
+	pub const StorageRentGracePeriod: u64 = 10;
+	pub static BaseFeePerGas: Balance = 0;
+}
+
+pub struct BaseFeeMultiplierUpdate;
+impl Convert<Multiplier, Multiplier> for BaseFeeMultiplierUpdate {
+	fn convert(previous: Multiplier) -> Multiplier {
+		previous.saturating_mul(Multiplier::saturating_from_rational(11, 10))
+	}
 }
 
 ord_parameter_types! {
@@ -201,6 +220,11 @@ impl Config for Runtime {
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
 	type TreasuryAccount = TreasuryAccount;
+	type StorageRentPerBytePerBlock = StorageRentPerBytePerBlock;
+	type StorageRentGracePeriod = StorageRentGracePeriod;
+	type BaseFeePerGas = BaseFeePerGas;
+	type BaseFeeMultiplierUpdate = BaseFeeMultiplierUpdate;
+	type OnChargeBaseFee = ();
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
 
 	type Runner = crate::runner::stack::Runner<Self>;
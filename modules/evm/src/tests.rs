@@ -1819,3 +1819,478 @@ fn convert_decimals_should_not_work() {
 		);
 	});
 }
+
+#[test]
+fn should_archive_and_restore_contract() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//     mapping(address => uint256) public values;
+	//
+	//     constructor() public {
+	//         values[msg.sender] = 42;
+	//     }
+	//
+	//     function set(uint val) public {
+	//      values[msg.sender] = val;
+	//     }
+	// }
+
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// create contract
+		let result =
+			<Runtime as Config>::Runner::create(alice(), contract, 0, 500000, 100000, <Runtime as Config>::config())
+				.unwrap();
+		let contract_address = result.value;
+
+		let storage_size = 340u32 + NewContractExtraBytes::get() + STORAGE_SIZE;
+		let storage_deposit = EVM::get_storage_deposit_per_byte() * storage_size as u128;
+		assert_eq!(EVM::rent_paid_until(&contract_address), Some(1));
+		assert_eq!(EVM::rent_per_block(&contract_address), storage_size as u128);
+		assert_eq!(reserved_balance(contract_address), storage_deposit);
+
+		assert_noop!(
+			EVM::top_up_rent(Origin::signed(alice_account_id.clone()), contract_address, 100),
+			Error::<Runtime>::RentTooLow
+		);
+
+		// only the whole blocks are charged
+		let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+		assert_ok!(EVM::top_up_rent(
+			Origin::signed(alice_account_id.clone()),
+			contract_address,
+			storage_size as u128 * 5 + 3
+		));
+		System::assert_last_event(Event::EVM(crate::Event::RentToppedUp {
+			contract: contract_address,
+			who: alice_account_id,
+			amount: storage_size as u128 * 5,
+			paid_until: 6,
+		}));
+		assert_eq!(
+			Balances::free_balance(TreasuryAccount::get()),
+			treasury_balance + storage_size as u128 * 5
+		);
+
+		System::set_block_number(16);
+		assert_noop!(
+			EVM::archive_contract(Origin::signed(bob_account_id.clone()), contract_address, 1),
+			Error::<Runtime>::RentNotExpired
+		);
+
+		System::set_block_number(17);
+		assert_noop!(
+			EVM::archive_contract(Origin::signed(bob_account_id.clone()), contract_address, 0),
+			Error::<Runtime>::InvalidStorageWitness
+		);
+
+		let storage: Vec<(H256, H256)> = AccountStorages::<Runtime>::iter_prefix(contract_address).collect();
+		assert_eq!(storage.len(), 1);
+		let storage_root = EVM::storage_root(storage.clone());
+
+		let alice_balance = balance(alice());
+		let bob_balance = balance(bob());
+		assert_ok!(EVM::archive_contract(
+			Origin::signed(bob_account_id.clone()),
+			contract_address,
+			1
+		));
+		System::assert_last_event(Event::EVM(crate::Event::ContractArchived {
+			contract: contract_address,
+			storage_root,
+		}));
+
+		// the maintainer gets the storage deposit back
+		assert_eq!(balance(alice()), alice_balance + storage_deposit);
+		assert_eq!(balance(bob()), bob_balance);
+		assert_eq!(reserved_balance(contract_address), 0);
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(contract_address).count(), 0);
+		assert_eq!(ContractStorageSizes::<Runtime>::get(contract_address), 0);
+		assert_eq!(Accounts::<Runtime>::get(contract_address).unwrap().contract_info, None);
+		let tombstone = EVM::contract_tombstones(contract_address).unwrap();
+		assert_eq!(tombstone.storage_root, storage_root);
+		assert_eq!(tombstone.storage_size, storage_size);
+		// the code is kept alive by the tombstone
+		assert!(Codes::<Runtime>::contains_key(tombstone.contract_info.code_hash));
+
+		assert_noop!(
+			EVM::restore_contract(
+				Origin::signed(bob_account_id.clone()),
+				contract_address,
+				vec![(storage[0].0, H256::from_low_u64_be(43))]
+			),
+			Error::<Runtime>::TombstoneMismatch
+		);
+
+		assert_ok!(EVM::restore_contract(
+			Origin::signed(bob_account_id.clone()),
+			contract_address,
+			storage.clone()
+		));
+		System::assert_last_event(Event::EVM(crate::Event::ContractRestored {
+			contract: contract_address,
+			who: bob_account_id,
+		}));
+
+		assert_eq!(balance(bob()), bob_balance - storage_deposit);
+		assert_eq!(reserved_balance(contract_address), storage_deposit);
+		assert_eq!(
+			AccountStorages::<Runtime>::iter_prefix(contract_address).collect::<Vec<_>>(),
+			storage
+		);
+		assert_eq!(ContractStorageSizes::<Runtime>::get(contract_address), storage_size);
+		assert_eq!(EVM::rent_paid_until(&contract_address), Some(17));
+		assert_eq!(EVM::contract_tombstones(contract_address), None);
+		assert_eq!(
+			Accounts::<Runtime>::get(contract_address).unwrap().contract_info,
+			Some(tombstone.contract_info)
+		);
+	});
+}
+
+#[test]
+fn should_top_up_rent_of_contract_without_storage() {
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// contracts created at genesis are not charged for their storage
+		EVM::create_contract(alice(), contract_a(), vec![0x00]);
+		assert_eq!(EVM::contract_storage_sizes(contract_a()), 0);
+		assert_eq!(EVM::rent_paid_until(&contract_a()), Some(1));
+
+		// the contract is charged for one byte
+		assert_eq!(EVM::rent_per_block(&contract_a()), 1);
+		assert_ok!(EVM::top_up_rent(
+			Origin::signed(alice_account_id.clone()),
+			contract_a(),
+			10
+		));
+		System::assert_last_event(Event::EVM(crate::Event::RentToppedUp {
+			contract: contract_a(),
+			who: alice_account_id,
+			amount: 10,
+			paid_until: 11,
+		}));
+
+		System::set_block_number(21);
+		assert_noop!(
+			EVM::archive_contract(Origin::signed(bob_account_id.clone()), contract_a(), 0),
+			Error::<Runtime>::RentNotExpired
+		);
+
+		System::set_block_number(22);
+		assert_ok!(EVM::archive_contract(Origin::signed(bob_account_id), contract_a(), 0));
+	});
+}
+
+#[test]
+fn should_not_archive_contract_without_rent() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+
+		// system contracts are not subject to rent
+		assert_ok!(EVM::create_nft_contract(
+			Origin::signed(NetworkContractAccount::get()),
+			contract.clone(),
+			0,
+			1000000,
+			1000000,
+		));
+		let system_contract = MIRRORED_TOKENS_ADDRESS_START | H160::from_low_u64_be(MIRRORED_NFT_ADDRESS_START);
+		assert_eq!(EVM::rent_paid_until(&system_contract), None);
+
+		// neither are predeployed contracts
+		let predeploy_contract = H160::from_str("1111111111111111111111111111111111111111").unwrap();
+		assert_ok!(EVM::create_predeploy_contract(
+			Origin::signed(NetworkContractAccount::get()),
+			predeploy_contract,
+			contract.clone(),
+			0,
+			1000000,
+			1000000,
+		));
+		assert_eq!(EVM::rent_paid_until(&predeploy_contract), None);
+
+		// contracts deployed before storage rent have no entry either
+		let result =
+			<Runtime as Config>::Runner::create(alice(), contract, 0, 500000, 100000, <Runtime as Config>::config())
+				.unwrap();
+		let legacy_contract = result.value;
+		RentPaidUntil::<Runtime>::remove(legacy_contract);
+
+		System::set_block_number(1_000);
+		for contract in [system_contract, predeploy_contract, legacy_contract] {
+			assert!(!EVM::is_rent_expired(&contract));
+			assert_noop!(
+				EVM::archive_contract(Origin::signed(alice_account_id.clone()), contract, 0),
+				Error::<Runtime>::RentNotExpired
+			);
+			assert_noop!(
+				EVM::top_up_rent(Origin::signed(alice_account_id.clone()), contract, 1_000_000),
+				Error::<Runtime>::RentNotApplicable
+			);
+		}
+	});
+}
+
+#[test]
+fn should_charge_base_fee() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//     mapping(address => uint256) public values;
+	//
+	//     constructor() public {
+	//         values[msg.sender] = 42;
+	//     }
+	//
+	//     function set(uint val) public {
+	//      values[msg.sender] = val;
+	//     }
+	// }
+
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		BaseFeePerGas::set(10);
+		assert_eq!(EVM::base_fee_multiplier(), Multiplier::one());
+		assert_eq!(EVM::base_fee_per_gas(), 10);
+
+		let total_issuance = Balances::total_issuance();
+		let post_info =
+			EVM::create(Origin::signed(alice_account_id.clone()), contract.clone(), 0, 500000, 100000).unwrap();
+		let used_gas = post_info.actual_weight.unwrap() as u128;
+		assert!(used_gas > 0);
+
+		// the base fee is burned
+		assert_eq!(Balances::total_issuance(), total_issuance - used_gas * 10);
+
+		// the multiplier is updated at the end of the block
+		EVM::on_finalize(1);
+		assert_eq!(EVM::base_fee_multiplier(), Multiplier::saturating_from_rational(11, 10));
+		assert_eq!(EVM::base_fee_per_gas(), 11);
+
+		// the base fee of the gas limit must be payable before the execution
+		BaseFeePerGas::set(balance(alice()));
+		assert_noop!(
+			EVM::create(Origin::signed(alice_account_id), contract, 0, 500000, 100000),
+			Error::<Runtime>::ChargeFeeFailed
+		);
+
+		BaseFeePerGas::set(0);
+	});
+}
+
+#[test]
+fn should_schedule_and_cancel_call() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//     mapping(address => uint256) public values;
+	//
+	//     constructor() public {
+	//         values[msg.sender] = 42;
+	//     }
+	//
+	//     function set(uint val) public {
+	//      values[msg.sender] = val;
+	//     }
+	// }
+
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	).unwrap();
+	// set(123)
+	let input = from_hex("0x60fe47b1000000000000000000000000000000000000000000000000000000000000007b").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		let result =
+			<Runtime as Config>::Runner::create(alice(), contract, 0, 500000, 100000, <Runtime as Config>::config())
+				.unwrap();
+		let contract_address = result.value;
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		deploy_free(contract_address);
+
+		assert_noop!(
+			EVM::schedule_call(
+				Origin::signed(bob_account_id.clone()),
+				contract_address,
+				input.clone(),
+				0,
+				100000,
+				1000,
+				2,
+				0,
+				2
+			),
+			Error::<Runtime>::InvalidSchedule
+		);
+
+		assert_ok!(EVM::schedule_call(
+			Origin::signed(bob_account_id.clone()),
+			contract_address,
+			input.clone(),
+			0,
+			100000,
+			1000,
+			2,
+			3,
+			2
+		));
+		System::assert_last_event(Event::EVM(crate::Event::CallScheduled {
+			id: 0,
+			from: bob(),
+			target: contract_address,
+			when: 2,
+			interval: 3,
+			executions: 2,
+		}));
+		let storage_deposit = EVM::get_storage_deposit_per_byte() * 1000;
+		assert_eq!(reserved_balance(bob()), storage_deposit);
+
+		// not due yet
+		IdleScheduler::on_idle(1, 1_000_000_000_000);
+		assert_eq!(EVM::scheduled_calls(0).unwrap().remaining_executions, 2);
+
+		System::set_block_number(2);
+		IdleScheduler::on_idle(2, 1_000_000_000_000);
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			Event::EVM(crate::Event::ScheduledCallExecuted {
+				id: 0,
+				exit_reason: ExitReason::Succeed(_),
+				..
+			})
+		)));
+		let info = EVM::scheduled_calls(0).unwrap();
+		assert_eq!(info.next_execution, 5);
+		assert_eq!(info.remaining_executions, 1);
+		assert_eq!(reserved_balance(bob()), storage_deposit);
+
+		System::set_block_number(5);
+		IdleScheduler::on_idle(5, 1_000_000_000_000);
+		assert_eq!(EVM::scheduled_calls(0), None);
+		assert_eq!(reserved_balance(bob()), 0);
+
+		// cancel
+		assert_ok!(EVM::schedule_call(
+			Origin::signed(bob_account_id.clone()),
+			contract_address,
+			input,
+			0,
+			100000,
+			1000,
+			10,
+			0,
+			1
+		));
+		assert_eq!(reserved_balance(bob()), storage_deposit);
+		assert_noop!(
+			EVM::cancel_scheduled_call(Origin::signed(alice_account_id), 1),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EVM::cancel_scheduled_call(Origin::signed(bob_account_id.clone()), 1));
+		System::assert_last_event(Event::EVM(crate::Event::ScheduledCallCancelled { id: 1 }));
+		assert_eq!(reserved_balance(bob()), 0);
+		assert_noop!(
+			EVM::cancel_scheduled_call(Origin::signed(bob_account_id), 1),
+			Error::<Runtime>::ScheduledCallNotFound
+		);
+
+		// the stale task is removed
+		System::set_block_number(10);
+		IdleScheduler::on_idle(10, 1_000_000_000_000);
+		assert_eq!(module_idle_scheduler::Tasks::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn should_release_reserves_of_failed_scheduled_call() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//     mapping(address => uint256) public values;
+	//
+	//     constructor() public {
+	//         values[msg.sender] = 42;
+	//     }
+	//
+	//     function set(uint val) public {
+	//      values[msg.sender] = val;
+	//     }
+	// }
+
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	).unwrap();
+	// set(123)
+	let input = from_hex("0x60fe47b1000000000000000000000000000000000000000000000000000000000000007b").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		let result =
+			<Runtime as Config>::Runner::create(alice(), contract, 0, 500000, 100000, <Runtime as Config>::config())
+				.unwrap();
+		let contract_address = result.value;
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		deploy_free(contract_address);
+
+		assert_ok!(EVM::schedule_call(
+			Origin::signed(bob_account_id),
+			contract_address,
+			input,
+			0,
+			100000,
+			1000,
+			2,
+			3,
+			2
+		));
+		let bob_balance = balance(bob());
+		let storage_deposit = EVM::get_storage_deposit_per_byte() * 1000;
+		assert_eq!(reserved_balance(bob()), storage_deposit);
+
+		// the base fee can't be paid, the execution fails
+		BaseFeePerGas::set(bob_balance);
+		System::set_block_number(2);
+		IdleScheduler::on_idle(2, 1_000_000_000_000);
+		assert!(System::events()
+			.iter()
+			.any(|record| matches!(record.event, Event::EVM(crate::Event::ScheduledCallCancelled { id: 0 }))));
+
+		// the reserves are released
+		assert_eq!(EVM::scheduled_calls(0), None);
+		assert_eq!(reserved_balance(bob()), 0);
+		assert_eq!(balance(bob()), bob_balance + storage_deposit);
+
+		BaseFeePerGas::set(0);
+	});
+}
@@ -20,6 +20,7 @@
 #![allow(clippy::unused_unit)]
 
 pub mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 
@@ -0,0 +1,350 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Migration to retire homa-lite by moving its state into the ledgers of module-homa.
+
+use crate::{
+	AvailableStakingBalance, Config, LastRedeemRequestKeyIterated, Pallet, RedeemRequests, ScheduledUnbond,
+	StakingCurrencyMintCap, StakingInterestRatePerUpdate, TotalStakingCurrency,
+};
+use frame_support::{
+	log,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use module_homa::UnlockChunk;
+use module_support::{ExchangeRateProvider, Ratio};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use primitives::{Balance, EraIndex};
+use sp_runtime::{
+	traits::{One, Saturating, UniqueSaturatedInto, Zero},
+	FixedPointNumber,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+/// Moves the state of homa-lite into module-homa:
+/// - `AvailableStakingBalance` is transferred from `T::ParachainAccount` to the Homa account. It
+///   pays the redeem requests first, the remainder goes to `ToBondPool`.
+/// - `ScheduledUnbond` becomes unlocking chunks of the ledger of `T::SubAccountIndex`, in the era
+///   the relaychain block falls into. They pay the redeem requests after the available balance.
+/// - `RedeemRequests` are paid as `Unbondings` from the above at the homa-lite exchange rate, the
+///   unpaid part becomes a redeem request of module-homa.
+/// - The rest of `TotalStakingCurrency` is bonded in the ledger of `T::SubAccountIndex`.
+///
+/// Scheduled unbonds not claimed by any redeem request stay bonded in the ledger, as module-homa
+/// has no unlocking that is not owned by a redeemer. GovernanceOrigin should reconcile them with
+/// `reset_ledgers` once they are withdrawn on relaychain.
+///
+/// The mint cap of homa-lite is reset, so no more liquid currency can be minted by homa-lite.
+///
+/// Nothing is migrated if `TotalStakingCurrency` is zero, or if `T::ParachainAccount` cannot cover
+/// `AvailableStakingBalance`.
+pub struct MigrateToHoma<T>(PhantomData<T>);
+
+impl<T: Config + module_homa::Config> MigrateToHoma<T> {
+	/// The era of module-homa in which the relaychain block `at` falls.
+	fn era_of(at: T::BlockNumber) -> EraIndex {
+		let current_era = module_homa::Pallet::<T>::relay_chain_current_era();
+		let frequency = module_homa::Pallet::<T>::bump_era_frequency();
+		if frequency.is_zero() {
+			return current_era;
+		}
+
+		let elapsed = at.saturating_sub(module_homa::Pallet::<T>::last_era_bumped_block());
+		let eras: EraIndex =
+			(elapsed.saturating_add(frequency).saturating_sub(One::one()) / frequency).unique_saturated_into();
+		current_era.saturating_add(eras)
+	}
+}
+
+impl<T: Config + module_homa::Config> OnRuntimeUpgrade for MigrateToHoma<T> {
+	fn on_runtime_upgrade() -> Weight {
+		log::info!(target: "homa-lite", "Migrating homa-lite to homa");
+
+		let total_staking = TotalStakingCurrency::<T>::get();
+		if total_staking.is_zero() {
+			log::info!(target: "homa-lite", "Nothing to migrate");
+			return T::DbWeight::get().reads(1);
+		}
+
+		let staking_currency_id = <T as Config>::StakingCurrencyId::get();
+		let parachain_account = T::ParachainAccount::get();
+		let available_staking = AvailableStakingBalance::<T>::get();
+		let mut reads: Weight = 3;
+		if <T as Config>::Currency::free_balance(staking_currency_id, &parachain_account) < available_staking {
+			log::error!(
+				target: "homa-lite",
+				"ParachainAccount cannot cover the available staking balance of {:?}, migration aborted",
+				available_staking
+			);
+			return T::DbWeight::get().reads(reads);
+		}
+
+		// the available staking balance has been withdrawn on relaychain already
+		let homa_account = module_homa::Pallet::<T>::account_id();
+		if let Err(e) = <T as Config>::Currency::transfer(
+			staking_currency_id,
+			&parachain_account,
+			&homa_account,
+			available_staking,
+		) {
+			log::error!(
+				target: "homa-lite",
+				"Failed to transfer available staking balance, migration aborted: {:?}",
+				e
+			);
+			return T::DbWeight::get().reads(reads);
+		}
+
+		// the exchange rate of homa-lite depends on `TotalStakingCurrency`
+		let exchange_rate = Pallet::<T>::get_exchange_rate();
+		TotalStakingCurrency::<T>::kill();
+		AvailableStakingBalance::<T>::kill();
+		let scheduled_unbond = ScheduledUnbond::<T>::take();
+		reads = reads.saturating_add(5);
+		let mut writes: Weight = 5;
+
+		let liquid_currency_id = <T as Config>::LiquidCurrencyId::get();
+		let current_era = module_homa::Pallet::<T>::relay_chain_current_era();
+
+		// the staking currency to pay the redeem requests, in the order of availability:
+		// (era, remaining, paid)
+		let mut chunks: Vec<(EraIndex, Balance, Balance)> = sp_std::iter::once((current_era, available_staking, 0))
+			.chain(
+				scheduled_unbond
+					.iter()
+					.map(|(amount, at)| (Self::era_of(*at), *amount, 0)),
+			)
+			.collect();
+
+		for (redeemer, (request_amount, _)) in RedeemRequests::<T>::drain() {
+			let gap = <T as Config>::Currency::unreserve(liquid_currency_id, &redeemer, request_amount);
+			let mut liquid_remaining = request_amount.saturating_sub(gap);
+
+			for (era, remaining, paid) in chunks.iter_mut() {
+				if liquid_remaining.is_zero() {
+					break;
+				}
+
+				let staking_needed = exchange_rate.saturating_mul_int(liquid_remaining);
+				let staking_amount = staking_needed.min(*remaining);
+				if staking_amount.is_zero() {
+					continue;
+				}
+				let liquid_amount = if staking_amount == staking_needed {
+					liquid_remaining
+				} else {
+					Ratio::checked_from_rational(staking_amount, staking_needed)
+						.unwrap_or_default()
+						.saturating_mul_int(liquid_remaining)
+				};
+
+				if <T as Config>::Currency::withdraw(liquid_currency_id, &redeemer, liquid_amount).is_err() {
+					break;
+				}
+				module_homa::Unbondings::<T>::mutate(&redeemer, *era, |n| *n = n.saturating_add(staking_amount));
+				*remaining = remaining.saturating_sub(staking_amount);
+				*paid = paid.saturating_add(staking_amount);
+				liquid_remaining = liquid_remaining.saturating_sub(liquid_amount);
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(2);
+			}
+
+			if !liquid_remaining.is_zero()
+				&& <T as Config>::Currency::transfer(liquid_currency_id, &redeemer, &homa_account, liquid_remaining)
+					.is_ok()
+			{
+				module_homa::RedeemRequests::<T>::mutate(&redeemer, |maybe_request| {
+					let (amount, allow_fast_match) = maybe_request.unwrap_or_default();
+					*maybe_request = Some((amount.saturating_add(liquid_remaining), allow_fast_match));
+				});
+			}
+			reads = reads.saturating_add(4);
+			writes = writes.saturating_add(4);
+		}
+
+		let (_, available_remaining, available_paid) = chunks[0];
+		module_homa::UnclaimedRedemption::<T>::mutate(|total| *total = total.saturating_add(available_paid));
+		module_homa::ToBondPool::<T>::mutate(|pool| *pool = pool.saturating_add(available_remaining));
+
+		let scheduled_paid = chunks
+			.iter()
+			.skip(1)
+			.fold(Zero::zero(), |total: Balance, (_, _, paid)| total.saturating_add(*paid));
+		let scheduled_unpaid = chunks
+			.iter()
+			.skip(1)
+			.fold(Zero::zero(), |total: Balance, (_, remaining, _)| {
+				total.saturating_add(*remaining)
+			});
+		if !scheduled_unpaid.is_zero() {
+			log::warn!(
+				target: "homa-lite",
+				"Scheduled unbonds of {:?} are not claimed by redeem requests and stay bonded",
+				scheduled_unpaid
+			);
+		}
+
+		let _ = module_homa::Pallet::<T>::do_update_ledger(T::SubAccountIndex::get(), |ledger| -> Result<(), ()> {
+			ledger.bonded = ledger.bonded.saturating_add(
+				total_staking
+					.saturating_sub(available_staking)
+					.saturating_sub(scheduled_paid),
+			);
+			for (era, _, paid) in chunks.iter().skip(1) {
+				if !paid.is_zero() {
+					ledger.unlocking.push(UnlockChunk {
+						value: *paid,
+						era: *era,
+					});
+				}
+			}
+			ledger.unlocking.sort_by_key(|chunk| chunk.era);
+			Ok(())
+		});
+
+		StakingCurrencyMintCap::<T>::kill();
+		StakingInterestRatePerUpdate::<T>::kill();
+		LastRedeemRequestKeyIterated::<T>::kill();
+		reads = reads.saturating_add(3);
+		writes = writes.saturating_add(6);
+
+		log::info!(target: "homa-lite", "Completed homa-lite migration to homa");
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		assert!(
+			module_homa::Pallet::<T>::get_total_staking_currency().is_zero(),
+			"Homa already has staking currency."
+		);
+
+		let total_staking = TotalStakingCurrency::<T>::get();
+		let scheduled_unbond = ScheduledUnbond::<T>::get()
+			.iter()
+			.fold(Zero::zero(), |total: Balance, (amount, _)| {
+				total.saturating_add(*amount)
+			});
+		assert!(
+			total_staking >= AvailableStakingBalance::<T>::get().saturating_add(scheduled_unbond),
+			"TotalStakingCurrency is less than available and scheduled unbond staking."
+		);
+
+		for (redeemer, (request_amount, _)) in RedeemRequests::<T>::iter() {
+			assert!(
+				<T as Config>::Currency::reserved_balance(<T as Config>::LiquidCurrencyId::get(), &redeemer)
+					>= request_amount,
+				"Redeem request is not fully reserved."
+			);
+		}
+
+		let staking_currency_id = <T as Config>::StakingCurrencyId::get();
+		let available_staking = AvailableStakingBalance::<T>::get();
+		assert!(
+			total_staking.is_zero()
+				|| <T as Config>::Currency::free_balance(staking_currency_id, &T::ParachainAccount::get())
+					>= available_staking,
+			"ParachainAccount cannot cover the available staking balance."
+		);
+
+		Self::set_temp_storage(Pallet::<T>::get_exchange_rate(), "exchange_rate");
+		Self::set_temp_storage(total_staking.is_zero(), "is_empty");
+		Self::set_temp_storage(available_staking, "available_staking");
+		Self::set_temp_storage(
+			<T as Config>::Currency::total_issuance(staking_currency_id),
+			"staking_issuance",
+		);
+		Self::set_temp_storage(
+			<T as Config>::Currency::free_balance(staking_currency_id, &module_homa::Pallet::<T>::account_id()),
+			"homa_balance",
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		let staking_currency_id = <T as Config>::StakingCurrencyId::get();
+		let staking_issuance =
+			Self::get_temp_storage::<Balance>("staking_issuance").ok_or("staking_issuance not found")?;
+		assert_eq!(
+			<T as Config>::Currency::total_issuance(staking_currency_id),
+			staking_issuance,
+			"Staking currency issued by the migration."
+		);
+
+		if Self::get_temp_storage::<bool>("is_empty").unwrap_or(true) {
+			return Ok(());
+		}
+
+		let available_staking =
+			Self::get_temp_storage::<Balance>("available_staking").ok_or("available_staking not found")?;
+		let homa_balance = Self::get_temp_storage::<Balance>("homa_balance").ok_or("homa_balance not found")?;
+		assert_eq!(
+			<T as Config>::Currency::free_balance(staking_currency_id, &module_homa::Pallet::<T>::account_id()),
+			homa_balance.saturating_add(available_staking),
+			"Available staking balance not transferred to Homa."
+		);
+
+		assert!(
+			TotalStakingCurrency::<T>::get().is_zero(),
+			"TotalStakingCurrency not migrated."
+		);
+		assert!(
+			AvailableStakingBalance::<T>::get().is_zero(),
+			"AvailableStakingBalance not migrated."
+		);
+		assert!(ScheduledUnbond::<T>::get().is_empty(), "ScheduledUnbond not migrated.");
+		assert!(
+			RedeemRequests::<T>::iter().next().is_none(),
+			"RedeemRequests not migrated."
+		);
+		assert!(
+			StakingCurrencyMintCap::<T>::get().is_zero(),
+			"StakingCurrencyMintCap not reset."
+		);
+
+		let exchange_rate = Self::get_temp_storage::<Ratio>("exchange_rate").ok_or("exchange_rate not found")?;
+		let new_exchange_rate = module_homa::Pallet::<T>::current_exchange_rate();
+		let difference = if exchange_rate > new_exchange_rate {
+			exchange_rate.saturating_sub(new_exchange_rate)
+		} else {
+			new_exchange_rate.saturating_sub(exchange_rate)
+		};
+		assert!(
+			difference <= Ratio::saturating_from_rational(1, 1_000_000),
+			"Exchange rate changed by the migration."
+		);
+
+		let current_era = module_homa::Pallet::<T>::relay_chain_current_era();
+		let claimable = module_homa::Unbondings::<T>::iter()
+			.filter(|(_, era, _)| *era <= current_era)
+			.fold(Zero::zero(), |total: Balance, (_, _, amount)| {
+				total.saturating_add(amount)
+			});
+		assert!(
+			module_homa::Pallet::<T>::unclaimed_redemption() >= claimable,
+			"UnclaimedRedemption does not cover claimable unbondings."
+		);
+		Ok(())
+	}
+}
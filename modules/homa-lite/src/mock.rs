@@ -21,15 +21,16 @@
 #![cfg(test)]
 
 pub use super::*;
+pub use frame_support::PalletId;
 pub use frame_support::{
 	ord_parameter_types, parameter_types,
 	traits::{Everything, Nothing},
 };
 pub use frame_system::{EnsureRoot, EnsureSignedBy, RawOrigin};
 pub use module_relaychain::RelayChainCallBuilder;
-pub use module_support::mocks::MockAddressMapping;
+pub use module_support::{mocks::MockAddressMapping, HomaSubAccountXcm, HomaValidatorProvider, ValidatorMetrics};
 pub use orml_traits::{parameter_type_with_key, XcmTransfer};
-pub use primitives::{Amount, TokenSymbol};
+pub use primitives::{Amount, EraIndex, TokenSymbol};
 pub use sp_core::H256;
 pub use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
 
@@ -50,6 +51,8 @@ pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId = AccountId32::new([2u8; 32]);
 pub const CHARLIE: AccountId = AccountId32::new([3u8; 32]);
 pub const INVALID_CALLER: AccountId = AccountId32::new([254u8; 32]);
+pub const HOMA_TREASURY: AccountId = AccountId32::new([253u8; 32]);
+pub const INSURANCE_FUND: AccountId = AccountId32::new([252u8; 32]);
 pub const ACALA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
 pub const LKSM: CurrencyId = CurrencyId::Token(TokenSymbol::LKSM);
@@ -206,6 +209,10 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -214,7 +221,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -311,6 +323,76 @@ impl Config for Runtime {
 	type StakingUpdateFrequency = StakingUpdateFrequency;
 }
 
+/// A mock XCM of module-homa, only used by the migration to homa.
+pub struct MockHomaSubAccountXcm;
+impl HomaSubAccountXcm<AccountId, Balance> for MockHomaSubAccountXcm {
+	fn transfer_staking_to_sub_account(_: &AccountId, _: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn withdraw_unbonded_from_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn bond_extra_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn unbond_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn nominate_on_sub_account(_: u16, _: Vec<AccountId>) -> DispatchResult {
+		Ok(())
+	}
+
+	fn get_xcm_transfer_fee() -> Balance {
+		0
+	}
+}
+
+pub struct MockValidatorProvider;
+impl HomaValidatorProvider<AccountId, Balance> for MockValidatorProvider {
+	fn validators(_: u32) -> Vec<(AccountId, ValidatorMetrics<Balance>)> {
+		vec![]
+	}
+}
+
+parameter_types! {
+	pub const HomaPalletId: PalletId = PalletId(*b"aca/homa");
+	pub const TreasuryAccount: AccountId = HOMA_TREASURY;
+	pub const InsuranceFundAccount: AccountId = INSURANCE_FUND;
+	pub ActiveSubAccountsIndexList: Vec<u16> = vec![0];
+	pub const BondingDuration: EraIndex = 28;
+	pub const MintThreshold: Balance = 0;
+	pub const RedeemThreshold: Balance = 0;
+	pub const MaxNominationsPerSubAccount: u32 = 2;
+	pub const MaxValidatorCandidates: u32 = 10;
+}
+
+impl module_homa::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type StakingCurrencyId = StakingCurrencyId;
+	type LiquidCurrencyId = LiquidCurrencyId;
+	type PalletId = HomaPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type InsuranceFundAccount = InsuranceFundAccount;
+	type DefaultExchangeRate = DefaultExchangeRate;
+	type ActiveSubAccountsIndexList = ActiveSubAccountsIndexList;
+	type BondingDuration = BondingDuration;
+	type MintThreshold = MintThreshold;
+	type RedeemThreshold = RedeemThreshold;
+	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
+	type HomaXcm = MockHomaSubAccountXcm;
+	type DEX = ();
+	type ValidatorProvider = MockValidatorProvider;
+	type MaxNominationsPerSubAccount = MaxNominationsPerSubAccount;
+	type MaxValidatorCandidates = MaxValidatorCandidates;
+	type WeightInfo = ();
+}
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -326,6 +408,7 @@ frame_support::construct_runtime!(
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
 		PalletXcm: pallet_xcm::{Pallet, Call, Event<T>, Origin},
+		Homa: module_homa::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
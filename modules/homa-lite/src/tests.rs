@@ -21,7 +21,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::OnRuntimeUpgrade};
 use mock::{
 	dollar, millicent, Currencies, Event, ExtBuilder, HomaLite, MockRelayBlockNumberProvider, Origin, Runtime, System,
 	ACALA, ALICE, BOB, CHARLIE, DAVE, INITIAL_BALANCE, INVALID_CALLER, KSM, LKSM,
@@ -1895,3 +1895,95 @@ fn mint_can_handle_rounding_error_dust() {
 		);
 	});
 }
+
+#[test]
+fn migrate_to_homa_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let homa_account = module_homa::Pallet::<Runtime>::account_id();
+		assert_ok!(Currencies::transfer(Origin::signed(DAVE), ALICE, LKSM, dollar(2_000)));
+		assert_ok!(Currencies::reserve(LKSM, &ALICE, dollar(2_000)));
+		RedeemRequests::<Runtime>::insert(&ALICE, (dollar(2_000), Permill::zero()));
+		assert_ok!(Currencies::deposit(KSM, &DAVE, dollar(100)));
+		AvailableStakingBalance::<Runtime>::put(dollar(100));
+		ScheduledUnbond::<Runtime>::put(BoundedVec::try_from(vec![(dollar(50), 100)]).unwrap());
+		// exchange rate = 1 / 10
+		TotalStakingCurrency::<Runtime>::put(dollar(100_000));
+		let staking_issuance = Currencies::total_issuance(KSM);
+
+		migrations::MigrateToHoma::<Runtime>::on_runtime_upgrade();
+
+		// the available staking balance is transferred, not issued.
+		assert_eq!(Currencies::total_issuance(KSM), staking_issuance);
+		assert_eq!(Currencies::free_balance(KSM, &DAVE), 0);
+		assert_eq!(Currencies::free_balance(KSM, &homa_account), dollar(100));
+
+		// 1500 LKSM of the redeem request is paid by the available and the scheduled unbond staking,
+		// the rest becomes a redeem request of homa.
+		assert_eq!(Currencies::free_balance(LKSM, &ALICE), 0);
+		assert_eq!(Currencies::reserved_balance(LKSM, &ALICE), 0);
+		assert_eq!(Currencies::free_balance(LKSM, &homa_account), dollar(500));
+		assert_eq!(module_homa::Unbondings::<Runtime>::get(&ALICE, 0), dollar(150));
+		assert_eq!(
+			module_homa::RedeemRequests::<Runtime>::get(&ALICE),
+			Some((dollar(500), false))
+		);
+		assert_eq!(module_homa::Pallet::<Runtime>::unclaimed_redemption(), dollar(100));
+		assert_eq!(module_homa::Pallet::<Runtime>::to_bond_pool(), 0);
+		assert_eq!(
+			module_homa::Pallet::<Runtime>::staking_ledgers(0),
+			Some(module_homa::StakingLedger {
+				bonded: dollar(99_850),
+				unlocking: vec![module_homa::UnlockChunk {
+					value: dollar(50),
+					era: 0
+				}],
+			})
+		);
+
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), 0);
+		assert_eq!(AvailableStakingBalance::<Runtime>::get(), 0);
+		assert!(ScheduledUnbond::<Runtime>::get().is_empty());
+		assert_eq!(RedeemRequests::<Runtime>::get(&ALICE), None);
+	});
+}
+
+#[test]
+fn migrate_to_homa_does_nothing_without_staking() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(KSM, &DAVE, dollar(60)));
+		AvailableStakingBalance::<Runtime>::put(dollar(60));
+		ScheduledUnbond::<Runtime>::put(BoundedVec::try_from(vec![(dollar(50), 100)]).unwrap());
+		RedeemRequests::<Runtime>::insert(&DAVE, (dollar(2_000), Permill::zero()));
+
+		migrations::MigrateToHoma::<Runtime>::on_runtime_upgrade();
+
+		assert_eq!(AvailableStakingBalance::<Runtime>::get(), dollar(60));
+		assert_eq!(ScheduledUnbond::<Runtime>::get().into_inner(), vec![(dollar(50), 100)]);
+		assert_eq!(
+			RedeemRequests::<Runtime>::get(&DAVE),
+			Some((dollar(2_000), Permill::zero()))
+		);
+		assert_eq!(Currencies::free_balance(KSM, &DAVE), dollar(60));
+		assert_eq!(module_homa::Pallet::<Runtime>::staking_ledgers(0), None);
+	});
+}
+
+#[test]
+fn migrate_to_homa_does_nothing_if_available_staking_is_not_covered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(KSM, &DAVE, dollar(59)));
+		AvailableStakingBalance::<Runtime>::put(dollar(60));
+		TotalStakingCurrency::<Runtime>::put(dollar(100_000));
+
+		migrations::MigrateToHoma::<Runtime>::on_runtime_upgrade();
+
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), dollar(100_000));
+		assert_eq!(AvailableStakingBalance::<Runtime>::get(), dollar(60));
+		assert_eq!(Currencies::free_balance(KSM, &DAVE), dollar(59));
+		assert_eq!(
+			Currencies::free_balance(KSM, &module_homa::Pallet::<Runtime>::account_id()),
+			0
+		);
+		assert_eq!(module_homa::Pallet::<Runtime>::staking_ledgers(0), None);
+	});
+}
@@ -37,11 +37,11 @@ use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-	traits::{BlockNumberProvider, Bounded, MaybeDisplay, MaybeSerializeDeserialize, Member, Zero},
-	DispatchResult, FixedPointNumber, RuntimeDebug,
+	traits::{BlockNumberProvider, Bounded, MaybeDisplay, MaybeSerializeDeserialize, Member, One, Zero},
+	DispatchResult, FixedPointNumber, Perbill, RuntimeDebug,
 };
 use sp_std::{fmt::Debug, vec::Vec};
-use support::{ExchangeRateProvider, Ratio};
+use support::{ExchangeRateProvider, HomaValidatorProvider, Ratio, ValidatorMetrics};
 
 mod mock;
 mod tests;
@@ -58,6 +58,7 @@ pub trait WeightInfo {
 	fn freeze(u: u32) -> Weight;
 	fn thaw() -> Weight;
 	fn slash() -> Weight;
+	fn update_commissions(u: u32) -> Weight;
 }
 
 // TODO: do benchmarking test.
@@ -83,6 +84,9 @@ impl WeightInfo for () {
 	fn slash() -> Weight {
 		10_000
 	}
+	fn update_commissions(_u: u32) -> Weight {
+		10_000
+	}
 }
 
 /// Insurance for a validator from a single address
@@ -160,6 +164,17 @@ pub struct ValidatorBacking {
 	is_frozen: bool,
 }
 
+/// Slash history of a relay chain validator
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, Default, PartialEq, MaxEncodedLen, TypeInfo)]
+pub struct SlashRecord {
+	/// The number of times the validator has been slashed
+	count: u32,
+	/// The total tokens slashed on the relay chain
+	total_slashed: Balance,
+	/// The total liquid tokens paid by the insurance fund
+	insurance_payout: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -197,6 +212,10 @@ pub mod module {
 
 		// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+		#[pallet::constant]
+		/// The account holding the insurance fund in liquid tokens, which is burned to cover
+		/// slashes exceeding the guarantees.
+		type InsuranceFundAccount: Get<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -235,6 +254,15 @@ pub mod module {
 			validator: T::RelaychainAccountId,
 			bond: Balance,
 		},
+		UpdateCommission {
+			validator: T::RelaychainAccountId,
+			commission: Perbill,
+		},
+		InsurancePayout {
+			validator: T::RelaychainAccountId,
+			claim: Balance,
+			payout: Balance,
+		},
 	}
 
 	/// The slash guarantee deposits for relaychain validators.
@@ -267,6 +295,21 @@ pub mod module {
 	pub type ValidatorBackings<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::RelaychainAccountId, ValidatorBacking, OptionQuery>;
 
+	/// The slash history of validators.
+	///
+	/// SlashRecords: map RelaychainAccountId => SlashRecord
+	#[pallet::storage]
+	#[pallet::getter(fn slash_records)]
+	pub type SlashRecords<T: Config> = StorageMap<_, Blake2_128Concat, T::RelaychainAccountId, SlashRecord, ValueQuery>;
+
+	/// The commission of validators on the relay chain.
+	///
+	/// ValidatorCommissions: map RelaychainAccountId => Perbill
+	#[pallet::storage]
+	#[pallet::getter(fn validator_commissions)]
+	pub type ValidatorCommissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::RelaychainAccountId, Perbill, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -470,9 +513,13 @@ pub mod module {
 			} in slashes
 			{
 				let ValidatorBacking { total_insurance, .. } = Self::validator_backings(&validator).unwrap_or_default();
-				let insurance_loss = staking_liquid_exchange_rate
-					.saturating_mul_int(relaychain_token_amount)
-					.min(total_insurance);
+				SlashRecords::<T>::mutate(&validator, |record| {
+					record.count = record.count.saturating_add(1);
+					record.total_slashed = record.total_slashed.saturating_add(relaychain_token_amount);
+				});
+				let liquid_loss = staking_liquid_exchange_rate.saturating_mul_int(relaychain_token_amount);
+				let insurance_loss = liquid_loss.min(total_insurance);
+				let mut validator_slashing: Balance = Zero::zero();
 
 				for (guarantor, _) in Guarantees::<T>::iter_prefix(&validator) {
 					// NOTE: ignoring result because the closure will not throw err.
@@ -488,20 +535,80 @@ pub mod module {
 							validator: validator.clone(),
 							bond: actual_slashing,
 						});
-						actual_total_slashing = actual_total_slashing.saturating_add(actual_slashing);
+						validator_slashing = validator_slashing.saturating_add(actual_slashing);
 						Ok(())
 					});
 					debug_assert!(res.is_ok());
 				}
+
+				// the insurance fund covers the loss exceeding the guarantees
+				let claim = liquid_loss.saturating_sub(validator_slashing);
+				if !claim.is_zero() {
+					let gap = T::LiquidTokenCurrency::slash(&T::InsuranceFundAccount::get(), claim);
+					let payout = claim.saturating_sub(gap);
+					if !payout.is_zero() {
+						SlashRecords::<T>::mutate(&validator, |record| {
+							record.insurance_payout = record.insurance_payout.saturating_add(payout);
+						});
+						Self::deposit_event(Event::InsurancePayout {
+							validator: validator.clone(),
+							claim,
+							payout,
+						});
+						validator_slashing = validator_slashing.saturating_add(payout);
+					}
+				}
+				actual_total_slashing = actual_total_slashing.saturating_add(validator_slashing);
 			}
 
 			T::OnSlash::happened(&actual_total_slashing);
 			Ok(())
 		}
+
+		/// Update the commissions of validators on the relay chain.
+		/// Ensures the caller can freeze validators.
+		///
+		/// - `commissions`: The AccountIds of the validators on the relay chain and their
+		///   commissions
+		#[pallet::weight(T::WeightInfo::update_commissions(commissions.len() as u32))]
+		#[transactional]
+		pub fn update_commissions(
+			origin: OriginFor<T>,
+			commissions: Vec<(T::RelaychainAccountId, Perbill)>,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			for (validator, commission) in commissions {
+				ValidatorCommissions::<T>::insert(&validator, commission);
+				Self::deposit_event(Event::UpdateCommission { validator, commission });
+			}
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The ratio of the insurance fund to the total issuance of liquid tokens.
+	pub fn insurance_fund_coverage_ratio() -> Ratio {
+		Ratio::checked_from_rational(
+			T::LiquidTokenCurrency::free_balance(&T::InsuranceFundAccount::get()),
+			T::LiquidTokenCurrency::total_issuance(),
+		)
+		.unwrap_or_default()
+	}
+
+	/// The ratio of the guarantees of `validator` plus the insurance fund to the total issuance
+	/// of liquid tokens, i.e. the share of liquid tokens recoverable if the validator is
+	/// slashed.
+	pub fn coverage_ratio(validator: &T::RelaychainAccountId) -> Ratio {
+		let total_insurance = Self::validator_backings(validator).unwrap_or_default().total_insurance;
+		Ratio::checked_from_rational(
+			total_insurance.saturating_add(T::LiquidTokenCurrency::free_balance(&T::InsuranceFundAccount::get())),
+			T::LiquidTokenCurrency::total_issuance(),
+		)
+		.unwrap_or_default()
+		.min(Ratio::one())
+	}
+
 	fn update_guarantee(
 		guarantor: &T::AccountId,
 		validator: &T::RelaychainAccountId,
@@ -565,6 +672,38 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> HomaValidatorProvider<T::RelaychainAccountId, Balance> for Pallet<T> {
+	fn validators(limit: u32) -> Vec<(T::RelaychainAccountId, ValidatorMetrics<Balance>)> {
+		let mut backings: Vec<(T::RelaychainAccountId, ValidatorBacking)> = ValidatorBackings::<T>::iter().collect();
+		// unfrozen validators first, then by insurance in descending order, ties are broken by
+		// account for determinism
+		backings.sort_by(|a, b| {
+			a.1.is_frozen
+				.cmp(&b.1.is_frozen)
+				.then_with(|| b.1.total_insurance.cmp(&a.1.total_insurance))
+				.then_with(|| a.0.cmp(&b.0))
+		});
+
+		backings
+			.into_iter()
+			.take(limit as usize)
+			.map(|(validator, backing)| {
+				let SlashRecord {
+					count, total_slashed, ..
+				} = Self::slash_records(&validator);
+				let metrics = ValidatorMetrics {
+					insurance: backing.total_insurance,
+					slash_count: count,
+					total_slashed,
+					commission: Self::validator_commissions(&validator),
+					is_frozen: backing.is_frozen,
+				};
+				(validator, metrics)
+			})
+			.collect()
+	}
+}
+
 impl<T: Config> Contains<T::RelaychainAccountId> for Pallet<T> {
 	fn contains(relaychain_account_id: &T::RelaychainAccountId) -> bool {
 		Self::validator_backings(relaychain_account_id)
@@ -42,6 +42,7 @@ pub const BOB: AccountId = 1;
 pub const VALIDATOR_1: AccountId = 2;
 pub const VALIDATOR_2: AccountId = 3;
 pub const VALIDATOR_3: AccountId = 4;
+pub const INSURANCE_FUND: AccountId = 100;
 pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
 
@@ -89,6 +90,10 @@ parameter_types! {
 	pub const MaxLocks: u32 = 100;
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -97,7 +102,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = MaxLocks;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -191,6 +201,7 @@ parameter_types! {
 	pub const BondingDuration: BlockNumber = 100;
 	pub const ValidatorInsuranceThreshold: Balance = 200;
 	pub static MockBlockNumberProvider: u64 = 0;
+	pub const InsuranceFundAccount: AccountId = INSURANCE_FUND;
 }
 
 impl BlockNumberProvider for MockBlockNumberProvider {
@@ -220,6 +231,7 @@ impl Config for Runtime {
 	type OnIncreaseGuarantee = MockOnIncreaseGuarantee;
 	type OnDecreaseGuarantee = MockOnDecreaseGuarantee;
 	type BlockNumberProvider = MockBlockNumberProvider;
+	type InsuranceFundAccount = InsuranceFundAccount;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -757,6 +757,98 @@ fn slash_work() {
 			HomaValidatorListModule::total_locked_by_guarantor(BOB).unwrap_or_default(),
 			281
 		);
+
+		assert_eq!(
+			HomaValidatorListModule::slash_records(VALIDATOR_1),
+			SlashRecord {
+				count: 1,
+				total_slashed: 90,
+				insurance_payout: 0,
+			}
+		);
+		assert_eq!(
+			HomaValidatorListModule::slash_records(VALIDATOR_2),
+			SlashRecord {
+				count: 1,
+				total_slashed: 50,
+				insurance_payout: 0,
+			}
+		);
+	});
+}
+
+#[test]
+fn validator_provider_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_1, 100));
+		assert_noop!(
+			HomaValidatorListModule::update_commissions(
+				Origin::signed(ALICE),
+				vec![(VALIDATOR_1, Perbill::from_percent(5))]
+			),
+			BadOrigin
+		);
+		assert_ok!(HomaValidatorListModule::update_commissions(
+			Origin::signed(10),
+			vec![(VALIDATOR_1, Perbill::from_percent(5))]
+		));
+		System::assert_last_event(mock::Event::HomaValidatorListModule(crate::Event::UpdateCommission {
+			validator: VALIDATOR_1,
+			commission: Perbill::from_percent(5),
+		}));
+		assert_ok!(HomaValidatorListModule::slash(
+			Origin::signed(10),
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 10
+			}]
+		));
+		assert_ok!(HomaValidatorListModule::freeze(Origin::signed(10), vec![VALIDATOR_1]));
+
+		assert_eq!(
+			<HomaValidatorListModule as HomaValidatorProvider<_, _>>::validators(10),
+			vec![(
+				VALIDATOR_1,
+				ValidatorMetrics {
+					insurance: 80,
+					slash_count: 1,
+					total_slashed: 10,
+					commission: Perbill::from_percent(5),
+					is_frozen: true,
+				}
+			)]
+		);
+		assert_eq!(
+			<HomaValidatorListModule as HomaValidatorProvider<_, _>>::validators(0),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn validator_provider_ranks_validators() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_1, 100));
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_2, 200));
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(BOB), VALIDATOR_3, 300));
+		assert_ok!(HomaValidatorListModule::freeze(Origin::signed(10), vec![VALIDATOR_3]));
+
+		let validators = |limit: u32| -> Vec<AccountId> {
+			<HomaValidatorListModule as HomaValidatorProvider<_, _>>::validators(limit)
+				.into_iter()
+				.map(|(validator, _)| validator)
+				.collect()
+		};
+		assert_eq!(validators(10), vec![VALIDATOR_2, VALIDATOR_1, VALIDATOR_3]);
+		assert_eq!(validators(2), vec![VALIDATOR_2, VALIDATOR_1]);
+		assert_eq!(validators(1), vec![VALIDATOR_2]);
+
+		assert_ok!(HomaValidatorListModule::thaw(Origin::signed(10), vec![VALIDATOR_3]));
+		assert_eq!(validators(2), vec![VALIDATOR_3, VALIDATOR_2]);
 	});
 }
 
@@ -784,3 +876,69 @@ fn contains_work() {
 		assert!(HomaValidatorListModule::contains(&VALIDATOR_1));
 	});
 }
+
+#[test]
+fn insurance_fund_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LDOTCurrency::deposit(&INSURANCE_FUND, 200));
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_1, 100));
+
+		assert_eq!(
+			HomaValidatorListModule::insurance_fund_coverage_ratio(),
+			Ratio::saturating_from_rational(200, 2200)
+		);
+		assert_eq!(
+			HomaValidatorListModule::coverage_ratio(&VALIDATOR_1),
+			Ratio::saturating_from_rational(300, 2200)
+		);
+		assert_eq!(
+			HomaValidatorListModule::coverage_ratio(&VALIDATOR_2),
+			Ratio::saturating_from_rational(200, 2200)
+		);
+
+		// the loss of 200 liquid tokens exceeds the guarantee of 100
+		assert_ok!(HomaValidatorListModule::slash(
+			Origin::signed(10),
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 100
+			}]
+		));
+		System::assert_has_event(mock::Event::HomaValidatorListModule(crate::Event::SlashGuarantee {
+			who: ALICE,
+			validator: VALIDATOR_1,
+			bond: 100,
+		}));
+		System::assert_last_event(mock::Event::HomaValidatorListModule(crate::Event::InsurancePayout {
+			validator: VALIDATOR_1,
+			claim: 100,
+			payout: 100,
+		}));
+		assert_eq!(LDOTCurrency::free_balance(&INSURANCE_FUND), 100);
+		assert_eq!(
+			HomaValidatorListModule::slash_records(VALIDATOR_1),
+			SlashRecord {
+				count: 1,
+				total_slashed: 100,
+				insurance_payout: 100,
+			}
+		);
+
+		// the insurance fund pays what it has
+		assert_ok!(HomaValidatorListModule::slash(
+			Origin::signed(10),
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 100
+			}]
+		));
+		System::assert_last_event(mock::Event::HomaValidatorListModule(crate::Event::InsurancePayout {
+			validator: VALIDATOR_1,
+			claim: 200,
+			payout: 100,
+		}));
+		assert_eq!(LDOTCurrency::free_balance(&INSURANCE_FUND), 0);
+		assert_eq!(HomaValidatorListModule::insurance_fund_coverage_ratio(), Ratio::zero());
+	});
+}
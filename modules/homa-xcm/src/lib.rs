@@ -43,6 +43,7 @@ pub mod module {
 		XcmWithdrawUnbonded,
 		XcmBondExtra,
 		XcmUnbond,
+		XcmNominate,
 	}
 
 	#[pallet::config]
@@ -224,6 +225,28 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Send XCM message to the relaychain for sub account to nominate the targets.
+		fn nominate_on_sub_account(sub_account_index: u16, targets: Vec<T::AccountId>) -> DispatchResult {
+			let (xcm_dest_weight, xcm_fee) = Self::xcm_dest_weight_and_fee(HomaXcmOperation::XcmNominate);
+			let xcm_message = T::RelayChainCallBuilder::finalize_call_into_xcm_message(
+				T::RelayChainCallBuilder::utility_as_derivative_call(
+					T::RelayChainCallBuilder::staking_nominate(targets.clone()),
+					sub_account_index,
+				),
+				xcm_fee,
+				xcm_dest_weight,
+			);
+			let result = pallet_xcm::Pallet::<T>::send_xcm(Here, Parent, xcm_message);
+			log::debug!(
+				target: "homa-xcm",
+				"subaccount {:?} send XCM to nominate {:?}, result: {:?}",
+				sub_account_index, targets, result
+			);
+
+			ensure!(result.is_ok(), Error::<T>::XcmFailed);
+			Ok(())
+		}
+
 		/// The fee of cross-chain transfer is deducted from the recipient.
 		fn get_xcm_transfer_fee() -> Balance {
 			Self::xcm_dest_weight_and_fee(HomaXcmOperation::XtokensTransfer).1
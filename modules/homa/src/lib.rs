@@ -23,7 +23,10 @@
 
 use frame_support::{log, pallet_prelude::*, transactional, PalletId};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use module_support::{ExchangeRate, ExchangeRateProvider, HomaSubAccountXcm, Rate, Ratio};
+use module_support::{
+	DEXManager, ExchangeRate, ExchangeRateProvider, HomaSubAccountXcm, HomaValidatorProvider, Rate, Ratio, SwapLimit,
+	ValidatorMetrics,
+};
 use orml_traits::MultiCurrency;
 use primitives::{Balance, CurrencyId, EraIndex};
 use scale_info::TypeInfo;
@@ -32,7 +35,7 @@ use sp_runtime::{
 		AccountIdConversion, BlockNumberProvider, Bounded, CheckedDiv, CheckedSub, One, Saturating,
 		UniqueSaturatedInto, Zero,
 	},
-	ArithmeticError, FixedPointNumber,
+	ArithmeticError, FixedPointNumber, Perbill,
 };
 use sp_std::{cmp::Ordering, convert::From, prelude::*, vec, vec::Vec};
 
@@ -68,6 +71,22 @@ pub mod module {
 		pub era: EraIndex,
 	}
 
+	/// The governance parameters of the strategy to pick nominations for subaccounts.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct NominationStrategy {
+		/// The number of validators each subaccount nominates.
+		pub nominations_per_sub_account: u32,
+		/// The maximum number of validators from the validator list to score.
+		pub max_candidates: u32,
+		/// Validators with less insurance are excluded.
+		#[codec(compact)]
+		pub min_insurance: Balance,
+		/// Validators with higher commission are excluded.
+		pub max_commission: Perbill,
+		/// Validators slashed more times are excluded.
+		pub max_slash_count: u32,
+	}
+
 	impl StakingLedger {
 		/// Remove entries from `unlocking` that are sufficiently old and the sum of expired
 		/// unlocking.
@@ -126,6 +145,10 @@ pub mod module {
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
 
+		/// The insurance fund covering the slash of liquid staking holders beyond guarantees.
+		#[pallet::constant]
+		type InsuranceFundAccount: Get<Self::AccountId>;
+
 		/// The index list of active Homa subaccounts.
 		/// `active` means these subaccounts can continue do bond/unbond operations by Homa.
 		#[pallet::constant]
@@ -149,6 +172,20 @@ pub mod module {
 		/// The HomaXcm to manage the staking of sub-account on relaychain.
 		type HomaXcm: HomaSubAccountXcm<Self::AccountId, Balance>;
 
+		/// DEX to swap liquid currency to staking currency for instant redeem.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The validators to be scored by the nomination strategy.
+		type ValidatorProvider: HomaValidatorProvider<Self::AccountId, Balance>;
+
+		/// The maximum number of validators a subaccount can nominate on relaychain.
+		#[pallet::constant]
+		type MaxNominationsPerSubAccount: Get<u32>;
+
+		/// The maximum number of validators the nomination strategy can score.
+		#[pallet::constant]
+		type MaxValidatorCandidates: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -167,6 +204,19 @@ pub mod module {
 		OutdatedEraIndex,
 		/// Redeem request is not allowed to be fast matched.
 		FastMatchIsNotAllowed,
+		/// The DEX price is below the current exchange rate by more than InstantRedeemDiscount.
+		InstantRedeemUnavailable,
+		/// The redeem request is not found.
+		RedeemRequestNotFound,
+		/// The redeem request is not listed for sale.
+		RedeemRequestNotListed,
+		/// The buyer already has a redeem request.
+		RedeemRequestAlreadyExists,
+		/// The price of the listed redeem request exceeds the max price of the buyer.
+		PriceExceedsLimit,
+		/// The nomination strategy exceeds MaxNominationsPerSubAccount or
+		/// MaxValidatorCandidates.
+		InvalidNominationStrategy,
 	}
 
 	#[pallet::event]
@@ -206,10 +256,27 @@ pub mod module {
 		CommissionRateUpdated(Rate),
 		/// The fast match fee rate has been updated. \[commission_rate\]
 		FastMatchFeeRateUpdated(Rate),
+		/// The insurance fund rate has been updated. \[insurance_fund_rate\]
+		InsuranceFundRateUpdated(Rate),
 		/// The relaychain block number of last era bumped updated. \[last_era_bumped_block\]
 		LastEraBumpedBlockUpdated(T::BlockNumber),
 		/// The frequency to bump era has been updated. \[frequency\]
 		BumpEraFrequencyUpdated(T::BlockNumber),
+		/// Liquid currency is redeemed instantly on DEX. \[redeemer, liquid_amount,
+		/// received_staking_amount\]
+		InstantRedeemed(T::AccountId, Balance, Balance),
+		/// The instant redeem discount has been updated. \[discount\]
+		InstantRedeemDiscountUpdated(Rate),
+		/// Redeem request is listed for sale. \[redeemer, price_in_staking\]
+		RedeemRequestListed(T::AccountId, Balance),
+		/// Redeem request listing is removed. \[redeemer\]
+		RedeemRequestUnlisted(T::AccountId),
+		/// Redeem request is sold. \[seller, buyer, liquid_amount, price_in_staking\]
+		RedeemRequestSold(T::AccountId, T::AccountId, Balance, Balance),
+		/// The nomination strategy has been updated. \[strategy\]
+		NominationStrategyUpdated(Option<NominationStrategy>),
+		/// The nominations of subaccount have been updated. \[sub_account_index, targets\]
+		NominationsUpdated(u16, Vec<T::AccountId>),
 	}
 
 	/// The current era of relaychain
@@ -303,6 +370,14 @@ pub mod module {
 	#[pallet::getter(fn fast_match_fee_rate)]
 	pub type FastMatchFeeRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
 
+	/// The rate of Homa drawn from the staking reward to fund the insurance.
+	/// The draw will be transfer to InsuranceFundAccount in liquid currency.
+	///
+	/// InsuranceFundRate: value: Rate
+	#[pallet::storage]
+	#[pallet::getter(fn insurance_fund_rate)]
+	pub type InsuranceFundRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
+
 	/// The relaychain block number of last era bumped.
 	///
 	/// LastEraBumpedBlock: value: T::BlockNumber
@@ -317,6 +392,36 @@ pub mod module {
 	#[pallet::getter(fn bump_era_frequency)]
 	pub type BumpEraFrequency<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
 
+	/// The maximum discount of the DEX price to the current exchange rate that instant redeem
+	/// accepts.
+	///
+	/// InstantRedeemDiscount: value: Rate
+	#[pallet::storage]
+	#[pallet::getter(fn instant_redeem_discount)]
+	pub type InstantRedeemDiscount<T: Config> = StorageValue<_, Rate, ValueQuery>;
+
+	/// Redeem requests listed for sale. The listing is removed once the request changes.
+	///
+	/// RedeemRequestListings: Map: AccountId => Option<price_in_staking: Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_request_listings)]
+	pub type RedeemRequestListings<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Balance, OptionQuery>;
+
+	/// The strategy to pick nominations for subaccounts, nominations are left to governance if
+	/// None.
+	///
+	/// NominationStrategyParams: value: Option<NominationStrategy>
+	#[pallet::storage]
+	#[pallet::getter(fn nomination_strategy)]
+	pub type NominationStrategyParams<T: Config> = StorageValue<_, NominationStrategy, OptionQuery>;
+
+	/// The validators nominated by Homa subaccounts.
+	///
+	/// Nominations: map: u16 => Vec<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn nominations)]
+	pub type Nominations<T: Config> = StorageMap<_, Twox64Concat, u16, Vec<T::AccountId>, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -326,7 +431,9 @@ pub mod module {
 			let bump_era_number = Self::era_amount_should_to_bump(T::RelayChainBlockNumber::current_block_number());
 			if !bump_era_number.is_zero() {
 				let _ = Self::bump_current_era(bump_era_number);
-				<T as Config>::WeightInfo::on_initialize_with_bump_era()
+				<T as Config>::WeightInfo::on_initialize_with_bump_era().saturating_add(
+					<T as Config>::WeightInfo::process_nominations(T::MaxValidatorCandidates::get()),
+				)
 			} else {
 				<T as Config>::WeightInfo::on_initialize()
 			}
@@ -403,6 +510,7 @@ pub mod module {
 			RedeemRequests::<T>::try_mutate_exists(&redeemer, |maybe_request| -> DispatchResult {
 				let (previous_request_amount, _) = maybe_request.take().unwrap_or_default();
 				let liquid_currency_id = T::LiquidCurrencyId::get();
+				Self::remove_redeem_request_listing(&redeemer);
 
 				ensure!(
 					(!previous_request_amount.is_zero() && amount.is_zero()) || amount >= T::RedeemThreshold::get(),
@@ -460,6 +568,96 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Redeem liquid currency instantly by swapping it to staking currency on DEX. Only
+		/// executed if the DEX price is no more than InstantRedeemDiscount below the current
+		/// exchange rate.
+		///
+		/// Parameters:
+		/// - `amount`: The amount of liquid currency to redeem.
+		#[pallet::weight(< T as Config >::WeightInfo::instant_redeem())]
+		#[transactional]
+		pub fn instant_redeem(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let redeemer = ensure_signed(origin)?;
+			ensure!(amount >= T::RedeemThreshold::get(), Error::<T>::BelowRedeemThreshold);
+
+			let min_staking_amount = Rate::one()
+				.saturating_sub(Self::instant_redeem_discount())
+				.saturating_mul_int(Self::convert_liquid_to_staking(amount)?);
+			let path = [T::LiquidCurrencyId::get(), T::StakingCurrencyId::get()];
+			let limit = SwapLimit::ExactSupply(amount, min_staking_amount);
+			ensure!(
+				T::DEX::get_swap_amount(&path, limit).is_some(),
+				Error::<T>::InstantRedeemUnavailable
+			);
+
+			let (_, staking_amount) = T::DEX::swap_with_specific_path(&redeemer, &path, limit)?;
+
+			Self::deposit_event(Event::<T>::InstantRedeemed(redeemer, amount, staking_amount));
+			Ok(())
+		}
+
+		/// List the redeem request of the caller for sale. The buyer pays `price` in staking
+		/// currency and takes over the request. Any change of the request removes the listing.
+		///
+		/// Parameters:
+		/// - `price`: The price in staking currency.
+		#[pallet::weight(< T as Config >::WeightInfo::list_redeem_request())]
+		#[transactional]
+		pub fn list_redeem_request(origin: OriginFor<T>, #[pallet::compact] price: Balance) -> DispatchResult {
+			let redeemer = ensure_signed(origin)?;
+			ensure!(
+				RedeemRequests::<T>::contains_key(&redeemer),
+				Error::<T>::RedeemRequestNotFound
+			);
+
+			RedeemRequestListings::<T>::insert(&redeemer, price);
+			Self::deposit_event(Event::<T>::RedeemRequestListed(redeemer, price));
+			Ok(())
+		}
+
+		/// Remove the sale listing of the redeem request of the caller.
+		#[pallet::weight(< T as Config >::WeightInfo::cancel_redeem_request_listing())]
+		#[transactional]
+		pub fn cancel_redeem_request_listing(origin: OriginFor<T>) -> DispatchResult {
+			let redeemer = ensure_signed(origin)?;
+			ensure!(
+				RedeemRequestListings::<T>::contains_key(&redeemer),
+				Error::<T>::RedeemRequestNotListed
+			);
+
+			Self::remove_redeem_request_listing(&redeemer);
+			Ok(())
+		}
+
+		/// Buy the listed redeem request of `seller`. The caller becomes the redeemer of the
+		/// request, and must not have a redeem request.
+		///
+		/// Parameters:
+		/// - `seller`: The redeemer of the listed request.
+		/// - `max_price`: The maximum price in staking currency to pay.
+		#[pallet::weight(< T as Config >::WeightInfo::buy_redeem_request())]
+		#[transactional]
+		pub fn buy_redeem_request(
+			origin: OriginFor<T>,
+			seller: T::AccountId,
+			#[pallet::compact] max_price: Balance,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let price = RedeemRequestListings::<T>::take(&seller).ok_or(Error::<T>::RedeemRequestNotListed)?;
+			ensure!(price <= max_price, Error::<T>::PriceExceedsLimit);
+			ensure!(
+				!RedeemRequests::<T>::contains_key(&buyer),
+				Error::<T>::RedeemRequestAlreadyExists
+			);
+			let request = RedeemRequests::<T>::take(&seller).ok_or(Error::<T>::RedeemRequestNotFound)?;
+
+			T::Currency::transfer(T::StakingCurrencyId::get(), &buyer, &seller, price)?;
+			RedeemRequests::<T>::insert(&buyer, request);
+
+			Self::deposit_event(Event::<T>::RedeemRequestSold(seller, buyer, request.0, price));
+			Ok(())
+		}
+
 		/// Withdraw the expired redemption of specific redeemer by unbond.
 		///
 		/// Parameters:
@@ -567,6 +765,63 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Sets the maximum discount of the DEX price to the current exchange rate that
+		/// instant redeem accepts.
+		/// Requires `GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `discount`: the maximum discount.
+		#[pallet::weight(< T as Config >::WeightInfo::update_instant_redeem_discount())]
+		#[transactional]
+		pub fn update_instant_redeem_discount(origin: OriginFor<T>, discount: Rate) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			InstantRedeemDiscount::<T>::put(discount);
+			Self::deposit_event(Event::<T>::InstantRedeemDiscountUpdated(discount));
+			Ok(())
+		}
+
+		/// Sets the rate of staking rewards drawn to the insurance fund.
+		/// Requires `GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `rate`: the insurance fund rate.
+		#[pallet::weight(< T as Config >::WeightInfo::update_insurance_fund_rate())]
+		#[transactional]
+		pub fn update_insurance_fund_rate(origin: OriginFor<T>, rate: Rate) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			InsuranceFundRate::<T>::put(rate);
+			Self::deposit_event(Event::<T>::InsuranceFundRateUpdated(rate));
+			Ok(())
+		}
+
+		/// Sets the strategy to pick nominations for subaccounts when era bumped.
+		/// Requires `GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `strategy`: the new strategy, None to leave nominations to governance.
+		#[pallet::weight(< T as Config >::WeightInfo::update_nomination_strategy())]
+		#[transactional]
+		pub fn update_nomination_strategy(
+			origin: OriginFor<T>,
+			strategy: Option<NominationStrategy>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			if let Some(strategy) = &strategy {
+				ensure!(
+					!strategy.nominations_per_sub_account.is_zero()
+						&& strategy.nominations_per_sub_account <= T::MaxNominationsPerSubAccount::get()
+						&& strategy.max_candidates <= T::MaxValidatorCandidates::get(),
+					Error::<T>::InvalidNominationStrategy
+				);
+			}
+
+			NominationStrategyParams::<T>::set(strategy.clone());
+			Self::deposit_event(Event::<T>::NominationStrategyUpdated(strategy));
+			Ok(())
+		}
+
 		/// Reset the bonded and unbonding to local subaccounts ledger according to the ledger on
 		/// relaychain. Requires `GovernanceOrigin`
 		///
@@ -631,6 +886,13 @@ pub mod module {
 			T::PalletId::get().into_account()
 		}
 
+		/// Remove the sale listing of the redeem request of `redeemer`, if any.
+		fn remove_redeem_request_listing(redeemer: &T::AccountId) {
+			if RedeemRequestListings::<T>::take(redeemer).is_some() {
+				Self::deposit_event(Event::<T>::RedeemRequestUnlisted(redeemer.clone()));
+			}
+		}
+
 		pub fn do_update_ledger<R, E>(
 			sub_account_index: u16,
 			f: impl FnOnce(&mut StakingLedger) -> sp_std::result::Result<R, E>,
@@ -731,6 +993,7 @@ pub mod module {
 					};
 
 					if !actual_liquid_to_redeem.is_zero() {
+						Self::remove_redeem_request_listing(redeemer);
 						let liquid_to_burn = Rate::one()
 							.saturating_sub(fast_match_fee_rate)
 							.saturating_mul_int(actual_liquid_to_redeem);
@@ -771,7 +1034,8 @@ pub mod module {
 
 		/// Accumulate staking rewards according to EstimatedRewardRatePerEra and era internally.
 		/// And draw commission from estimated staking rewards by issuing liquid currency to
-		/// TreasuryAccount. Note: This will cause some losses to the minters in previous_era,
+		/// TreasuryAccount, as well as the share of the insurance fund to InsuranceFundAccount.
+		/// Note: This will cause some losses to the minters in previous_era,
 		/// because they have been already deducted some liquid currency amount when mint in
 		/// previous_era. Until there is a better way to calculate, this part of the loss can only
 		/// be regarded as an implicit mint fee!
@@ -801,18 +1065,33 @@ pub mod module {
 				}
 
 				let commission_rate = Self::commission_rate();
-				if !total_reward_staking.is_zero() && !commission_rate.is_zero() {
+				let insurance_fund_rate = Self::insurance_fund_rate();
+				let draw_rate = commission_rate.saturating_add(insurance_fund_rate);
+				if !total_reward_staking.is_zero() && !draw_rate.is_zero() {
 					let liquid_currency_id = T::LiquidCurrencyId::get();
-					let commission_staking_amount = commission_rate.saturating_mul_int(total_reward_staking);
-					let commission_ratio =
-						Ratio::checked_from_rational(commission_staking_amount, Self::get_total_bonded())
-							.unwrap_or_else(Ratio::min_value);
-					let inflate_rate = commission_ratio
-						.checked_div(&Ratio::one().saturating_sub(commission_ratio))
+					let draw_staking_amount = draw_rate.saturating_mul_int(total_reward_staking);
+					let draw_ratio = Ratio::checked_from_rational(draw_staking_amount, Self::get_total_bonded())
+						.unwrap_or_else(Ratio::min_value);
+					let inflate_rate = draw_ratio
+						.checked_div(&Ratio::one().saturating_sub(draw_ratio))
 						.unwrap_or_else(Ratio::max_value);
 					let inflate_liquid_amount = inflate_rate.saturating_mul_int(Self::get_total_liquid_currency());
-
-					T::Currency::deposit(liquid_currency_id, &T::TreasuryAccount::get(), inflate_liquid_amount)?;
+					let insurance_liquid_amount =
+						Ratio::checked_from_rational(insurance_fund_rate.into_inner(), draw_rate.into_inner())
+							.unwrap_or_else(Ratio::min_value)
+							.saturating_mul_int(inflate_liquid_amount);
+					let commission_liquid_amount = inflate_liquid_amount.saturating_sub(insurance_liquid_amount);
+
+					if !commission_liquid_amount.is_zero() {
+						T::Currency::deposit(liquid_currency_id, &T::TreasuryAccount::get(), commission_liquid_amount)?;
+					}
+					if !insurance_liquid_amount.is_zero() {
+						T::Currency::deposit(
+							liquid_currency_id,
+							&T::InsuranceFundAccount::get(),
+							insurance_liquid_amount,
+						)?;
+					}
 				}
 			}
 
@@ -911,6 +1190,7 @@ pub mod module {
 					total_redeem_amount = total_redeem_amount.saturating_add(redeem_amount);
 					remain_total_bonded = remain_total_bonded.saturating_sub(redemption_amount);
 					RedeemRequests::<T>::remove(&redeemer);
+					Self::remove_redeem_request_listing(&redeemer);
 					Unbondings::<T>::mutate(&redeemer, era_index_to_expire, |n| {
 						*n = n.saturating_add(redemption_amount)
 					});
@@ -954,6 +1234,57 @@ pub mod module {
 			T::Currency::withdraw(T::LiquidCurrencyId::get(), &Self::account_id(), total_redeem_amount)
 		}
 
+		/// Score validators by the nomination strategy, and pick the nominations for active
+		/// subaccounts. Subaccounts nominate different validators if there are enough
+		/// candidates. Send XCM to the relaychain for subaccounts whose nominations changed.
+		#[transactional]
+		pub fn process_nominations() -> DispatchResult {
+			let strategy = match Self::nomination_strategy() {
+				Some(strategy) => strategy,
+				None => return Ok(()),
+			};
+
+			let mut candidates: Vec<(T::AccountId, Balance)> =
+				T::ValidatorProvider::validators(strategy.max_candidates.min(T::MaxValidatorCandidates::get()))
+					.into_iter()
+					.filter(|(_, metrics)| {
+						!metrics.is_frozen
+							&& metrics.insurance >= strategy.min_insurance
+							&& metrics.commission <= strategy.max_commission
+							&& metrics.slash_count <= strategy.max_slash_count
+					})
+					.map(|(validator, metrics)| (validator, Self::validator_score(&metrics)))
+					.collect();
+			if candidates.is_empty() {
+				return Ok(());
+			}
+			// sort by score in descending order, ties are broken by account for determinism
+			candidates.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+			let per_sub_account = (strategy.nominations_per_sub_account as usize).min(candidates.len());
+			for (i, sub_account_index) in T::ActiveSubAccountsIndexList::get().into_iter().enumerate() {
+				let mut targets: Vec<T::AccountId> = (0..per_sub_account)
+					.map(|j| candidates[(i * per_sub_account + j) % candidates.len()].0.clone())
+					.collect();
+				targets.sort();
+
+				if targets != Self::nominations(sub_account_index) {
+					T::HomaXcm::nominate_on_sub_account(sub_account_index, targets.clone())?;
+					Nominations::<T>::insert(sub_account_index, &targets);
+					Self::deposit_event(Event::<T>::NominationsUpdated(sub_account_index, targets));
+				}
+			}
+
+			Ok(())
+		}
+
+		/// The score of validator for nomination: insurance after commission, divided by
+		/// the number of slashes plus one.
+		pub fn validator_score(metrics: &ValidatorMetrics<Balance>) -> Balance {
+			(Perbill::one().saturating_sub(metrics.commission) * metrics.insurance)
+				/ Balance::from(metrics.slash_count).saturating_add(1)
+		}
+
 		pub fn era_amount_should_to_bump(relaychain_block_number: T::BlockNumber) -> EraIndex {
 			relaychain_block_number
 				.checked_sub(&Self::last_era_bumped_block())
@@ -980,6 +1311,7 @@ pub mod module {
 				Self::process_scheduled_unbond(new_era)?;
 				Self::process_to_bond_pool()?;
 				Self::process_redeem_requests(new_era)?;
+				Self::process_nominations()?;
 				Ok(())
 			}();
 
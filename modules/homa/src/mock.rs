@@ -29,7 +29,7 @@ use frame_system::{EnsureRoot, EnsureSignedBy};
 use module_support::mocks::MockAddressMapping;
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, TokenSymbol};
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
 
 pub type AccountId = AccountId32;
@@ -44,6 +44,7 @@ pub const BOB: AccountId = AccountId32::new([2u8; 32]);
 pub const CHARLIE: AccountId = AccountId32::new([3u8; 32]);
 pub const DAVE: AccountId = AccountId32::new([4u8; 32]);
 pub const HOMA_TREASURY: AccountId = AccountId32::new([255u8; 32]);
+pub const INSURANCE_FUND: AccountId = AccountId32::new([254u8; 32]);
 pub const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const STAKING_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
 pub const LIQUID_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
@@ -67,11 +68,96 @@ impl HomaSubAccountXcm<AccountId, Balance> for MockHomaSubAccountXcm {
 		Ok(())
 	}
 
+	fn nominate_on_sub_account(_: u16, _: Vec<AccountId>) -> DispatchResult {
+		Ok(())
+	}
+
 	fn get_xcm_transfer_fee() -> Balance {
 		1_000_000
 	}
 }
 
+/// mock validator list returning `MockValidators`.
+pub struct MockValidatorProvider;
+impl HomaValidatorProvider<AccountId, Balance> for MockValidatorProvider {
+	fn validators(limit: u32) -> Vec<(AccountId, ValidatorMetrics<Balance>)> {
+		MockValidators::get().into_iter().take(limit as usize).collect()
+	}
+}
+
+/// mock DEX swapping liquid currency to staking currency at `MockDexSwapRate`.
+pub struct MockDEX;
+impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
+	fn get_liquidity_pool(_: CurrencyId, _: CurrencyId) -> (Balance, Balance) {
+		unimplemented!()
+	}
+
+	fn get_liquidity_token_address(_: CurrencyId, _: CurrencyId) -> Option<H160> {
+		unimplemented!()
+	}
+
+	fn get_swap_amount(path: &[CurrencyId], limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+		match limit {
+			SwapLimit::ExactSupply(supply_amount, min_target_amount)
+				if path == [LIQUID_CURRENCY_ID, STAKING_CURRENCY_ID] =>
+			{
+				let target_amount = MockDexSwapRate::get().saturating_mul_int(supply_amount);
+				if target_amount >= min_target_amount {
+					Some((supply_amount, target_amount))
+				} else {
+					None
+				}
+			}
+			_ => None,
+		}
+	}
+
+	fn get_best_price_swap_path(
+		_: CurrencyId,
+		_: CurrencyId,
+		_: SwapLimit<Balance>,
+		_: Vec<Vec<CurrencyId>>,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn swap_with_specific_path(
+		who: &AccountId,
+		path: &[CurrencyId],
+		limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let (supply_amount, target_amount) =
+			Self::get_swap_amount(path, limit).ok_or(DispatchError::Other("cannot swap"))?;
+		Currencies::withdraw(path[0], who, supply_amount)?;
+		Currencies::deposit(path[1], who, target_amount)?;
+		Ok((supply_amount, target_amount))
+	}
+
+	fn add_liquidity(
+		_: &AccountId,
+		_: CurrencyId,
+		_: CurrencyId,
+		_: Balance,
+		_: Balance,
+		_: Balance,
+		_: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn remove_liquidity(
+		_: &AccountId,
+		_: CurrencyId,
+		_: CurrencyId,
+		_: Balance,
+		_: Balance,
+		_: Balance,
+		_: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 }
@@ -108,6 +194,10 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -116,7 +206,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -171,12 +266,17 @@ parameter_types! {
 	pub const LiquidCurrencyId: CurrencyId = LIQUID_CURRENCY_ID;
 	pub const HomaPalletId: PalletId = PalletId(*b"aca/homa");
 	pub const TreasuryAccount: AccountId = HOMA_TREASURY;
+	pub const InsuranceFundAccount: AccountId = INSURANCE_FUND;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub ActiveSubAccountsIndexList: Vec<u16> = vec![0, 1, 2];
 	pub const BondingDuration: EraIndex = 28;
 	pub static MintThreshold: Balance = 0;
 	pub static RedeemThreshold: Balance = 0;
 	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
+	pub static MockDexSwapRate: ExchangeRate = ExchangeRate::zero();
+	pub static MockValidators: Vec<(AccountId, ValidatorMetrics<Balance>)> = vec![];
+	pub const MaxNominationsPerSubAccount: u32 = 2;
+	pub const MaxValidatorCandidates: u32 = 10;
 }
 
 impl Config for Runtime {
@@ -187,6 +287,7 @@ impl Config for Runtime {
 	type LiquidCurrencyId = LiquidCurrencyId;
 	type PalletId = HomaPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type InsuranceFundAccount = InsuranceFundAccount;
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type ActiveSubAccountsIndexList = ActiveSubAccountsIndexList;
 	type BondingDuration = BondingDuration;
@@ -194,6 +295,10 @@ impl Config for Runtime {
 	type RedeemThreshold = RedeemThreshold;
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type HomaXcm = MockHomaSubAccountXcm;
+	type DEX = MockDEX;
+	type ValidatorProvider = MockValidatorProvider;
+	type MaxNominationsPerSubAccount = MaxNominationsPerSubAccount;
+	type MaxValidatorCandidates = MaxValidatorCandidates;
 	type WeightInfo = ();
 }
 
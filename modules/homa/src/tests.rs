@@ -734,6 +734,37 @@ fn process_staking_rewards_works() {
 				Currencies::free_balance(LIQUID_CURRENCY_ID, &TreasuryAccount::get()),
 				677_966
 			);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &InsuranceFundAccount::get()),
+				0
+			);
+
+			assert_noop!(
+				Homa::update_insurance_fund_rate(Origin::signed(ALICE), Rate::saturating_from_rational(10, 100)),
+				BadOrigin
+			);
+			assert_ok!(Homa::update_insurance_fund_rate(
+				Origin::signed(HomaAdmin::get()),
+				Rate::saturating_from_rational(10, 100)
+			));
+			System::assert_last_event(Event::Homa(crate::Event::InsuranceFundRateUpdated(
+				Rate::saturating_from_rational(10, 100),
+			)));
+			assert_eq!(Homa::insurance_fund_rate(), Rate::saturating_from_rational(10, 100));
+
+			// accumulate staking rewards, will draw commission to TreasuryAccount and
+			// insurance to InsuranceFundAccount
+			assert_ok!(Homa::process_staking_rewards(3, 2));
+			assert_eq!(Homa::get_total_bonded(), 6_912_000);
+			assert_eq!(Currencies::total_issuance(LIQUID_CURRENCY_ID), 42_080_654);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &TreasuryAccount::get()),
+				1_379_310
+			);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &InsuranceFundAccount::get()),
+				701_344
+			);
 		});
 }
 
@@ -1330,3 +1361,167 @@ fn bump_current_era_works() {
 			);
 		});
 }
+
+#[test]
+fn instant_redeem_works() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, LIQUID_CURRENCY_ID, 1_000_000)])
+		.build()
+		.execute_with(|| {
+			MockDexSwapRate::set(ExchangeRate::saturating_from_rational(9, 100));
+			assert_eq!(
+				Homa::current_exchange_rate(),
+				ExchangeRate::saturating_from_rational(1, 10)
+			);
+
+			assert_noop!(
+				Homa::instant_redeem(Origin::signed(ALICE), 1_000_000),
+				Error::<Runtime>::InstantRedeemUnavailable
+			);
+
+			assert_noop!(
+				Homa::update_instant_redeem_discount(Origin::signed(ALICE), Rate::saturating_from_rational(10, 100)),
+				BadOrigin
+			);
+			assert_ok!(Homa::update_instant_redeem_discount(
+				Origin::signed(HomaAdmin::get()),
+				Rate::saturating_from_rational(10, 100)
+			));
+			System::assert_last_event(Event::Homa(crate::Event::InstantRedeemDiscountUpdated(
+				Rate::saturating_from_rational(10, 100),
+			)));
+			assert_eq!(Homa::instant_redeem_discount(), Rate::saturating_from_rational(10, 100));
+
+			assert_ok!(Homa::instant_redeem(Origin::signed(ALICE), 1_000_000));
+			System::assert_last_event(Event::Homa(crate::Event::InstantRedeemed(ALICE, 1_000_000, 90_000)));
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 0);
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &ALICE), 90_000);
+		});
+}
+
+#[test]
+fn redeem_request_marketplace_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, LIQUID_CURRENCY_ID, 1_000_000),
+			(BOB, STAKING_CURRENCY_ID, 1_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Homa::list_redeem_request(Origin::signed(ALICE), 90_000),
+				Error::<Runtime>::RedeemRequestNotFound
+			);
+
+			assert_ok!(Homa::request_redeem(Origin::signed(ALICE), 1_000_000, false));
+			assert_ok!(Homa::list_redeem_request(Origin::signed(ALICE), 90_000));
+			System::assert_last_event(Event::Homa(crate::Event::RedeemRequestListed(ALICE, 90_000)));
+			assert_eq!(Homa::redeem_request_listings(&ALICE), Some(90_000));
+
+			assert_noop!(
+				Homa::buy_redeem_request(Origin::signed(BOB), CHARLIE, 90_000),
+				Error::<Runtime>::RedeemRequestNotListed
+			);
+			assert_noop!(
+				Homa::buy_redeem_request(Origin::signed(BOB), ALICE, 80_000),
+				Error::<Runtime>::PriceExceedsLimit
+			);
+			assert_ok!(Homa::buy_redeem_request(Origin::signed(BOB), ALICE, 90_000));
+			System::assert_last_event(Event::Homa(crate::Event::RedeemRequestSold(
+				ALICE, BOB, 1_000_000, 90_000,
+			)));
+			assert_eq!(Homa::redeem_requests(&ALICE), None);
+			assert_eq!(Homa::redeem_requests(&BOB), Some((1_000_000, false)));
+			assert_eq!(Homa::redeem_request_listings(&ALICE), None);
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &ALICE), 90_000);
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &BOB), 910_000);
+
+			// changing the request removes the listing
+			assert_ok!(Homa::list_redeem_request(Origin::signed(BOB), 100_000));
+			assert_ok!(Homa::request_redeem(Origin::signed(BOB), 500_000, false));
+			assert_eq!(Homa::redeem_request_listings(&BOB), None);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &BOB), 500_000);
+
+			assert_noop!(
+				Homa::cancel_redeem_request_listing(Origin::signed(BOB)),
+				Error::<Runtime>::RedeemRequestNotListed
+			);
+			assert_ok!(Homa::list_redeem_request(Origin::signed(BOB), 50_000));
+			assert_ok!(Homa::cancel_redeem_request_listing(Origin::signed(BOB)));
+			System::assert_last_event(Event::Homa(crate::Event::RedeemRequestUnlisted(BOB)));
+			assert_eq!(Homa::redeem_request_listings(&BOB), None);
+		});
+}
+
+#[test]
+fn process_nominations_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let validator_1 = AccountId::new([11u8; 32]);
+		let validator_2 = AccountId::new([12u8; 32]);
+		let validator_3 = AccountId::new([13u8; 32]);
+		let metrics = |insurance: Balance, commission: u32, slash_count: u32, is_frozen: bool| ValidatorMetrics {
+			insurance,
+			slash_count,
+			total_slashed: 0,
+			commission: Perbill::from_percent(commission),
+			is_frozen,
+		};
+		MockValidators::set(vec![
+			(validator_1.clone(), metrics(1_000, 10, 0, false)),
+			(validator_2.clone(), metrics(2_000, 0, 1, false)),
+			(validator_3.clone(), metrics(500, 0, 0, false)),
+			(AccountId::new([14u8; 32]), metrics(5_000, 0, 0, true)),
+			(AccountId::new([15u8; 32]), metrics(5_000, 50, 0, false)),
+			(AccountId::new([16u8; 32]), metrics(5_000, 0, 2, false)),
+			(AccountId::new([17u8; 32]), metrics(50, 0, 0, false)),
+		]);
+		assert_eq!(Homa::validator_score(&metrics(1_000, 10, 0, false)), 900);
+		assert_eq!(Homa::validator_score(&metrics(2_000, 0, 1, false)), 1_000);
+
+		// no strategy, nominations are left to governance
+		assert_ok!(Homa::process_nominations());
+		assert_eq!(Homa::nominations(0), vec![]);
+
+		let strategy = NominationStrategy {
+			nominations_per_sub_account: 2,
+			max_candidates: 10,
+			min_insurance: 100,
+			max_commission: Perbill::from_percent(20),
+			max_slash_count: 1,
+		};
+		assert_noop!(
+			Homa::update_nomination_strategy(Origin::signed(ALICE), Some(strategy.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			Homa::update_nomination_strategy(
+				Origin::signed(HomaAdmin::get()),
+				Some(NominationStrategy {
+					nominations_per_sub_account: 3,
+					..strategy.clone()
+				})
+			),
+			Error::<Runtime>::InvalidNominationStrategy
+		);
+		assert_ok!(Homa::update_nomination_strategy(
+			Origin::signed(HomaAdmin::get()),
+			Some(strategy.clone())
+		));
+		System::assert_last_event(Event::Homa(crate::Event::NominationStrategyUpdated(Some(strategy))));
+
+		// candidates by score: validator_2, validator_1, validator_3
+		assert_ok!(Homa::process_nominations());
+		assert_eq!(Homa::nominations(0), vec![validator_1.clone(), validator_2.clone()]);
+		assert_eq!(Homa::nominations(1), vec![validator_2.clone(), validator_3.clone()]);
+		assert_eq!(Homa::nominations(2), vec![validator_1.clone(), validator_3.clone()]);
+		System::assert_has_event(Event::Homa(crate::Event::NominationsUpdated(
+			1,
+			vec![validator_2, validator_3],
+		)));
+
+		// unchanged nominations are not sent again
+		System::reset_events();
+		assert_ok!(Homa::process_nominations());
+		assert_eq!(System::events(), vec![]);
+	});
+}
@@ -0,0 +1,39 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for nft module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+// The `unnecessary_mut_passed` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+pub use module_nft::{OwnershipTree, TokenNode};
+
+sp_api::decl_runtime_apis! {
+	pub trait NftApi<AccountId, ClassId, TokenId> where
+		AccountId: Codec,
+		ClassId: Codec,
+		TokenId: Codec,
+	{
+		/// The ownership tree of the token, `None` if the token doesn't exist.
+		fn ownership_tree(class_id: ClassId, token_id: TokenId) -> Option<OwnershipTree<AccountId, ClassId, TokenId>>;
+	}
+}
@@ -0,0 +1,103 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the nft module.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use self::gen_client::Client as NftClient;
+pub use module_nft_rpc_runtime_api::{NftApi as NftRuntimeApi, OwnershipTree, TokenNode};
+
+#[rpc]
+pub trait NftApi<BlockHash, AccountId, ClassId, TokenId> {
+	/// Returns the owner of the root token, the ancestors and the nested tokens of the
+	/// token.
+	#[rpc(name = "nft_ownershipTree")]
+	fn ownership_tree(
+		&self,
+		class_id: ClassId,
+		token_id: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<Option<OwnershipTree<AccountId, ClassId, TokenId>>>;
+}
+
+/// A struct that implements the [`NftApi`].
+pub struct Nft<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Nft<C, B> {
+	/// Create new `Nft` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, ClassId, TokenId> NftApi<<Block as BlockT>::Hash, AccountId, ClassId, TokenId>
+	for Nft<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: NftRuntimeApi<Block, AccountId, ClassId, TokenId>,
+	AccountId: Codec,
+	ClassId: Codec,
+	TokenId: Codec,
+{
+	fn ownership_tree(
+		&self,
+		class_id: ClassId,
+		token_id: TokenId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<OwnershipTree<AccountId, ClassId, TokenId>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.ownership_tree(&at, class_id, token_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query ownership tree.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
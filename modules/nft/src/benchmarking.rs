@@ -59,7 +59,8 @@ fn create_token_class<T: Config>(caller: T::AccountId) -> Result<T::AccountId, D
 			ClassProperty::Transferable
 				| ClassProperty::Burnable
 				| ClassProperty::Mintable
-				| ClassProperty::ClassPropertiesMutable,
+				| ClassProperty::ClassPropertiesMutable
+				| ClassProperty::TokenAttributesMutable,
 		),
 		test_attr(),
 	)?;
@@ -146,6 +147,142 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 	}: _(RawOrigin::Signed(module_account), 0u32.into(), Properties(ClassProperty::Transferable.into()))
+
+	set_royalty {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let royalty = RoyaltyInfo { beneficiary: caller.clone(), rate: T::MaxRoyalty::get() };
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), Some(royalty))
+
+	list_token {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into())
+
+	list_token_for_auction {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into(), 100u32.into())
+
+	cancel_listing {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::list_token(RawOrigin::Signed(to.clone()).into(), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()))
+
+	buy_token {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+
+		let module_account = create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::set_royalty(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Some(RoyaltyInfo { beneficiary: caller, rate: T::MaxRoyalty::get() }),
+		)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::list_token(RawOrigin::Signed(to).into(), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into())?;
+		<T as module::Config>::Currency::make_free_balance_be(&buyer, dollar(1000).unique_saturated_into());
+	}: _(RawOrigin::Signed(buyer), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into())
+
+	fractionalise {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into())
+
+	redeem_fraction {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::fractionalise(RawOrigin::Signed(to.clone()).into(), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()))
+
+	update_token_attributes {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], Default::default(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()), test_attr())
+
+	freeze_token_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()))
+
+	set_attribute_updater {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let updater: T::AccountId = account("updater", 0, SEED);
+		let updater_lookup = T::Lookup::unlookup(updater);
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), updater_lookup, true)
+
+	set_attribute_schema {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let mut schema: AttributeSchema = BTreeMap::new();
+		for i in 0..30 {
+			schema.insert(vec![i], AttributeValueType::Bytes);
+		}
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), Some(schema))
+
+	nest {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 2)?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 1u32.into()), (0u32.into(), 0u32.into()))
+
+	unnest {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 2)?;
+		crate::Pallet::<T>::nest(RawOrigin::Signed(to.clone()).into(), (0u32.into(), 1u32.into()), (0u32.into(), 0u32.into()))?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 1u32.into()))
 }
 
 #[cfg(test)]
@@ -156,15 +293,17 @@ mod mock {
 	use codec::{Decode, Encode};
 	use frame_support::{
 		parameter_types,
-		traits::{Contains, InstanceFilter},
+		traits::{Contains, InstanceFilter, Nothing, Randomness},
 		weights::Weight,
 		PalletId, RuntimeDebug,
 	};
+	use orml_traits::parameter_type_with_key;
+	use primitives::{Amount, AuctionId, TokenSymbol};
 	use sp_core::{crypto::AccountId32, H256};
 	use sp_runtime::{
 		testing::Header,
-		traits::{BlakeTwo256, IdentityLookup},
-		Perbill,
+		traits::{BlakeTwo256, Hash, IdentityLookup},
+		Perbill, Permill,
 	};
 
 	parameter_types! {
@@ -279,12 +418,65 @@ mod mock {
 		type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	}
 
+	parameter_type_with_key! {
+		pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+			Default::default()
+		};
+	}
+
+	parameter_types! {
+		pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+	}
+
+	impl orml_tokens::Config for Runtime {
+		type Event = ();
+		type Balance = Balance;
+		type Amount = Amount;
+		type CurrencyId = CurrencyId;
+		type WeightInfo = ();
+		type ExistentialDeposits = ExistentialDeposits;
+		type OnDust = ();
+		type TransferHooks = ();
+		type MaxLocks = ();
+		type MaxReserves = MaxReserves;
+		type ReserveIdentifier = [u8; 8];
+		type ApprovalDeposit = ExistentialDeposits;
+		type ApprovalReserveId = TokensApprovalReserveId;
+		type DustRemovalWhitelist = Nothing;
+	}
+
+	pub struct MockRandomness;
+	impl Randomness<H256, u64> for MockRandomness {
+		fn random(subject: &[u8]) -> (H256, u64) {
+			(BlakeTwo256::hash(subject), 0)
+		}
+	}
+
+	impl orml_auction::Config for Runtime {
+		type Event = ();
+		type Balance = Balance;
+		type AuctionId = AuctionId;
+		type Handler = NFT;
+		type Randomness = MockRandomness;
+		type WeightInfo = ();
+	}
+
+	pub struct ShareCurrencyId;
+	impl Convert<(u32, u64), Option<CurrencyId>> for ShareCurrencyId {
+		fn convert(_token: (u32, u64)) -> Option<CurrencyId> {
+			Some(CurrencyId::Token(TokenSymbol::ACA))
+		}
+	}
+
 	parameter_types! {
 		pub const CreateClassDeposit: Balance = 200;
 		pub const CreateTokenDeposit: Balance = 100;
 		pub const DataDepositPerByte: Balance = 10;
 		pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
 		pub MaxAttributesBytes: u32 = 2048;
+		pub const MaxRoyalty: Permill = Permill::from_percent(20);
+		pub const MaxNestingDepth: u32 = 3;
+		pub const MaxChildren: u32 = 2;
 	}
 
 	impl crate::Config for Runtime {
@@ -295,6 +487,12 @@ mod mock {
 		type DataDepositPerByte = DataDepositPerByte;
 		type PalletId = NftPalletId;
 		type MaxAttributesBytes = MaxAttributesBytes;
+		type MaxRoyalty = MaxRoyalty;
+		type Auction = AuctionModule;
+		type ShareCurrency = Tokens;
+		type ShareCurrencyId = ShareCurrencyId;
+		type MaxNestingDepth = MaxNestingDepth;
+		type MaxChildren = MaxChildren;
 		type WeightInfo = ();
 	}
 
@@ -327,6 +525,8 @@ mod mock {
 			Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 			OrmlNFT: orml_nft::{Pallet, Storage, Config<T>},
 			NFT: nft::{Pallet, Call, Event<T>},
+			Tokens: orml_tokens::{Pallet, Storage},
+			AuctionModule: orml_auction::{Pallet, Call, Storage},
 		}
 	);
 
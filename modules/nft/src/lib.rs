@@ -27,20 +27,20 @@ use frame_support::{
 	require_transactional,
 	traits::{
 		Currency,
-		ExistenceRequirement::{AllowDeath, KeepAlive},
+		ExistenceRequirement::{self, AllowDeath, KeepAlive},
 		NamedReservableCurrency,
 	},
 	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
-use orml_traits::NFT;
-use primitives::{NFTBalance, ReserveIdentifier};
+use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, OnNewBidResult, NFT};
+use primitives::{AuctionId, CurrencyId, NFTBalance, ReserveIdentifier};
 use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	traits::{AccountIdConversion, Convert, Hash, Saturating, StaticLookup, Zero},
+	DispatchResult, Permill, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
@@ -54,6 +54,38 @@ pub use weights::WeightInfo;
 
 pub type CID = Vec<u8>;
 pub type Attributes = BTreeMap<Vec<u8>, Vec<u8>>;
+pub type AttributeSchema = BTreeMap<Vec<u8>, AttributeValueType>;
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AttributeValueType {
+	/// Any bytes
+	Bytes,
+	/// SCALE encoded bool
+	Bool,
+	/// SCALE encoded u32
+	U32,
+	/// SCALE encoded u64
+	U64,
+	/// SCALE encoded u128
+	U128,
+	/// UTF-8 string
+	Utf8,
+}
+
+impl AttributeValueType {
+	/// Check whether `value` is a valid value of the type.
+	pub fn is_valid(&self, value: &[u8]) -> bool {
+		match self {
+			AttributeValueType::Bytes => true,
+			AttributeValueType::Bool => value == [0] || value == [1],
+			AttributeValueType::U32 => value.len() == 4,
+			AttributeValueType::U64 => value.len() == 8,
+			AttributeValueType::U128 => value.len() == 16,
+			AttributeValueType::Utf8 => sp_std::str::from_utf8(value).is_ok(),
+		}
+	}
+}
 
 #[repr(u8)]
 #[derive(Encode, Decode, Clone, Copy, BitFlags, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -66,6 +98,10 @@ pub enum ClassProperty {
 	Mintable = 0b00000100,
 	/// Is class properties mutable
 	ClassPropertiesMutable = 0b00001000,
+	/// Is token attributes mutable
+	TokenAttributesMutable = 0b00010000,
+	/// Burning a token also burns the tokens nested in it, instead of being rejected
+	NestedBurnCascade = 0b00100000,
 }
 
 #[derive(Clone, Copy, PartialEq, Default, RuntimeDebug)]
@@ -118,6 +154,60 @@ pub struct TokenData<Balance> {
 	pub attributes: Attributes,
 }
 
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct RoyaltyInfo<AccountId> {
+	/// The account receiving the royalty
+	pub beneficiary: AccountId,
+	/// The share of the sale price paid as royalty
+	pub rate: Permill,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum ListingKind<Balance> {
+	/// Sold to the first buyer paying `price`
+	FixedPrice { price: Balance },
+	/// Sold to the winner of `auction_id` if the winning bid is at least `min_price`
+	Auction { auction_id: AuctionId, min_price: Balance },
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct Listing<AccountId, Balance> {
+	/// The owner of the token before it was listed
+	pub seller: AccountId,
+	/// How the token is sold
+	pub kind: ListingKind<Balance>,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct FractionInfo<Balance> {
+	/// The currency of the shares
+	pub currency_id: CurrencyId,
+	/// Total amount of the shares
+	pub shares: Balance,
+}
+
+/// A token and the tokens nested in it.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TokenNode<ClassId, TokenId> {
+	/// The token
+	pub token: (ClassId, TokenId),
+	/// The tokens directly nested in the token
+	pub children: Vec<TokenNode<ClassId, TokenId>>,
+}
+
+/// The ownership tree of a nested token.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OwnershipTree<AccountId, ClassId, TokenId> {
+	/// The account owning the root token
+	pub root_owner: AccountId,
+	/// The path from the root token to the parent of the queried token
+	pub ancestors: Vec<(ClassId, TokenId)>,
+	/// The queried token and its descendants
+	pub node: TokenNode<ClassId, TokenId>,
+}
+
 pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
 pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
 pub type BalanceOf<T> =
@@ -164,6 +254,28 @@ pub mod module {
 		#[pallet::constant]
 		type MaxAttributesBytes: Get<u32>;
 
+		/// Maximum royalty rate of a class
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+
+		/// The auction to sell listed tokens, its handler must be this module.
+		type Auction: Auction<Self::AccountId, Self::BlockNumber, AuctionId = AuctionId, Balance = BalanceOf<Self>>;
+
+		/// The currency to mint the shares of fractionalised tokens.
+		type ShareCurrency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = BalanceOf<Self>>;
+
+		/// The currency id of the shares of a token. Return `None` if the token can't be
+		/// fractionalised.
+		type ShareCurrencyId: Convert<(ClassIdOf<Self>, TokenIdOf<Self>), Option<CurrencyId>>;
+
+		/// Maximum depth of nested tokens, a token without parent has depth 0
+		#[pallet::constant]
+		type MaxNestingDepth: Get<u32>;
+
+		/// Maximum number of tokens directly nested in a token
+		#[pallet::constant]
+		type MaxChildren: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -191,6 +303,40 @@ pub mod module {
 		Immutable,
 		/// Attributes too large
 		AttributesTooLarge,
+		/// Royalty rate exceeds `MaxRoyalty`
+		RoyaltyTooHigh,
+		/// The token is not listed
+		ListingNotFound,
+		/// The listing price exceeds the price limit of the buyer
+		PriceExceedsLimit,
+		/// The auction has bids and can't be cancelled
+		AuctionHasBids,
+		/// Invalid auction duration
+		InvalidDuration,
+		/// The token can't be fractionalised
+		FractionalisationNotSupported,
+		/// The token is not fractionalised
+		FractionNotFound,
+		/// Not holding all the shares of the token
+		InsufficientShares,
+		/// The token metadata is frozen
+		MetadataFrozen,
+		/// Attributes don't match the schema of the class
+		AttributesNotMatchSchema,
+		/// The token can't be nested in itself or its descendants
+		NestingCycle,
+		/// Nesting exceeds `MaxNestingDepth`
+		NestingTooDeep,
+		/// The token is not nested
+		NotNested,
+		/// The token has nested tokens and its class doesn't cascade burns
+		TokenHasChildren,
+		/// The token is held by the escrow account
+		TokenInEscrow,
+		/// The class still has attribute updaters
+		AttributeUpdatersExist,
+		/// The parent has `MaxChildren` nested tokens
+		TooManyChildren,
 	}
 
 	#[pallet::event]
@@ -233,8 +379,181 @@ pub mod module {
 			owner: T::AccountId,
 			class_id: ClassIdOf<T>,
 		},
+		/// Updated the royalty of NFT class.
+		RoyaltyUpdated {
+			class_id: ClassIdOf<T>,
+			royalty: Option<RoyaltyInfo<T::AccountId>>,
+		},
+		/// Listed NFT token for sale.
+		TokenListed {
+			seller: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			kind: ListingKind<BalanceOf<T>>,
+		},
+		/// Cancelled NFT token listing.
+		ListingCancelled {
+			seller: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Sold NFT token.
+		TokenSold {
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			price: BalanceOf<T>,
+			royalty: BalanceOf<T>,
+		},
+		/// Fractionalised NFT token.
+		TokenFractionalised {
+			owner: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			currency_id: CurrencyId,
+			shares: BalanceOf<T>,
+		},
+		/// Redeemed fractionalised NFT token.
+		FractionRedeemed {
+			who: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Updated the attributes of NFT token.
+		UpdatedTokenAttributes {
+			who: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Froze the metadata of NFT token.
+		FrozeTokenMetadata {
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Added or removed an attribute updater of NFT class.
+		AttributeUpdaterSet {
+			class_id: ClassIdOf<T>,
+			who: T::AccountId,
+			enabled: bool,
+		},
+		/// Updated the attribute schema of NFT class.
+		AttributeSchemaUpdated { class_id: ClassIdOf<T> },
+		/// Nested NFT token in another token.
+		TokenNested {
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			parent: (ClassIdOf<T>, TokenIdOf<T>),
+		},
+		/// Took nested NFT token out of its parent.
+		TokenUnnested {
+			owner: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
 	}
 
+	/// The royalty of NFT classes.
+	///
+	/// ClassRoyalties: map ClassId => Option<RoyaltyInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn class_royalties)]
+	pub type ClassRoyalties<T: Config> =
+		StorageMap<_, Twox64Concat, ClassIdOf<T>, RoyaltyInfo<T::AccountId>, OptionQuery>;
+
+	/// The listed NFT tokens, held by the escrow account until sold or cancelled.
+	///
+	/// Listings: double_map ClassId, TokenId => Option<Listing>
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClassIdOf<T>,
+		Twox64Concat,
+		TokenIdOf<T>,
+		Listing<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The NFT token sold by an auction.
+	///
+	/// AuctionListings: map AuctionId => Option<(ClassId, TokenId)>
+	#[pallet::storage]
+	#[pallet::getter(fn auction_listings)]
+	pub type AuctionListings<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, (ClassIdOf<T>, TokenIdOf<T>), OptionQuery>;
+
+	/// The fractionalised NFT tokens, held by the escrow account until redeemed.
+	///
+	/// Fractions: double_map ClassId, TokenId => Option<FractionInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn fractions)]
+	pub type Fractions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClassIdOf<T>,
+		Twox64Concat,
+		TokenIdOf<T>,
+		FractionInfo<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The accounts allowed to update the token attributes of NFT classes, besides the
+	/// class owner.
+	///
+	/// AttributeUpdaters: double_map ClassId, AccountId => ()
+	#[pallet::storage]
+	#[pallet::getter(fn attribute_updaters)]
+	pub type AttributeUpdaters<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// The attribute schema of NFT classes, checked on mint and update.
+	///
+	/// ClassAttributeSchemas: map ClassId => Option<AttributeSchema>
+	#[pallet::storage]
+	#[pallet::getter(fn class_attribute_schemas)]
+	pub type ClassAttributeSchemas<T: Config> = StorageMap<_, Twox64Concat, ClassIdOf<T>, AttributeSchema, OptionQuery>;
+
+	/// The NFT tokens whose metadata is permanently immutable.
+	///
+	/// FrozenTokens: double_map ClassId, TokenId => ()
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_tokens)]
+	pub type FrozenTokens<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, TokenIdOf<T>, (), OptionQuery>;
+
+	/// The parent of nested NFT tokens. A nested token is owned by the nest account of
+	/// its parent.
+	///
+	/// TokenParents: double_map ClassId, TokenId => Option<(ClassId, TokenId)>
+	#[pallet::storage]
+	#[pallet::getter(fn token_parents)]
+	pub type TokenParents<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClassIdOf<T>,
+		Twox64Concat,
+		TokenIdOf<T>,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		OptionQuery,
+	>;
+
+	/// The tokens directly nested in NFT tokens.
+	///
+	/// TokenChildren: double_map (ClassId, TokenId), (ClassId, TokenId) => ()
+	#[pallet::storage]
+	#[pallet::getter(fn token_children)]
+	pub type TokenChildren<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Twox64Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -327,30 +646,39 @@ pub mod module {
 		/// Burn NFT token
 		///
 		/// - `token`: (class_id, token_id)
-		#[pallet::weight(<T as Config>::WeightInfo::burn())]
+		#[pallet::weight(<T as Config>::WeightInfo::burn()
+			.saturating_mul(Pallet::<T>::max_descendants().saturating_add(1).into()))]
 		#[transactional]
-		pub fn burn(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		pub fn burn(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::do_burn(who, token, None)
+			let burned_children = Self::do_burn(who, token, None)?;
+			Ok(Some(<T as Config>::WeightInfo::burn().saturating_mul(burned_children.saturating_add(1).into())).into())
 		}
 
 		/// Burn NFT token
 		///
 		/// - `token`: (class_id, token_id)
 		/// - `remark`: Vec<u8>
-		#[pallet::weight(<T as Config>::WeightInfo::burn_with_remark(remark.len() as u32))]
+		#[pallet::weight(<T as Config>::WeightInfo::burn_with_remark(remark.len() as u32)
+			.saturating_add(<T as Config>::WeightInfo::burn().saturating_mul(Pallet::<T>::max_descendants().into())))]
 		#[transactional]
 		pub fn burn_with_remark(
 			origin: OriginFor<T>,
 			token: (ClassIdOf<T>, TokenIdOf<T>),
 			remark: Vec<u8>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::do_burn(who, token, Some(remark))
+			let weight = <T as Config>::WeightInfo::burn_with_remark(remark.len() as u32);
+			let burned_children = Self::do_burn(who, token, Some(remark))?;
+			Ok(
+				Some(weight.saturating_add(<T as Config>::WeightInfo::burn().saturating_mul(burned_children.into())))
+					.into(),
+			)
 		}
 
 		/// Destroy NFT class, remove dest from proxy, and send all the free
-		/// balance to dest
+		/// balance to dest. The attribute updaters of the class must be removed
+		/// beforehand.
 		///
 		/// - `class_id`: The class ID to destroy
 		/// - `dest`: The proxy account that will receive free balance
@@ -369,12 +697,19 @@ pub mod module {
 				class_info.total_issuance == Zero::zero(),
 				Error::<T>::CannotDestroyClass
 			);
+			ensure!(
+				AttributeUpdaters::<T>::iter_prefix(class_id).next().is_none(),
+				Error::<T>::AttributeUpdatersExist
+			);
 
 			let data = class_info.data;
 
 			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, data.deposit);
 
 			orml_nft::Pallet::<T>::destroy_class(&who, class_id)?;
+			ClassRoyalties::<T>::remove(class_id);
+			ClassAttributeSchemas::<T>::remove(class_id);
+			// burnt tokens are unfrozen, there are no frozen tokens left
 
 			// this should unresere proxy deposit
 			pallet_proxy::Pallet::<T>::remove_proxy_delegate(&who, dest.clone(), Default::default(), Zero::zero())?;
@@ -418,6 +753,350 @@ pub mod module {
 				Ok(())
 			})
 		}
+
+		/// Update the royalty paid on every sale of the tokens of NFT class.
+		///
+		/// - `class_id`: The class ID to update
+		/// - `royalty`: The new royalty, `None` to remove it
+		#[pallet::weight(<T as Config>::WeightInfo::set_royalty())]
+		#[transactional]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			royalty: Option<RoyaltyInfo<T::AccountId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			if let Some(royalty) = &royalty {
+				ensure!(royalty.rate <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+			}
+
+			ClassRoyalties::<T>::set(class_id, royalty.clone());
+
+			Self::deposit_event(Event::RoyaltyUpdated { class_id, royalty });
+			Ok(())
+		}
+
+		/// List NFT token for sale at a fixed price. The token is held by the escrow
+		/// account until sold or cancelled.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `price`: the sale price
+		#[pallet::weight(<T as Config>::WeightInfo::list_token())]
+		#[transactional]
+		pub fn list_token(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_list(who, token, ListingKind::FixedPrice { price })
+		}
+
+		/// List NFT token for sale by auction. The token is held by the escrow account
+		/// until the auction ends.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `min_price`: the reserve price of the auction
+		/// - `duration`: the number of blocks the auction lasts
+		#[pallet::weight(<T as Config>::WeightInfo::list_token_for_auction())]
+		#[transactional]
+		pub fn list_token_for_auction(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] min_price: BalanceOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!duration.is_zero(), Error::<T>::InvalidDuration);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let auction_id = T::Auction::new_auction(now, Some(now.saturating_add(duration)))?;
+			AuctionListings::<T>::insert(auction_id, token);
+
+			Self::do_list(who, token, ListingKind::Auction { auction_id, min_price })
+		}
+
+		/// Cancel the listing of NFT token and return it to the seller. An auction
+		/// listing can only be cancelled before the first bid.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		#[transactional]
+		pub fn cancel_listing(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::ListingNotFound)?;
+			ensure!(who == listing.seller, Error::<T>::NoPermission);
+
+			if let ListingKind::Auction { auction_id, .. } = listing.kind {
+				ensure!(
+					T::Auction::auction_info(auction_id).map_or(true, |info| info.bid.is_none()),
+					Error::<T>::AuctionHasBids
+				);
+				T::Auction::remove_auction(auction_id);
+				AuctionListings::<T>::remove(auction_id);
+			}
+
+			Self::do_cancel_listing(&who, token)
+		}
+
+		/// Buy NFT token listed at a fixed price. The royalty of the class is paid from
+		/// the price.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `max_price`: the maximum price to pay
+		#[pallet::weight(<T as Config>::WeightInfo::buy_token())]
+		#[transactional]
+		pub fn buy_token(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::ListingNotFound)?;
+			let price = match listing.kind {
+				ListingKind::FixedPrice { price } => price,
+				ListingKind::Auction { .. } => return Err(Error::<T>::ListingNotFound.into()),
+			};
+			ensure!(price <= max_price, Error::<T>::PriceExceedsLimit);
+
+			Self::do_settle_sale(&who, &who, listing.seller, token, price, KeepAlive)
+		}
+
+		/// Lock NFT token in the escrow account and mint its shares to the owner. The
+		/// token can be redeemed by whoever holds all the shares.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `shares`: the amount of shares to mint
+		#[pallet::weight(<T as Config>::WeightInfo::fractionalise())]
+		#[transactional]
+		pub fn fractionalise(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] shares: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!shares.is_zero(), Error::<T>::InvalidQuantity);
+			let currency_id = T::ShareCurrencyId::convert(token).ok_or(Error::<T>::FractionalisationNotSupported)?;
+			ensure!(
+				T::ShareCurrency::total_issuance(currency_id).is_zero(),
+				Error::<T>::FractionalisationNotSupported
+			);
+
+			Self::do_transfer(&who, &Self::escrow_account_id(), token)?;
+			T::ShareCurrency::deposit(currency_id, &who, shares)?;
+			Fractions::<T>::insert(token.0, token.1, FractionInfo { currency_id, shares });
+
+			Self::deposit_event(Event::TokenFractionalised {
+				owner: who,
+				class_id: token.0,
+				token_id: token.1,
+				currency_id,
+				shares,
+			});
+			Ok(())
+		}
+
+		/// Burn all the shares of fractionalised NFT token and take the token.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_fraction())]
+		#[transactional]
+		pub fn redeem_fraction(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let info = Fractions::<T>::take(token.0, token.1).ok_or(Error::<T>::FractionNotFound)?;
+			ensure!(
+				T::ShareCurrency::free_balance(info.currency_id, &who) >= info.shares,
+				Error::<T>::InsufficientShares
+			);
+
+			T::ShareCurrency::withdraw(info.currency_id, &who, info.shares)?;
+			Self::do_transfer(&Self::escrow_account_id(), &who, token)?;
+
+			Self::deposit_event(Event::FractionRedeemed {
+				who,
+				class_id: token.0,
+				token_id: token.1,
+			});
+			Ok(())
+		}
+
+		/// Replace the attributes of NFT token. The class must be
+		/// TokenAttributesMutable. The caller must be the class owner or an attribute
+		/// updater of the class, and pays for the increase of the token deposit.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `attributes`: The new attributes
+		#[pallet::weight(<T as Config>::WeightInfo::update_token_attributes())]
+		#[transactional]
+		pub fn update_token_attributes(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			attributes: Attributes,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_update_token_attributes(&who, token, attributes)?;
+
+			Self::deposit_event(Event::UpdatedTokenAttributes {
+				who,
+				class_id: token.0,
+				token_id: token.1,
+			});
+			Ok(())
+		}
+
+		/// Freeze the metadata and attributes of NFT token permanently.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::freeze_token_metadata())]
+		#[transactional]
+		pub fn freeze_token_metadata(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				orml_nft::Tokens::<T>::contains_key(token.0, token.1),
+				Error::<T>::TokenIdNotFound
+			);
+
+			FrozenTokens::<T>::insert(token.0, token.1, ());
+
+			Self::deposit_event(Event::FrozeTokenMetadata {
+				class_id: token.0,
+				token_id: token.1,
+			});
+			Ok(())
+		}
+
+		/// Allow or disallow an account to update the token attributes of NFT class.
+		///
+		/// - `class_id`: The class ID
+		/// - `updater`: The account
+		/// - `enabled`: Whether the account can update the token attributes
+		#[pallet::weight(<T as Config>::WeightInfo::set_attribute_updater())]
+		#[transactional]
+		pub fn set_attribute_updater(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			updater: <T::Lookup as StaticLookup>::Source,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let updater = T::Lookup::lookup(updater)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+
+			if enabled {
+				AttributeUpdaters::<T>::insert(class_id, &updater, ());
+			} else {
+				AttributeUpdaters::<T>::remove(class_id, &updater);
+			}
+
+			Self::deposit_event(Event::AttributeUpdaterSet {
+				class_id,
+				who: updater,
+				enabled,
+			});
+			Ok(())
+		}
+
+		/// Update the attribute schema of NFT class. Token attributes are checked against
+		/// the schema on mint and update.
+		///
+		/// - `class_id`: The class ID
+		/// - `schema`: The new schema, `None` to remove it
+		#[pallet::weight(<T as Config>::WeightInfo::set_attribute_schema())]
+		#[transactional]
+		pub fn set_attribute_schema(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			schema: Option<AttributeSchema>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+
+			if let Some(schema) = &schema {
+				let schema_len = schema
+					.keys()
+					.fold(0u32, |acc, k| acc.saturating_add(k.len().saturating_add(1) as u32));
+				ensure!(
+					schema_len <= T::MaxAttributesBytes::get(),
+					Error::<T>::AttributesTooLarge
+				);
+			}
+
+			ClassAttributeSchemas::<T>::set(class_id, schema);
+
+			Self::deposit_event(Event::AttributeSchemaUpdated { class_id });
+			Ok(())
+		}
+
+		/// Nest NFT token in another token. The token is owned by the nest account of
+		/// the parent and moves with it. The caller must own the token and the root of
+		/// the parent.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `parent`: (class_id, token_id) of the new parent
+		#[pallet::weight(<T as Config>::WeightInfo::nest()
+			.saturating_add(T::DbWeight::get().reads(Pallet::<T>::max_descendants().into())))]
+		#[transactional]
+		pub fn nest(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			parent: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::owner_of(token)? == who, Error::<T>::NoPermission);
+
+			let ancestors = Self::ancestors(parent);
+			ensure!(token != parent && !ancestors.contains(&token), Error::<T>::NestingCycle);
+			let root = ancestors.last().copied().unwrap_or(parent);
+			ensure!(Self::owner_of(root)? == who, Error::<T>::NoPermission);
+			// the deepest descendant of `token` ends up at depth `depth(parent) + 1 + height(token)`
+			let depth = (ancestors.len() as u32)
+				.saturating_add(1)
+				.saturating_add(Self::height(token));
+			ensure!(depth <= T::MaxNestingDepth::get(), Error::<T>::NestingTooDeep);
+			ensure!(
+				(TokenChildren::<T>::iter_key_prefix(parent).count() as u32) < T::MaxChildren::get(),
+				Error::<T>::TooManyChildren
+			);
+
+			Self::do_transfer(&who, &Self::nest_account_id(parent), token)?;
+			TokenParents::<T>::insert(token.0, token.1, parent);
+			TokenChildren::<T>::insert(parent, token, ());
+
+			Self::deposit_event(Event::TokenNested {
+				class_id: token.0,
+				token_id: token.1,
+				parent,
+			});
+			Ok(())
+		}
+
+		/// Take nested NFT token out of its parent and give it to the caller, who must
+		/// own the root of the token.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::unnest())]
+		#[transactional]
+		pub fn unnest(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::root_owner(token)? == who, Error::<T>::NoPermission);
+			let parent = TokenParents::<T>::take(token.0, token.1).ok_or(Error::<T>::NotNested)?;
+
+			TokenChildren::<T>::remove(parent, token);
+			Self::do_transfer(&Self::nest_account_id(parent), &who, token)?;
+
+			Self::deposit_event(Event::TokenUnnested {
+				owner: who,
+				class_id: token.0,
+				token_id: token.1,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -465,6 +1144,7 @@ impl<T: Config> Pallet<T> {
 			class_info.data.properties.0.contains(ClassProperty::Mintable),
 			Error::<T>::NonMintable
 		);
+		Self::check_schema(class_id, &attributes)?;
 
 		let data_deposit = Self::data_deposit(&metadata, &attributes)?;
 		let deposit = T::CreateTokenDeposit::get().saturating_add(data_deposit);
@@ -489,7 +1169,12 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
+	/// Burn `token` and the tokens nested in it, returns the number of burned nested tokens.
+	fn do_burn(
+		who: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		remark: Option<Vec<u8>>,
+	) -> Result<u32, DispatchError> {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
 		ensure!(
@@ -500,7 +1185,9 @@ impl<T: Config> Pallet<T> {
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
 
+		let burned_children = Self::burn_children(&who, token, data.properties)?;
 		orml_nft::Pallet::<T>::burn(&who, token)?;
+		FrozenTokens::<T>::remove(token.0, token.1);
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, token_info.data.deposit);
 
@@ -520,6 +1207,313 @@ impl<T: Config> Pallet<T> {
 			});
 		}
 
+		Ok(burned_children)
+	}
+
+	/// Burn the tokens nested in `token`, or reject if `properties` of its class
+	/// doesn't contain NestedBurnCascade. The deposits of the burned tokens are returned
+	/// to `who`. Returns the number of burned tokens.
+	#[require_transactional]
+	fn burn_children(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		properties: Properties,
+	) -> Result<u32, DispatchError> {
+		let children = TokenChildren::<T>::iter_key_prefix(token).collect::<Vec<_>>();
+		if children.is_empty() {
+			return Ok(0);
+		}
+		ensure!(
+			properties.0.contains(ClassProperty::NestedBurnCascade),
+			Error::<T>::TokenHasChildren
+		);
+
+		let holder = Self::nest_account_id(token);
+		let mut burned: u32 = 0;
+		for child in children {
+			let class_info = orml_nft::Pallet::<T>::classes(child.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			let properties = class_info.data.properties;
+			ensure!(properties.0.contains(ClassProperty::Burnable), Error::<T>::NonBurnable);
+			let token_info = orml_nft::Pallet::<T>::tokens(child.0, child.1).ok_or(Error::<T>::TokenIdNotFound)?;
+
+			// recursion is bounded by `MaxNestingDepth`
+			let burned_children = Self::burn_children(who, child, properties)?;
+			burned = burned.saturating_add(burned_children).saturating_add(1);
+			orml_nft::Pallet::<T>::burn(&holder, child)?;
+			FrozenTokens::<T>::remove(child.0, child.1);
+			TokenParents::<T>::remove(child.0, child.1);
+			TokenChildren::<T>::remove(token, child);
+
+			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &holder, token_info.data.deposit);
+			<T as module::Config>::Currency::transfer(&holder, who, token_info.data.deposit, AllowDeath)?;
+
+			Self::deposit_event(Event::BurnedToken {
+				owner: holder.clone(),
+				class_id: child.0,
+				token_id: child.1,
+			});
+		}
+		Ok(burned)
+	}
+
+	/// The maximum number of tokens nested below a token, bounded by `MaxChildren` and
+	/// `MaxNestingDepth`.
+	pub fn max_descendants() -> u32 {
+		let max_children = T::MaxChildren::get();
+		(0..T::MaxNestingDepth::get())
+			.fold((0u32, 1u32), |(total, level), _| {
+				let level = level.saturating_mul(max_children);
+				(total.saturating_add(level), level)
+			})
+			.0
+	}
+
+	/// The account owning the tokens nested in `token`.
+	pub fn nest_account_id(token: (ClassIdOf<T>, TokenIdOf<T>)) -> T::AccountId {
+		T::PalletId::get().into_sub_account((b"nest", token.0, token.1))
+	}
+
+	/// The ancestors of `token`, from its parent to the root.
+	pub fn ancestors(token: (ClassIdOf<T>, TokenIdOf<T>)) -> Vec<(ClassIdOf<T>, TokenIdOf<T>)> {
+		let mut ancestors = Vec::new();
+		let mut current = token;
+		// the loop is bounded by `MaxNestingDepth`
+		while let Some(parent) = Self::token_parents(current.0, current.1) {
+			ancestors.push(parent);
+			current = parent;
+		}
+		ancestors
+	}
+
+	/// The owner of the root token of `token`.
+	pub fn root_owner(token: (ClassIdOf<T>, TokenIdOf<T>)) -> Result<T::AccountId, DispatchError> {
+		let root = Self::ancestors(token).last().copied().unwrap_or(token);
+		Self::owner_of(root)
+	}
+
+	/// The ownership tree of `token`, `None` if the token doesn't exist.
+	pub fn ownership_tree(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> Option<OwnershipTree<T::AccountId, ClassIdOf<T>, TokenIdOf<T>>> {
+		let root_owner = Self::root_owner(token).ok()?;
+		let mut ancestors = Self::ancestors(token);
+		ancestors.reverse();
+		Some(OwnershipTree {
+			root_owner,
+			ancestors,
+			node: Self::token_node(token),
+		})
+	}
+
+	fn token_node(token: (ClassIdOf<T>, TokenIdOf<T>)) -> TokenNode<ClassIdOf<T>, TokenIdOf<T>> {
+		TokenNode {
+			token,
+			children: TokenChildren::<T>::iter_key_prefix(token)
+				.map(Self::token_node)
+				.collect(),
+		}
+	}
+
+	/// The number of levels of tokens nested below `token`.
+	fn height(token: (ClassIdOf<T>, TokenIdOf<T>)) -> u32 {
+		TokenChildren::<T>::iter_key_prefix(token)
+			.map(|child| Self::height(child).saturating_add(1))
+			.max()
+			.unwrap_or_default()
+	}
+
+	fn owner_of(token: (ClassIdOf<T>, TokenIdOf<T>)) -> Result<T::AccountId, DispatchError> {
+		orml_nft::Pallet::<T>::tokens(token.0, token.1)
+			.map(|t| t.owner)
+			.ok_or_else(|| Error::<T>::TokenIdNotFound.into())
+	}
+
+	/// The account holding the listed and fractionalised tokens, and the bids of the
+	/// auctions.
+	pub fn escrow_account_id() -> T::AccountId {
+		T::PalletId::get().into_sub_account(b"escrow")
+	}
+
+	#[require_transactional]
+	fn do_list(
+		seller: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		kind: ListingKind<BalanceOf<T>>,
+	) -> DispatchResult {
+		Self::do_transfer(&seller, &Self::escrow_account_id(), token)?;
+		Listings::<T>::insert(
+			token.0,
+			token.1,
+			Listing {
+				seller: seller.clone(),
+				kind: kind.clone(),
+			},
+		);
+
+		Self::deposit_event(Event::TokenListed {
+			seller,
+			class_id: token.0,
+			token_id: token.1,
+			kind,
+		});
+		Ok(())
+	}
+
+	#[require_transactional]
+	fn do_cancel_listing(seller: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		Listings::<T>::remove(token.0, token.1);
+		Self::do_transfer(&Self::escrow_account_id(), seller, token)?;
+
+		Self::deposit_event(Event::ListingCancelled {
+			seller: seller.clone(),
+			class_id: token.0,
+			token_id: token.1,
+		});
+		Ok(())
+	}
+
+	/// Pay `price` from `payer` to the seller and the royalty beneficiary of the class,
+	/// and transfer the listed token to `buyer`.
+	#[require_transactional]
+	fn do_settle_sale(
+		payer: &T::AccountId,
+		buyer: &T::AccountId,
+		seller: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		price: BalanceOf<T>,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		Listings::<T>::remove(token.0, token.1);
+
+		let royalty = match Self::class_royalties(token.0) {
+			Some(RoyaltyInfo { beneficiary, rate }) => {
+				let royalty = rate.mul_floor(price);
+				<T as module::Config>::Currency::transfer(payer, &beneficiary, royalty, existence_requirement)?;
+				royalty
+			}
+			None => Zero::zero(),
+		};
+		<T as module::Config>::Currency::transfer(
+			payer,
+			&seller,
+			price.saturating_sub(royalty),
+			existence_requirement,
+		)?;
+		Self::do_transfer(&Self::escrow_account_id(), buyer, token)?;
+
+		Self::deposit_event(Event::TokenSold {
+			seller,
+			buyer: buyer.clone(),
+			class_id: token.0,
+			token_id: token.1,
+			price,
+			royalty,
+		});
+		Ok(())
+	}
+
+	#[transactional]
+	fn auction_bid_handler(
+		id: AuctionId,
+		new_bid: (T::AccountId, BalanceOf<T>),
+		last_bid: Option<(T::AccountId, BalanceOf<T>)>,
+	) -> DispatchResult {
+		let token = Self::auction_listings(id).ok_or(Error::<T>::ListingNotFound)?;
+		let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::ListingNotFound)?;
+		if let ListingKind::Auction { min_price, .. } = listing.kind {
+			ensure!(new_bid.1 >= min_price, Error::<T>::PriceExceedsLimit);
+		}
+
+		let escrow = Self::escrow_account_id();
+		<T as module::Config>::Currency::transfer(&new_bid.0, &escrow, new_bid.1, KeepAlive)?;
+		// NFT auctions don't use candle escrow, the previous bid is refunded once it is outbid
+		if let Some((last_bidder, last_amount)) = last_bid {
+			<T as module::Config>::Currency::transfer(&escrow, &last_bidder, last_amount, AllowDeath)?;
+		}
+		Ok(())
+	}
+
+	#[transactional]
+	fn auction_end_handler(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		winner: Option<(T::AccountId, BalanceOf<T>)>,
+	) -> DispatchResult {
+		let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::ListingNotFound)?;
+		match winner {
+			Some((bidder, amount)) => Self::do_settle_sale(
+				&Self::escrow_account_id(),
+				&bidder,
+				listing.seller,
+				token,
+				amount,
+				AllowDeath,
+			),
+			None => Self::do_cancel_listing(&listing.seller, token),
+		}
+	}
+
+	#[require_transactional]
+	fn do_update_token_attributes(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		attributes: Attributes,
+	) -> DispatchResult {
+		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(
+			*who == class_info.owner || AttributeUpdaters::<T>::contains_key(token.0, who),
+			Error::<T>::NoPermission
+		);
+		ensure!(
+			class_info
+				.data
+				.properties
+				.0
+				.contains(ClassProperty::TokenAttributesMutable),
+			Error::<T>::Immutable
+		);
+		ensure!(
+			!FrozenTokens::<T>::contains_key(token.0, token.1),
+			Error::<T>::MetadataFrozen
+		);
+		Self::check_schema(token.0, &attributes)?;
+
+		orml_nft::Tokens::<T>::try_mutate(token.0, token.1, |maybe_token_info| -> DispatchResult {
+			let token_info = maybe_token_info.as_mut().ok_or(Error::<T>::TokenIdNotFound)?;
+			let owner = &token_info.owner;
+			// the deposit of an escrowed token would mix with the funds held by the escrow account
+			ensure!(*owner != Self::escrow_account_id(), Error::<T>::TokenInEscrow);
+
+			let data_deposit = Self::data_deposit(&token_info.metadata, &attributes)?;
+			let deposit = T::CreateTokenDeposit::get().saturating_add(data_deposit);
+			let old_deposit = token_info.data.deposit;
+			if deposit > old_deposit {
+				// the updater pays for the increase
+				let diff = deposit.saturating_sub(old_deposit);
+				<T as module::Config>::Currency::transfer(who, owner, diff, KeepAlive)?;
+				<T as module::Config>::Currency::reserve_named(&RESERVE_ID, owner, diff)?;
+			} else {
+				// the owner gets back the decrease
+				<T as module::Config>::Currency::unreserve_named(
+					&RESERVE_ID,
+					owner,
+					old_deposit.saturating_sub(deposit),
+				);
+			}
+
+			token_info.data = TokenData { deposit, attributes };
+			Ok(())
+		})
+	}
+
+	fn check_schema(class_id: ClassIdOf<T>, attributes: &Attributes) -> DispatchResult {
+		if let Some(schema) = Self::class_attribute_schemas(class_id) {
+			ensure!(
+				attributes
+					.iter()
+					.all(|(key, value)| schema.get(key).map_or(false, |value_type| value_type.is_valid(value))),
+				Error::<T>::AttributesNotMatchSchema
+			);
+		}
 		Ok(())
 	}
 
@@ -556,3 +1550,38 @@ impl<T: Config> NFT<T::AccountId> for Pallet<T> {
 		Self::do_transfer(from, to, token)
 	}
 }
+
+impl<T: Config> AuctionHandler<T::AccountId, BalanceOf<T>, T::BlockNumber, AuctionId> for Pallet<T> {
+	fn on_new_bid(
+		_now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, BalanceOf<T>),
+		last_bid: Option<(T::AccountId, BalanceOf<T>)>,
+	) -> OnNewBidResult<T::BlockNumber> {
+		OnNewBidResult {
+			accept_bid: Self::auction_bid_handler(id, new_bid, last_bid).is_ok(),
+			auction_end_change: Change::NoChange,
+		}
+	}
+
+	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, BalanceOf<T>)>) {
+		if let Some(token) = AuctionListings::<T>::take(id) {
+			if Self::auction_end_handler(token, winner.clone()).is_err() {
+				// refund the winner and keep the token listed until the seller cancels it
+				if let Some((bidder, amount)) = winner {
+					let _ = <T as module::Config>::Currency::transfer(
+						&Self::escrow_account_id(),
+						&bidder,
+						amount,
+						AllowDeath,
+					);
+				}
+			}
+		}
+	}
+
+	fn on_losing_bid(_id: AuctionId, (bidder, amount): (T::AccountId, BalanceOf<T>)) {
+		// the bid was held in escrow by `auction_bid_handler`
+		let _ = <T as module::Config>::Currency::transfer(&Self::escrow_account_id(), &bidder, amount, AllowDeath);
+	}
+}
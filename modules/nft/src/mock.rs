@@ -24,16 +24,17 @@ use crate as nft;
 use codec::{Decode, Encode};
 use frame_support::{
 	construct_runtime, ord_parameter_types, parameter_types,
-	traits::{Contains, InstanceFilter, Nothing},
+	traits::{Contains, InstanceFilter, Nothing, Randomness},
 	RuntimeDebug,
 };
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, Balance, BlockNumber, CurrencyId, ReserveIdentifier, TokenSymbol};
+use primitives::{Amount, AuctionId, Balance, BlockNumber, CurrencyId, ReserveIdentifier, TokenSymbol};
 use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Convert, Hash, IdentityLookup},
+	Permill,
 };
 use support::mocks::MockAddressMapping;
 
@@ -158,6 +159,10 @@ ord_parameter_types! {
 	pub const One: AccountId = ALICE;
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -166,7 +171,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -188,12 +198,42 @@ impl module_currencies::Config for Runtime {
 	type OnDust = ();
 }
 
+pub struct MockRandomness;
+impl Randomness<H256, u64> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), 0)
+	}
+}
+
+impl orml_auction::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type AuctionId = AuctionId;
+	type Handler = NFTModule;
+	type Randomness = MockRandomness;
+	type WeightInfo = ();
+}
+
+pub struct ShareCurrencyId;
+impl Convert<(u32, u64), Option<CurrencyId>> for ShareCurrencyId {
+	fn convert((class_id, token_id): (u32, u64)) -> Option<CurrencyId> {
+		if class_id == CLASS_ID {
+			Some(CurrencyId::ForeignAsset(token_id as u16))
+		} else {
+			None
+		}
+	}
+}
+
 parameter_types! {
 	pub const CreateClassDeposit: Balance = 200;
 	pub const CreateTokenDeposit: Balance = 100;
 	pub const DataDepositPerByte: Balance = 10;
 	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
 	pub MaxAttributesBytes: u32 = 10;
+	pub const MaxRoyalty: Permill = Permill::from_percent(20);
+	pub const MaxNestingDepth: u32 = 3;
+	pub const MaxChildren: u32 = 2;
 }
 impl Config for Runtime {
 	type Event = Event;
@@ -203,6 +243,12 @@ impl Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MaxRoyalty = MaxRoyalty;
+	type Auction = AuctionModule;
+	type ShareCurrency = Tokens;
+	type ShareCurrencyId = ShareCurrencyId;
+	type MaxNestingDepth = MaxNestingDepth;
+	type MaxChildren = MaxChildren;
 	type WeightInfo = ();
 }
 
@@ -239,6 +285,7 @@ construct_runtime!(
 		Utility: pallet_utility::{Pallet, Call, Event},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Currency: module_currencies::{Pallet, Call, Event<T>},
+		AuctionModule: orml_auction::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
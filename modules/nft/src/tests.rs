@@ -640,6 +640,23 @@ fn destroy_class_should_fail() {
 
 		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
 
+		assert_ok!(NFTModule::set_attribute_updater(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			BOB,
+			true
+		));
+		assert_noop!(
+			NFTModule::destroy_class(Origin::signed(class_id_account()), CLASS_ID, BOB),
+			Error::<Runtime>::AttributeUpdatersExist
+		);
+		assert_ok!(NFTModule::set_attribute_updater(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			BOB,
+			false
+		));
+
 		assert_noop!(
 			NFTModule::destroy_class(Origin::signed(class_id_account()), CLASS_ID, BOB),
 			pallet_proxy::Error::<Runtime>::NotFound
@@ -718,3 +735,705 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+const CHARLIE: AccountId = AccountId::new([3u8; 32]);
+const DAVE: AccountId = AccountId::new([4u8; 32]);
+
+fn setup_token() {
+	assert_ok!(NFTModule::create_class(
+		Origin::signed(ALICE),
+		vec![1],
+		Properties(ClassProperty::Transferable | ClassProperty::Mintable),
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(
+		&class_id_account(),
+		CreateTokenDeposit::get() + DataDepositPerByte::get()
+	));
+	assert_ok!(NFTModule::mint(
+		Origin::signed(class_id_account()),
+		BOB,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		1
+	));
+}
+
+#[test]
+fn set_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		let royalty = RoyaltyInfo {
+			beneficiary: ALICE,
+			rate: Permill::from_percent(10),
+		};
+
+		assert_noop!(
+			NFTModule::set_royalty(Origin::signed(BOB), CLASS_ID, Some(royalty.clone())),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_royalty(
+				Origin::signed(class_id_account()),
+				CLASS_ID_NOT_EXIST,
+				Some(royalty.clone())
+			),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_noop!(
+			NFTModule::set_royalty(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				Some(RoyaltyInfo {
+					beneficiary: ALICE,
+					rate: Permill::from_percent(30),
+				})
+			),
+			Error::<Runtime>::RoyaltyTooHigh
+		);
+
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(royalty.clone())
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::RoyaltyUpdated {
+			class_id: CLASS_ID,
+			royalty: Some(royalty.clone()),
+		}));
+		assert_eq!(NFTModule::class_royalties(CLASS_ID), Some(royalty));
+
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			None
+		));
+		assert_eq!(NFTModule::class_royalties(CLASS_ID), None);
+	});
+}
+
+#[test]
+fn fixed_price_listing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(RoyaltyInfo {
+				beneficiary: ALICE,
+				rate: Permill::from_percent(10),
+			})
+		));
+
+		assert_noop!(
+			NFTModule::list_token(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), 1000),
+			orml_nft::Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::list_token(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenListed {
+			seller: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			kind: ListingKind::FixedPrice { price: 1000 },
+		}));
+		assert_eq!(
+			NFTModule::owner((CLASS_ID, TOKEN_ID)),
+			Some(NFTModule::escrow_account_id())
+		);
+
+		// cancel and list again
+		assert_noop!(
+			NFTModule::cancel_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::cancel_listing(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::owner((CLASS_ID, TOKEN_ID)), Some(BOB));
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+		assert_ok!(NFTModule::list_token(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000));
+
+		Balances::make_free_balance_be(&CHARLIE, 10000);
+		assert_noop!(
+			NFTModule::buy_token(Origin::signed(CHARLIE), (CLASS_ID, TOKEN_ID), 999),
+			Error::<Runtime>::PriceExceedsLimit
+		);
+
+		let alice_balance = free_balance(&ALICE);
+		let bob_balance = free_balance(&BOB);
+		assert_ok!(NFTModule::buy_token(
+			Origin::signed(CHARLIE),
+			(CLASS_ID, TOKEN_ID),
+			1000
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenSold {
+			seller: BOB,
+			buyer: CHARLIE,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			price: 1000,
+			royalty: 100,
+		}));
+		assert_eq!(NFTModule::owner((CLASS_ID, TOKEN_ID)), Some(CHARLIE));
+		assert_eq!(free_balance(&CHARLIE), 9000);
+		assert_eq!(free_balance(&ALICE), alice_balance + 100);
+		assert_eq!(free_balance(&BOB), bob_balance + 900);
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+
+		assert_noop!(
+			NFTModule::buy_token(Origin::signed(CHARLIE), (CLASS_ID, TOKEN_ID), 1000),
+			Error::<Runtime>::ListingNotFound
+		);
+	});
+}
+
+#[test]
+fn auction_listing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(RoyaltyInfo {
+				beneficiary: ALICE,
+				rate: Permill::from_percent(10),
+			})
+		));
+
+		assert_noop!(
+			NFTModule::list_token_for_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 500, 0),
+			Error::<Runtime>::InvalidDuration
+		);
+		assert_ok!(NFTModule::list_token_for_auction(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			500,
+			10
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenListed {
+			seller: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			kind: ListingKind::Auction {
+				auction_id: 0,
+				min_price: 500,
+			},
+		}));
+		assert_eq!(NFTModule::auction_listings(0), Some((CLASS_ID, TOKEN_ID)));
+		assert_noop!(
+			NFTModule::buy_token(Origin::signed(CHARLIE), (CLASS_ID, TOKEN_ID), 1000),
+			Error::<Runtime>::ListingNotFound
+		);
+
+		Balances::make_free_balance_be(&CHARLIE, 10000);
+		Balances::make_free_balance_be(&DAVE, 10000);
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(CHARLIE), 0, 400),
+			orml_auction::Error::<Runtime>::BidNotAccepted
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(CHARLIE), 0, 600));
+		assert_eq!(free_balance(&CHARLIE), 9400);
+		assert_ok!(AuctionModule::bid(Origin::signed(DAVE), 0, 700));
+		assert_eq!(free_balance(&CHARLIE), 10000);
+		assert_eq!(free_balance(&DAVE), 9300);
+
+		assert_noop!(
+			NFTModule::cancel_listing(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::AuctionHasBids
+		);
+
+		let alice_balance = free_balance(&ALICE);
+		let bob_balance = free_balance(&BOB);
+		AuctionModule::on_finalize(11);
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenSold {
+			seller: BOB,
+			buyer: DAVE,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			price: 700,
+			royalty: 70,
+		}));
+		assert_eq!(NFTModule::owner((CLASS_ID, TOKEN_ID)), Some(DAVE));
+		assert_eq!(free_balance(&ALICE), alice_balance + 70);
+		assert_eq!(free_balance(&BOB), bob_balance + 630);
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(NFTModule::auction_listings(0), None);
+	});
+}
+
+#[test]
+fn auction_without_bid_should_return_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTModule::list_token_for_auction(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			500,
+			10
+		));
+
+		AuctionModule::on_finalize(11);
+		System::assert_last_event(Event::NFTModule(crate::Event::ListingCancelled {
+			seller: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(NFTModule::owner((CLASS_ID, TOKEN_ID)), Some(BOB));
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn fractionalise_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		let currency_id = CurrencyId::ForeignAsset(TOKEN_ID as u16);
+
+		assert_noop!(
+			NFTModule::fractionalise(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 0),
+			Error::<Runtime>::InvalidQuantity
+		);
+		assert_ok!(NFTModule::fractionalise(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			1000
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenFractionalised {
+			owner: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			currency_id,
+			shares: 1000,
+		}));
+		assert_eq!(
+			NFTModule::owner((CLASS_ID, TOKEN_ID)),
+			Some(NFTModule::escrow_account_id())
+		);
+		assert_eq!(<Tokens as MultiCurrency<_>>::free_balance(currency_id, &BOB), 1000);
+
+		assert_ok!(<Tokens as MultiCurrency<_>>::transfer(currency_id, &BOB, &CHARLIE, 400));
+		assert_noop!(
+			NFTModule::redeem_fraction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::InsufficientShares
+		);
+
+		assert_ok!(<Tokens as MultiCurrency<_>>::transfer(currency_id, &CHARLIE, &BOB, 400));
+		assert_ok!(NFTModule::redeem_fraction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::FractionRedeemed {
+			who: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(NFTModule::owner((CLASS_ID, TOKEN_ID)), Some(BOB));
+		assert_eq!(Tokens::total_issuance(currency_id), 0);
+		assert_noop!(
+			NFTModule::redeem_fraction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::FractionNotFound
+		);
+	});
+}
+
+#[test]
+fn update_token_attributes_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Mintable | ClassProperty::TokenAttributesMutable),
+			Default::default(),
+		));
+		let deposit = CreateTokenDeposit::get() + DataDepositPerByte::get();
+		assert_ok!(Balances::deposit_into_existing(&class_id_account(), 1000));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+		assert_eq!(reserved_balance(&BOB), deposit);
+
+		assert_noop!(
+			NFTModule::update_token_attributes(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), test_attr(1)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::update_token_attributes(
+				Origin::signed(class_id_account()),
+				(CLASS_ID, TOKEN_ID_NOT_EXIST),
+				test_attr(1)
+			),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		// the updater pays for the increase of the deposit
+		let class_balance = free_balance(&class_id_account());
+		assert_ok!(NFTModule::update_token_attributes(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			test_attr(1)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::UpdatedTokenAttributes {
+			who: class_id_account(),
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		let increase = TEST_ATTR_LEN * DataDepositPerByte::get();
+		assert_eq!(free_balance(&class_id_account()), class_balance - increase);
+		assert_eq!(reserved_balance(&BOB), deposit + increase);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().data,
+			TokenData {
+				deposit: deposit + increase,
+				attributes: test_attr(1),
+			}
+		);
+
+		// the owner gets back the decrease of the deposit
+		assert_noop!(
+			NFTModule::set_attribute_updater(Origin::signed(BOB), CLASS_ID, BOB, true),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::set_attribute_updater(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			BOB,
+			true
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::AttributeUpdaterSet {
+			class_id: CLASS_ID,
+			who: BOB,
+			enabled: true,
+		}));
+		assert_ok!(NFTModule::update_token_attributes(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			Default::default()
+		));
+		assert_eq!(reserved_balance(&BOB), deposit);
+		assert_eq!(free_balance(&BOB), increase);
+
+		// frozen tokens can't be updated
+		assert_noop!(
+			NFTModule::freeze_token_metadata(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::freeze_token_metadata(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::FrozeTokenMetadata {
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_noop!(
+			NFTModule::update_token_attributes(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), test_attr(1)),
+			Error::<Runtime>::MetadataFrozen
+		);
+	});
+}
+
+#[test]
+fn update_token_attributes_should_fail_if_in_escrow() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::Mintable | ClassProperty::TokenAttributesMutable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(&class_id_account(), 1000));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+
+		assert_ok!(NFTModule::list_token(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000));
+		assert_noop!(
+			NFTModule::update_token_attributes(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID), test_attr(1)),
+			Error::<Runtime>::TokenInEscrow
+		);
+	});
+}
+
+#[test]
+fn update_token_attributes_should_fail_if_immutable() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_noop!(
+			NFTModule::update_token_attributes(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID), test_attr(1)),
+			Error::<Runtime>::Immutable
+		);
+	});
+}
+
+#[test]
+fn attribute_schema_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Mintable | ClassProperty::TokenAttributesMutable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(&class_id_account(), 1000));
+
+		let mut schema: AttributeSchema = BTreeMap::new();
+		schema.insert(vec![0; 10], AttributeValueType::Bytes);
+		assert_noop!(
+			NFTModule::set_attribute_schema(Origin::signed(class_id_account()), CLASS_ID, Some(schema)),
+			Error::<Runtime>::AttributesTooLarge
+		);
+
+		let mut schema: AttributeSchema = BTreeMap::new();
+		schema.insert(vec![1], AttributeValueType::Bool);
+		schema.insert(vec![2], AttributeValueType::U32);
+		assert_noop!(
+			NFTModule::set_attribute_schema(Origin::signed(BOB), CLASS_ID, Some(schema.clone())),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::set_attribute_schema(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(schema.clone())
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::AttributeSchemaUpdated {
+			class_id: CLASS_ID,
+		}));
+		assert_eq!(NFTModule::class_attribute_schemas(CLASS_ID), Some(schema));
+
+		let mut unknown_key: Attributes = BTreeMap::new();
+		unknown_key.insert(vec![3], vec![0]);
+		assert_noop!(
+			NFTModule::mint(
+				Origin::signed(class_id_account()),
+				BOB,
+				CLASS_ID,
+				vec![1],
+				unknown_key,
+				1
+			),
+			Error::<Runtime>::AttributesNotMatchSchema
+		);
+
+		let mut invalid_value: Attributes = BTreeMap::new();
+		invalid_value.insert(vec![1], vec![2]);
+		assert_noop!(
+			NFTModule::mint(
+				Origin::signed(class_id_account()),
+				BOB,
+				CLASS_ID,
+				vec![1],
+				invalid_value,
+				1
+			),
+			Error::<Runtime>::AttributesNotMatchSchema
+		);
+
+		let mut attributes: Attributes = BTreeMap::new();
+		attributes.insert(vec![1], true.encode());
+		attributes.insert(vec![2], 5u32.encode());
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			attributes.clone(),
+			1
+		));
+
+		attributes.insert(vec![2], 5u64.encode());
+		assert_noop!(
+			NFTModule::update_token_attributes(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID), attributes),
+			Error::<Runtime>::AttributesNotMatchSchema
+		);
+	});
+}
+
+fn setup_nested_tokens(properties: Properties, quantity: u32) {
+	assert_ok!(NFTModule::create_class(
+		Origin::signed(ALICE),
+		vec![1],
+		properties,
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(
+		&class_id_account(),
+		(CreateTokenDeposit::get() + DataDepositPerByte::get()) * quantity as Balance
+	));
+	assert_ok!(NFTModule::mint(
+		Origin::signed(class_id_account()),
+		BOB,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		quantity
+	));
+}
+
+#[test]
+fn nest_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_nested_tokens(Properties(ClassProperty::Transferable | ClassProperty::Mintable), 3);
+		let token_deposit = CreateTokenDeposit::get() + DataDepositPerByte::get();
+
+		assert_noop!(
+			NFTModule::nest(Origin::signed(ALICE), (CLASS_ID, 1), (CLASS_ID, 0)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 1), (CLASS_ID, 0)));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenNested {
+			class_id: CLASS_ID,
+			token_id: 1,
+			parent: (CLASS_ID, 0),
+		}));
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 2), (CLASS_ID, 1)));
+
+		let holder = NFTModule::nest_account_id((CLASS_ID, 0));
+		assert_eq!(NFTModule::owner((CLASS_ID, 1)), Some(holder.clone()));
+		assert_eq!(reserved_balance(&holder), token_deposit);
+		assert_eq!(reserved_balance(&BOB), token_deposit);
+		assert_eq!(NFTModule::root_owner((CLASS_ID, 2)), Ok(BOB));
+		assert_eq!(
+			NFTModule::ownership_tree((CLASS_ID, 1)),
+			Some(OwnershipTree {
+				root_owner: BOB,
+				ancestors: vec![(CLASS_ID, 0)],
+				node: TokenNode {
+					token: (CLASS_ID, 1),
+					children: vec![TokenNode {
+						token: (CLASS_ID, 2),
+						children: vec![],
+					}],
+				},
+			})
+		);
+
+		// nested tokens move with the parent
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), CHARLIE, (CLASS_ID, 0)));
+		assert_eq!(NFTModule::root_owner((CLASS_ID, 2)), Ok(CHARLIE));
+		assert_noop!(
+			NFTModule::unnest(Origin::signed(BOB), (CLASS_ID, 2)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::unnest(Origin::signed(CHARLIE), (CLASS_ID, 0)),
+			Error::<Runtime>::NotNested
+		);
+
+		assert_ok!(NFTModule::unnest(Origin::signed(CHARLIE), (CLASS_ID, 2)));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenUnnested {
+			owner: CHARLIE,
+			class_id: CLASS_ID,
+			token_id: 2,
+		}));
+		assert_eq!(NFTModule::owner((CLASS_ID, 2)), Some(CHARLIE));
+		assert_eq!(NFTModule::token_parents(CLASS_ID, 2), None);
+		assert_eq!(NFTModule::token_children((CLASS_ID, 1), (CLASS_ID, 2)), None);
+		assert_eq!(reserved_balance(&CHARLIE), 2 * token_deposit);
+	});
+}
+
+#[test]
+fn nest_should_fail_on_cycle_or_too_deep() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_nested_tokens(Properties(ClassProperty::Transferable | ClassProperty::Mintable), 5);
+
+		assert_noop!(
+			NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 0), (CLASS_ID, 0)),
+			Error::<Runtime>::NestingCycle
+		);
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 1), (CLASS_ID, 0)));
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 2), (CLASS_ID, 1)));
+		assert_noop!(
+			NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 0), (CLASS_ID, 2)),
+			Error::<Runtime>::NestingCycle
+		);
+
+		// MaxNestingDepth is 3
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 4), (CLASS_ID, 3)));
+		assert_noop!(
+			NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 3), (CLASS_ID, 2)),
+			Error::<Runtime>::NestingTooDeep
+		);
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 3), (CLASS_ID, 1)));
+		assert_eq!(
+			NFTModule::ancestors((CLASS_ID, 4)),
+			vec![(CLASS_ID, 3), (CLASS_ID, 1), (CLASS_ID, 0)]
+		);
+	});
+}
+
+#[test]
+fn nest_should_fail_with_too_many_children() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_nested_tokens(Properties(ClassProperty::Transferable | ClassProperty::Mintable), 4);
+
+		// MaxChildren is 2
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 1), (CLASS_ID, 0)));
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 2), (CLASS_ID, 0)));
+		assert_noop!(
+			NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 3), (CLASS_ID, 0)),
+			Error::<Runtime>::TooManyChildren
+		);
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 3), (CLASS_ID, 1)));
+
+		// 2 + 4 + 8 tokens can be nested below a token
+		assert_eq!(NFTModule::max_descendants(), 14);
+	});
+}
+
+#[test]
+fn burn_nested_tokens_should_follow_class_policy() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_nested_tokens(
+			Properties(
+				ClassProperty::Transferable
+					| ClassProperty::Burnable
+					| ClassProperty::Mintable
+					| ClassProperty::ClassPropertiesMutable,
+			),
+			3,
+		);
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 1), (CLASS_ID, 0)));
+		assert_ok!(NFTModule::nest(Origin::signed(BOB), (CLASS_ID, 2), (CLASS_ID, 1)));
+
+		assert_noop!(
+			NFTModule::burn(Origin::signed(BOB), (CLASS_ID, 0)),
+			Error::<Runtime>::TokenHasChildren
+		);
+
+		assert_ok!(NFTModule::update_class_properties(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Properties(ClassProperty::Burnable | ClassProperty::NestedBurnCascade)
+		));
+		let token_deposit = CreateTokenDeposit::get() + DataDepositPerByte::get();
+		let free = free_balance(&BOB);
+		// the weight of the burned nested tokens is charged
+		assert_eq!(
+			NFTModule::burn(Origin::signed(BOB), (CLASS_ID, 0))
+				.unwrap()
+				.actual_weight,
+			Some(3 * <() as WeightInfo>::burn())
+		);
+		System::assert_last_event(Event::NFTModule(crate::Event::BurnedToken {
+			owner: BOB,
+			class_id: CLASS_ID,
+			token_id: 0,
+		}));
+
+		for token_id in 0..3 {
+			assert!(!orml_nft::Tokens::<Runtime>::contains_key(CLASS_ID, token_id));
+			assert_eq!(NFTModule::token_parents(CLASS_ID, token_id), None);
+		}
+		assert_eq!(reserved_balance(&BOB), 0);
+		assert_eq!(free_balance(&BOB), free + 3 * token_deposit);
+		assert_eq!(free_balance(&NFTModule::nest_account_id((CLASS_ID, 0))), 0);
+		assert_eq!(free_balance(&NFTModule::nest_account_id((CLASS_ID, 1))), 0);
+	});
+}
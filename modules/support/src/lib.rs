@@ -26,11 +26,11 @@ use primitives::{
 	task::TaskResult,
 	CurrencyId,
 };
-use sp_core::H160;
+use sp_core::{H160, U256};
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, CheckedDiv, MaybeSerializeDeserialize},
 	transaction_validity::TransactionValidityError,
-	DispatchError, DispatchResult, FixedU128, RuntimeDebug,
+	DispatchError, DispatchResult, FixedU128, Perbill, RuntimeDebug,
 };
 use sp_std::{
 	cmp::{Eq, PartialEq},
@@ -362,6 +362,15 @@ pub trait EVMBridge<AccountId, Balance> {
 	fn balance_of(context: InvokeContext, address: EvmAddress) -> Result<Balance, DispatchError>;
 	/// Execute ERC20.transfer(address, uint256) to transfer value to `to`
 	fn transfer(context: InvokeContext, to: EvmAddress, value: Balance) -> DispatchResult;
+	/// Execute ERC20.allowance(address, address) to read the amount `spender`
+	/// is still allowed to withdraw from `owner`
+	fn allowance(context: InvokeContext, owner: EvmAddress, spender: EvmAddress) -> Result<Balance, DispatchError>;
+	/// Execute ERC20.approve(address, uint256) to allow `spender` to withdraw
+	/// up to `value` from the sender
+	fn approve(context: InvokeContext, spender: EvmAddress, value: Balance) -> DispatchResult;
+	/// Execute ERC20.transferFrom(address, address, uint256) to transfer value
+	/// from `from` to `to` using the allowance of the sender
+	fn transfer_from(context: InvokeContext, from: EvmAddress, to: EvmAddress, value: Balance) -> DispatchResult;
 	/// Get the real origin account and charge storage rent from the origin.
 	fn get_origin() -> Option<AccountId>;
 	/// Provide a method to set origin for `on_initialize`
@@ -388,12 +397,61 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 	fn transfer(_context: InvokeContext, _to: EvmAddress, _value: Balance) -> DispatchResult {
 		Err(DispatchError::Other("unimplemented evm bridge"))
 	}
+	fn allowance(_context: InvokeContext, _owner: EvmAddress, _spender: EvmAddress) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+	fn approve(_context: InvokeContext, _spender: EvmAddress, _value: Balance) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+	fn transfer_from(_context: InvokeContext, _from: EvmAddress, _to: EvmAddress, _value: Balance) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
 	fn get_origin() -> Option<AccountId> {
 		None
 	}
 	fn set_origin(_origin: AccountId) {}
 }
 
+/// An abstraction of EVMNFTBridge
+pub trait EVMNFTBridge {
+	/// Execute ERC721.name() to read collection name from ERC721 contract
+	fn name(context: InvokeContext) -> Result<Vec<u8>, DispatchError>;
+	/// Execute ERC721.symbol() to read collection symbol from ERC721 contract
+	fn symbol(context: InvokeContext) -> Result<Vec<u8>, DispatchError>;
+	/// Execute ERC721.balanceOf(address) to read the number of tokens owned by
+	/// `owner`
+	fn balance_of(context: InvokeContext, owner: EvmAddress) -> Result<U256, DispatchError>;
+	/// Execute ERC721.ownerOf(uint256) to read the owner of `token_id`
+	fn owner_of(context: InvokeContext, token_id: U256) -> Result<EvmAddress, DispatchError>;
+	/// Execute ERC721.tokenURI(uint256) to read the metadata URI of `token_id`
+	fn token_uri(context: InvokeContext, token_id: U256) -> Result<Vec<u8>, DispatchError>;
+	/// Execute ERC721.transferFrom(address, address, uint256) to transfer
+	/// `token_id` from `from` to `to`
+	fn transfer_from(context: InvokeContext, from: EvmAddress, to: EvmAddress, token_id: U256) -> DispatchResult;
+}
+
+#[cfg(feature = "std")]
+impl EVMNFTBridge for () {
+	fn name(_context: InvokeContext) -> Result<Vec<u8>, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm nft bridge"))
+	}
+	fn symbol(_context: InvokeContext) -> Result<Vec<u8>, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm nft bridge"))
+	}
+	fn balance_of(_context: InvokeContext, _owner: EvmAddress) -> Result<U256, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm nft bridge"))
+	}
+	fn owner_of(_context: InvokeContext, _token_id: U256) -> Result<EvmAddress, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm nft bridge"))
+	}
+	fn token_uri(_context: InvokeContext, _token_id: U256) -> Result<Vec<u8>, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm nft bridge"))
+	}
+	fn transfer_from(_context: InvokeContext, _from: EvmAddress, _to: EvmAddress, _token_id: U256) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm nft bridge"))
+	}
+}
+
 /// An abstraction of EVMStateRentTrait
 pub trait EVMStateRentTrait<AccountId, Balance> {
 	/// Query the constants `NewContractExtraBytes` value from evm module.
@@ -588,6 +646,11 @@ pub trait CallBuilder {
 	/// - num_slashing_spans: The number of slashing spans to withdraw from.
 	fn staking_withdraw_unbonded(num_slashing_spans: u32) -> Self::RelayChainCall;
 
+	/// Nominate validators on relay-chain.
+	///  params:
+	/// - targets: The validators to nominate.
+	fn staking_nominate(targets: Vec<Self::AccountId>) -> Self::RelayChainCall;
+
 	/// Transfer Staking currency to another account, disallowing "death".
 	///  params:
 	/// - to: The destination for the transfer
@@ -636,6 +699,27 @@ pub trait NomineesProvider<AccountId> {
 	fn nominees() -> Vec<AccountId>;
 }
 
+/// The metrics of a relaychain validator used to score it for Homa nominations.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Default)]
+pub struct ValidatorMetrics<Balance> {
+	/// The insurance of the validator locked by guarantors
+	pub insurance: Balance,
+	/// The number of times the validator has been slashed
+	pub slash_count: u32,
+	/// The total staking amount slashed on the relaychain
+	pub total_slashed: Balance,
+	/// The commission of the validator on the relaychain
+	pub commission: Perbill,
+	/// Whether the validator is frozen
+	pub is_frozen: bool,
+}
+
+pub trait HomaValidatorProvider<RelaychainAccountId, Balance> {
+	/// Return at most `limit` validators and their metrics, unfrozen validators with the most
+	/// insurance first.
+	fn validators(limit: u32) -> Vec<(RelaychainAccountId, ValidatorMetrics<Balance>)>;
+}
+
 pub trait HomaSubAccountXcm<AccountId, Balance> {
 	/// Cross-chain transfer staking currency to sub account on relaychain.
 	fn transfer_staking_to_sub_account(sender: &AccountId, sub_account_index: u16, amount: Balance) -> DispatchResult;
@@ -646,6 +730,8 @@ pub trait HomaSubAccountXcm<AccountId, Balance> {
 	fn bond_extra_on_sub_account(sub_account_index: u16, amount: Balance) -> DispatchResult;
 	/// Send XCM message to the relaychain for sub account to unbond.
 	fn unbond_on_sub_account(sub_account_index: u16, amount: Balance) -> DispatchResult;
+	/// Send XCM message to the relaychain for sub account to nominate the targets.
+	fn nominate_on_sub_account(sub_account_index: u16, targets: Vec<AccountId>) -> DispatchResult;
 	/// The fee of cross-chain transfer is deducted from the recipient.
 	fn get_xcm_transfer_fee() -> Balance;
 }
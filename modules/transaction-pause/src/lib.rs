@@ -24,9 +24,10 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{Contains, PalletInfoAccess},
 	transactional,
+	weights::{GetDispatchInfo, PostDispatchInfo},
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::DispatchResult;
+use sp_runtime::{traits::Dispatchable, DispatchResult};
 use sp_std::{prelude::*, vec::Vec};
 
 mod mock;
@@ -36,6 +37,9 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The function name to pause all calls of a pallet.
+pub const ALL_FUNCTIONS: &[u8] = b"*";
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -44,9 +48,18 @@ pub mod module {
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
+		/// The outer call dispatch type.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ GetCallMetadata;
+
 		/// The origin which may set filter.
 		type UpdateOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The origin which may dispatch paused transactions.
+		type ExemptOrigin: EnsureOrigin<Self::Origin>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -57,6 +70,12 @@ pub mod module {
 		CannotPause,
 		/// invalid character encoding
 		InvalidCharacter,
+		/// the pallet or function is not found in runtime metadata
+		UnknownCall,
+		/// the expiry block is not in the future
+		InvalidExpiry,
+		/// the origin is not exempted from paused transactions
+		NotExempted,
 	}
 
 	#[pallet::event]
@@ -66,12 +85,17 @@ pub mod module {
 		TransactionPaused {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
+			expire_at: Option<T::BlockNumber>,
 		},
 		/// Unpaused transaction
 		TransactionUnpaused {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
 		},
+		/// The account is exempted or no longer exempted from paused transactions
+		ExemptedAccountUpdated { who: T::AccountId, exempted: bool },
+		/// A paused transaction is dispatched by the exempted origin
+		PausedTransactionDispatched { result: DispatchResult },
 	}
 
 	/// The paused transaction map
@@ -81,17 +105,71 @@ pub mod module {
 	#[pallet::getter(fn paused_transactions)]
 	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
 
+	/// The expiry block of the paused transaction, the pause has no expiry if not exists.
+	///
+	/// PauseExpiries: map (PalletNameBytes, FunctionNameBytes) => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn pause_expiries)]
+	pub type PauseExpiries<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), T::BlockNumber, OptionQuery>;
+
+	/// The paused transactions to unpause at the block.
+	///
+	/// ExpiringPauses: double_map BlockNumber, (PalletNameBytes, FunctionNameBytes) => Option<()>
+	#[pallet::storage]
+	pub type ExpiringPauses<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+
+	/// The accounts exempted from paused transactions.
+	///
+	/// ExemptedAccounts: map AccountId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn exempted_accounts)]
+	pub type ExemptedAccounts<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// Whether the paused transactions are dispatched by the exempted origin in the current call.
+	/// Only set during `dispatch_paused`.
+	///
+	/// Exempting: bool
+	#[pallet::storage]
+	pub type Exempting<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut count: u32 = 0;
+			for ((pallet_name, function_name), _) in ExpiringPauses::<T>::drain_prefix(now) {
+				count += 1;
+				// the pause may be unpaused or paused again with another expiry.
+				let key = (pallet_name, function_name);
+				if PauseExpiries::<T>::get(&key) == Some(now) {
+					PauseExpiries::<T>::remove(&key);
+					PausedTransactions::<T>::remove(&key);
+					let (pallet_name, function_name) = key;
+					Self::deposit_event(Event::TransactionUnpaused {
+						pallet_name_bytes: pallet_name,
+						function_name_bytes: function_name,
+					});
+				}
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Pause the call of the pallet for everyone except the exempted, until the `expire_at`
+		/// block if specified. `function_name` of `*` pauses all calls of the pallet.
 		#[pallet::weight(T::WeightInfo::pause_transaction())]
 		#[transactional]
-		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
+		pub fn pause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			expire_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 
 			// not allowed to pause calls of this pallet to ensure safe
@@ -100,15 +178,30 @@ pub mod module {
 				pallet_name_string != <Self as PalletInfoAccess>::name(),
 				Error::<T>::CannotPause
 			);
+			let function_name_string =
+				sp_std::str::from_utf8(&function_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+			Self::ensure_known_call(pallet_name_string, function_name_string)?;
+			if let Some(expire_at) = expire_at {
+				ensure!(
+					expire_at > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
 
-			PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::TransactionPaused {
-						pallet_name_bytes: pallet_name,
-						function_name_bytes: function_name,
-					});
-				}
+			let key = (pallet_name, function_name);
+			PausedTransactions::<T>::insert(&key, ());
+			if let Some(expire_at) = expire_at {
+				PauseExpiries::<T>::insert(&key, expire_at);
+				ExpiringPauses::<T>::insert(expire_at, &key, ());
+			} else {
+				PauseExpiries::<T>::remove(&key);
+			}
+
+			let (pallet_name, function_name) = key;
+			Self::deposit_event(Event::TransactionPaused {
+				pallet_name_bytes: pallet_name,
+				function_name_bytes: function_name,
+				expire_at,
 			});
 			Ok(())
 		}
@@ -121,6 +214,7 @@ pub mod module {
 			function_name: Vec<u8>,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
+			PauseExpiries::<T>::remove((&pallet_name, &function_name));
 			if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
 				Self::deposit_event(Event::TransactionUnpaused {
 					pallet_name_bytes: pallet_name,
@@ -129,19 +223,93 @@ pub mod module {
 			};
 			Ok(())
 		}
+
+		/// Exempt the account from paused transactions or not.
+		#[pallet::weight(T::WeightInfo::update_exempted_account())]
+		#[transactional]
+		pub fn update_exempted_account(origin: OriginFor<T>, who: T::AccountId, exempted: bool) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if exempted {
+				ExemptedAccounts::<T>::insert(&who, ());
+			} else {
+				ExemptedAccounts::<T>::remove(&who);
+			}
+			Self::deposit_event(Event::ExemptedAccountUpdated { who, exempted });
+			Ok(())
+		}
+
+		/// Dispatch the call bypassing the paused transactions, other call filters still apply.
+		/// Only `ExemptOrigin` and the exempted accounts can dispatch.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(T::WeightInfo::dispatch_paused().saturating_add(dispatch_info.weight), dispatch_info.class)
+		})]
+		pub fn dispatch_paused(origin: OriginFor<T>, call: Box<<T as Config>::Call>) -> DispatchResultWithPostInfo {
+			let exempted = T::ExemptOrigin::try_origin(origin.clone()).is_ok()
+				|| ensure_signed(origin.clone()).map_or(false, |who| ExemptedAccounts::<T>::contains_key(who));
+			ensure!(exempted, Error::<T>::NotExempted);
+
+			Exempting::<T>::put(true);
+			let result = call.dispatch(origin);
+			Exempting::<T>::kill();
+
+			Self::deposit_event(Event::PausedTransactionDispatched {
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(result
+				.map(|post_info| post_info.actual_weight)
+				.unwrap_or_else(|e| e.post_info.actual_weight)
+				.map(|actual_weight| T::WeightInfo::dispatch_paused().saturating_add(actual_weight))
+				.into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ensure the pallet and the function exist in runtime metadata.
+	fn ensure_known_call(pallet_name: &str, function_name: &str) -> DispatchResult {
+		ensure!(
+			<T as Config>::Call::get_module_names().contains(&pallet_name),
+			Error::<T>::UnknownCall
+		);
+		ensure!(
+			function_name.as_bytes() == ALL_FUNCTIONS
+				|| <T as Config>::Call::get_call_names(pallet_name).contains(&function_name),
+			Error::<T>::UnknownCall
+		);
+		Ok(())
+	}
+
+	/// Whether the call is paused, ignoring exemptions.
+	pub fn is_paused(pallet_name: &[u8], function_name: &[u8]) -> bool {
+		PausedTransactions::<T>::contains_key((pallet_name, function_name))
+			|| PausedTransactions::<T>::contains_key((pallet_name, ALL_FUNCTIONS))
+	}
+
+	/// The active paused transactions with the expiry block.
+	pub fn active_pauses() -> Vec<(Vec<u8>, Vec<u8>, Option<T::BlockNumber>)> {
+		PausedTransactions::<T>::iter_keys()
+			.map(|key| {
+				let expire_at = PauseExpiries::<T>::get(&key);
+				(key.0, key.1, expire_at)
+			})
+			.collect()
 	}
 }
 
 pub struct PausedTransactionFilter<T>(sp_std::marker::PhantomData<T>);
-impl<T: Config> Contains<T::Call> for PausedTransactionFilter<T>
+impl<T: Config> Contains<<T as frame_system::Config>::Call> for PausedTransactionFilter<T>
 where
 	<T as frame_system::Config>::Call: GetCallMetadata,
 {
-	fn contains(call: &T::Call) -> bool {
+	fn contains(call: &<T as frame_system::Config>::Call) -> bool {
+		if Exempting::<T>::get() {
+			return false;
+		}
 		let CallMetadata {
 			function_name,
 			pallet_name,
 		} = call.get_call_metadata();
-		PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+		Pallet::<T>::is_paused(pallet_name.as_bytes(), function_name.as_bytes())
 	}
 }
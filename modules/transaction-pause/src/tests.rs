@@ -21,9 +21,9 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
 use mock::{Event, *};
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::traits::{BadOrigin, Dispatchable};
 
 const BALANCE_TRANSFER: &<Runtime as frame_system::Config>::Call =
 	&mock::Call::Balances(pallet_balances::Call::transfer { dest: ALICE, value: 10 });
@@ -32,6 +32,8 @@ const TOKENS_TRANSFER: &<Runtime as frame_system::Config>::Call = &mock::Call::T
 	currency_id: AUSD,
 	amount: 10,
 });
+const SYSTEM_REMARK: &<Runtime as frame_system::Config>::Call =
+	&mock::Call::System(frame_system::Call::remark { remark: vec![] });
 
 #[test]
 fn pause_transaction_work() {
@@ -39,7 +41,7 @@ fn pause_transaction_work() {
 		System::set_block_number(1);
 
 		assert_noop!(
-			TransactionPause::pause_transaction(Origin::signed(5), b"Balances".to_vec(), b"transfer".to_vec()),
+			TransactionPause::pause_transaction(Origin::signed(5), b"Balances".to_vec(), b"transfer".to_vec(), None),
 			BadOrigin
 		);
 
@@ -50,11 +52,13 @@ fn pause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused {
 			pallet_name_bytes: b"Balances".to_vec(),
 			function_name_bytes: b"transfer".to_vec(),
+			expire_at: None,
 		}));
 		assert_eq!(
 			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
@@ -65,7 +69,8 @@ fn pause_transaction_work() {
 			TransactionPause::pause_transaction(
 				Origin::signed(1),
 				b"TransactionPause".to_vec(),
-				b"pause_transaction".to_vec()
+				b"pause_transaction".to_vec(),
+				None
 			),
 			Error::<Runtime>::CannotPause
 		);
@@ -73,15 +78,24 @@ fn pause_transaction_work() {
 			TransactionPause::pause_transaction(
 				Origin::signed(1),
 				b"TransactionPause".to_vec(),
-				b"some_other_call".to_vec()
+				b"some_other_call".to_vec(),
+				None
 			),
 			Error::<Runtime>::CannotPause
 		);
-		assert_ok!(TransactionPause::pause_transaction(
-			Origin::signed(1),
-			b"OtherPallet".to_vec(),
-			b"pause_transaction".to_vec()
-		));
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				Origin::signed(1),
+				b"OtherPallet".to_vec(),
+				b"pause_transaction".to_vec(),
+				None
+			),
+			Error::<Runtime>::UnknownCall
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::signed(1), b"Balances".to_vec(), b"transferr".to_vec(), None),
+			Error::<Runtime>::UnknownCall
+		);
 	});
 }
 
@@ -93,7 +107,8 @@ fn unpause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert_eq!(
 			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
@@ -129,12 +144,14 @@ fn paused_transaction_filter_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Tokens".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
 		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
@@ -152,3 +169,154 @@ fn paused_transaction_filter_work() {
 		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
 	});
 }
+
+#[test]
+fn pause_transaction_with_expiry_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::signed(1), b"Balances".to_vec(), b"transfer".to_vec(), Some(1)),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(10)
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+			expire_at: Some(10),
+		}));
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+			Some(10)
+		));
+		assert_eq!(
+			TransactionPause::pause_expiries((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(10)
+		);
+		assert_eq!(TransactionPause::active_pauses().len(), 2);
+		assert!(TransactionPause::active_pauses().contains(&(b"Balances".to_vec(), b"transfer".to_vec(), Some(10))));
+
+		// pause again without expiry
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+			None
+		));
+		assert_eq!(
+			TransactionPause::pause_expiries((b"Tokens".to_vec(), b"transfer".to_vec())),
+			None
+		);
+
+		TransactionPause::on_initialize(9);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		TransactionPause::on_initialize(10);
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+		}));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+		assert_eq!(
+			TransactionPause::pause_expiries((b"Balances".to_vec(), b"transfer".to_vec())),
+			None
+		);
+		assert_eq!(
+			TransactionPause::active_pauses(),
+			vec![(b"Tokens".to_vec(), b"transfer".to_vec(), None)]
+		);
+	});
+}
+
+#[test]
+fn pause_all_functions_of_pallet_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Tokens".to_vec(),
+			ALL_FUNCTIONS.to_vec(),
+			None
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		assert_ok!(TransactionPause::unpause_transaction(
+			Origin::signed(1),
+			b"Tokens".to_vec(),
+			ALL_FUNCTIONS.to_vec()
+		));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+	});
+}
+
+#[test]
+fn dispatch_paused_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"System".to_vec(),
+			b"remark".to_vec(),
+			None
+		));
+		assert_noop!(
+			SYSTEM_REMARK.clone().dispatch(Origin::signed(CHARLIE)),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+		assert_noop!(
+			TransactionPause::dispatch_paused(Origin::signed(CHARLIE), Box::new(SYSTEM_REMARK.clone())),
+			Error::<Runtime>::NotExempted
+		);
+
+		// exempted by origin
+		assert_ok!(TransactionPause::dispatch_paused(
+			Origin::signed(BOB),
+			Box::new(SYSTEM_REMARK.clone())
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PausedTransactionDispatched {
+			result: Ok(()),
+		}));
+		assert!(!Exempting::<Runtime>::get());
+
+		// exempted by account
+		assert_noop!(
+			TransactionPause::update_exempted_account(Origin::signed(5), CHARLIE, true),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::update_exempted_account(
+			Origin::signed(1),
+			CHARLIE,
+			true
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::ExemptedAccountUpdated {
+			who: CHARLIE,
+			exempted: true,
+		}));
+		assert_ok!(TransactionPause::dispatch_paused(
+			Origin::signed(CHARLIE),
+			Box::new(SYSTEM_REMARK.clone())
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PausedTransactionDispatched {
+			result: Ok(()),
+		}));
+
+		assert_ok!(TransactionPause::update_exempted_account(
+			Origin::signed(1),
+			CHARLIE,
+			false
+		));
+		assert_noop!(
+			TransactionPause::dispatch_paused(Origin::signed(CHARLIE), Box::new(SYSTEM_REMARK.clone())),
+			Error::<Runtime>::NotExempted
+		);
+	});
+}
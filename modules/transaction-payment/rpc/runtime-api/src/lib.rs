@@ -0,0 +1,45 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for transaction payment module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+// The `unnecessary_mut_passed` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_runtime::traits::MaybeDisplay;
+
+pub use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee};
+pub use primitives::CurrencyId;
+
+sp_api::decl_runtime_apis! {
+	pub trait FeeCurrencyApi<Balance> where
+		Balance: Codec + MaybeDisplay,
+	{
+		/// The fee details of the extrinsic quoted in `currency_id`, `None` if there is no swap
+		/// path from `currency_id` to the native currency under the price impact limit.
+		fn query_fee_details_in_currency(
+			uxt: Block::Extrinsic,
+			len: u32,
+			currency_id: CurrencyId,
+		) -> Option<FeeDetails<Balance>>;
+	}
+}
@@ -0,0 +1,142 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the transaction payment module.
+
+use std::{convert::TryInto, sync::Arc};
+
+use codec::{Codec, Decode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay},
+};
+
+pub use self::gen_client::Client as FeeCurrencyClient;
+pub use module_transaction_payment_rpc_runtime_api::{
+	CurrencyId, FeeCurrencyApi as FeeCurrencyRuntimeApi, FeeDetails, InclusionFee,
+};
+
+#[rpc]
+pub trait FeeCurrencyApi<BlockHash> {
+	/// Returns the fee details of the extrinsic quoted in `currency_id`, `None` if there is no
+	/// swap path from `currency_id` to the native currency under the price impact limit.
+	#[rpc(name = "payment_queryFeeDetailsInCurrency")]
+	fn query_fee_details_in_currency(
+		&self,
+		encoded_xt: Bytes,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<Option<FeeDetails<NumberOrHex>>>;
+}
+
+/// A struct that implements the [`FeeCurrencyApi`].
+pub struct FeeCurrency<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> FeeCurrency<C, B> {
+	/// Create new `FeeCurrency` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The transaction was not decodable.
+	DecodeError,
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+		}
+	}
+}
+
+impl<C, Block, Balance> FeeCurrencyApi<<Block as BlockT>::Hash> for FeeCurrency<C, (Block, Balance)>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: FeeCurrencyRuntimeApi<Block, Balance>,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn query_fee_details_in_currency(
+		&self,
+		encoded_xt: Bytes,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<FeeDetails<NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		let encoded_len = encoded_xt.len() as u32;
+		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to query fee details.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		let fee_details = api
+			.query_fee_details_in_currency(&at, uxt, encoded_len, currency_id)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query fee details.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		let try_into_rpc_balance = |value: Balance| {
+			value.try_into().map_err(|_| RpcError {
+				code: ErrorCode::InvalidParams,
+				message: format!("{} doesn't fit in NumberOrHex representation", value),
+				data: None,
+			})
+		};
+
+		fee_details
+			.map(|fee_details| {
+				Ok(FeeDetails {
+					inclusion_fee: match fee_details.inclusion_fee {
+						Some(inclusion_fee) => Some(InclusionFee {
+							base_fee: try_into_rpc_balance(inclusion_fee.base_fee)?,
+							len_fee: try_into_rpc_balance(inclusion_fee.len_fee)?,
+							adjusted_weight_fee: try_into_rpc_balance(inclusion_fee.adjusted_weight_fee)?,
+						}),
+						None => None,
+					},
+					tip: try_into_rpc_balance(fee_details.tip)?,
+				})
+			})
+			.transpose()
+	}
+}
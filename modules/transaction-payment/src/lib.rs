@@ -27,10 +27,11 @@
 #![allow(clippy::unused_unit)]
 
 use frame_support::{
-	dispatch::{DispatchResult, Dispatchable},
+	dispatch::{CallMetadata, DispatchResult, Dispatchable, GetCallMetadata},
 	pallet_prelude::*,
 	traits::{
-		Currency, ExistenceRequirement, Imbalance, NamedReservableCurrency, OnUnbalanced, SameOrOther, WithdrawReasons,
+		Contains, Currency, ExistenceRequirement, Imbalance, NamedReservableCurrency, OnUnbalanced, ReservableCurrency,
+		SameOrOther, WithdrawReasons,
 	},
 	transactional,
 	weights::{
@@ -47,13 +48,13 @@ use primitives::{Balance, CurrencyId, ReserveIdentifier};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, Convert, DispatchInfoOf, One, PostDispatchInfoOf, SaturatedConversion, Saturating,
-		SignedExtension, Zero,
+		AccountIdConversion, CheckedDiv, Convert, DispatchInfoOf, One, PostDispatchInfoOf, SaturatedConversion,
+		Saturating, SignedExtension, Zero,
 	},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill,
+	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 use support::{DEXManager, PriceProvider, Ratio, SwapLimit, TransactionPayment};
@@ -72,6 +73,7 @@ pub use weights::WeightInfo;
 pub type Multiplier = FixedU128;
 
 type PalletBalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type SponsoredCallNameOf<T> = BoundedVec<u8, <T as Config>::MaxSponsoredCallNameLength>;
 type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
@@ -220,6 +222,19 @@ where
 	}
 }
 
+/// The policy under which a sponsor pays the fees of other accounts.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SponsorPolicy<BlockNumber> {
+	/// The max fee sponsored for one account in a period.
+	pub per_user_limit: Balance,
+	/// The max fee sponsored for all accounts in a period.
+	pub per_period_limit: Balance,
+	/// The length of the period in blocks.
+	pub period: BlockNumber,
+	/// The deposit reserved from the sponsor for the storage of the policy.
+	pub deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -317,6 +332,16 @@ pub mod module {
 		/// The price source to provider external market price.
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The joints of the swap paths to try when swapping the fee currency named by the user
+		/// to native currency.
+		#[pallet::constant]
+		type AlternativeSwapPathJointList: Get<Vec<Vec<CurrencyId>>>;
+
+		/// The max price impact of the swap from the fee currency named by the user to native
+		/// currency, compared to the spot price of the swap path.
+		#[pallet::constant]
+		type MaxFeeSwapPriceImpact: Get<Ratio>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 
@@ -330,6 +355,18 @@ pub mod module {
 
 		/// The origin which change swap balance threshold or enable charge fee pool.
 		type UpdateOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
+		/// The max count of calls a sponsor policy can allow.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+
+		/// The max length of the pallet name and the function name of a sponsored call.
+		#[pallet::constant]
+		type MaxSponsoredCallNameLength: Get<u32>;
+
+		/// Deposit for each call allowed by a sponsor policy.
+		#[pallet::constant]
+		type SponsoredCallDeposit: Get<PalletBalanceOf<Self>>;
 	}
 
 	#[pallet::extra_constants]
@@ -359,6 +396,14 @@ pub mod module {
 		DexNotAvailable,
 		/// Charge fee pool is already exist
 		ChargeFeePoolAlreadyExisted,
+		/// The sponsor policy is invalid
+		InvalidSponsorPolicy,
+		/// The sponsor policy allows too many calls
+		TooManySponsoredCalls,
+		/// The name of a sponsored call is too long
+		SponsoredCallNameTooLong,
+		/// The sponsor policy is not found
+		SponsorPolicyNotFound,
 	}
 
 	#[pallet::event]
@@ -377,6 +422,19 @@ pub mod module {
 			pool_size: Balance,
 			swap_threshold: Balance,
 		},
+		/// The sponsor policy is registered.
+		SponsorPolicyRegistered {
+			sponsor: T::AccountId,
+			policy: SponsorPolicy<T::BlockNumber>,
+		},
+		/// The sponsor policy is revoked.
+		SponsorPolicyRevoked { sponsor: T::AccountId },
+		/// The fee of the transaction is paid by the sponsor.
+		FeeSponsored {
+			sponsor: T::AccountId,
+			who: T::AccountId,
+			fee: Balance,
+		},
 	}
 
 	/// The next fee multiplier.
@@ -409,6 +467,52 @@ pub mod module {
 	#[pallet::getter(fn swap_balance_threshold)]
 	pub type SwapBalanceThreshold<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
 
+	/// The sponsor policies.
+	///
+	/// SponsorPolicies: map AccountId => Option<SponsorPolicy>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_policies)]
+	pub type SponsorPolicies<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SponsorPolicy<T::BlockNumber>, OptionQuery>;
+
+	/// The calls allowed by the sponsor policies.
+	///
+	/// SponsoredCalls: double_map AccountId, (PalletNameBytes, FunctionNameBytes) => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_calls)]
+	pub type SponsoredCalls<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		(SponsoredCallNameOf<T>, SponsoredCallNameOf<T>),
+		(),
+		OptionQuery,
+	>;
+
+	/// The fee paid by the sponsor in the period.
+	///
+	/// SponsorUsage: map AccountId => (PeriodIndex, Balance)
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_usage)]
+	pub type SponsorUsage<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (T::BlockNumber, Balance), ValueQuery>;
+
+	/// The fee paid by the sponsor for an account in the period. The usage of a former period is
+	/// reset when it is updated, and is kept when the policy is revoked.
+	///
+	/// SponsoredUserUsage: double_map AccountId, AccountId => (PeriodIndex, Balance)
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_user_usage)]
+	pub type SponsoredUserUsage<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		(T::BlockNumber, Balance),
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -526,6 +630,88 @@ pub mod module {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			Self::initialize_pool(currency_id, pool_size, swap_threshold)
 		}
+
+		/// Register the policy to pay the fees of the `allowed_calls` of other accounts, replacing
+		/// the former policy of the sponsor. The fees paid in the current period are kept.
+		/// `SponsoredCallDeposit` is reserved for each allowed call.
+		///
+		/// - `per_user_limit`: the max fee sponsored for one account in a period.
+		/// - `per_period_limit`: the max fee sponsored for all accounts in a period.
+		/// - `period`: the length of the period in blocks.
+		/// - `allowed_calls`: the (pallet name, function name) of the sponsored calls.
+		#[pallet::weight(<T as Config>::WeightInfo::register_sponsor_policy(allowed_calls.len() as u32))]
+		#[transactional]
+		pub fn register_sponsor_policy(
+			origin: OriginFor<T>,
+			per_user_limit: Balance,
+			per_period_limit: Balance,
+			period: T::BlockNumber,
+			allowed_calls: Vec<(Vec<u8>, Vec<u8>)>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				!period.is_zero()
+					&& !per_user_limit.is_zero()
+					&& !per_period_limit.is_zero()
+					&& !allowed_calls.is_empty(),
+				Error::<T>::InvalidSponsorPolicy
+			);
+			ensure!(
+				allowed_calls.len() as u32 <= T::MaxSponsoredCalls::get(),
+				Error::<T>::TooManySponsoredCalls
+			);
+			let allowed_calls = allowed_calls
+				.into_iter()
+				.map(|(pallet_name, function_name)| {
+					Ok((
+						pallet_name
+							.try_into()
+							.map_err(|_| Error::<T>::SponsoredCallNameTooLong)?,
+						function_name
+							.try_into()
+							.map_err(|_| Error::<T>::SponsoredCallNameTooLong)?,
+					))
+				})
+				.collect::<Result<Vec<(SponsoredCallNameOf<T>, SponsoredCallNameOf<T>)>, Error<T>>>()?;
+
+			let deposit = T::SponsoredCallDeposit::get().saturating_mul(allowed_calls.len() as Balance);
+			let old_deposit = Self::sponsor_policies(&sponsor).map_or(Zero::zero(), |policy| policy.deposit);
+			if deposit > old_deposit {
+				<T as Config>::Currency::reserve(&sponsor, deposit.saturating_sub(old_deposit))?;
+			} else {
+				<T as Config>::Currency::unreserve(&sponsor, old_deposit.saturating_sub(deposit));
+			}
+
+			let policy = SponsorPolicy {
+				per_user_limit,
+				per_period_limit,
+				period,
+				deposit,
+			};
+			// the former calls are bounded by `MaxSponsoredCalls`
+			SponsoredCalls::<T>::remove_prefix(&sponsor, None);
+			for call in allowed_calls {
+				SponsoredCalls::<T>::insert(&sponsor, call, ());
+			}
+			SponsorPolicies::<T>::insert(&sponsor, &policy);
+			Self::deposit_event(Event::SponsorPolicyRegistered { sponsor, policy });
+			Ok(())
+		}
+
+		/// Revoke the sponsor policy, the fees of other accounts are no longer paid and the deposit
+		/// is returned. The fees paid in the current period are kept, they are reset lazily in the
+		/// next period.
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_sponsor_policy())]
+		#[transactional]
+		pub fn revoke_sponsor_policy(origin: OriginFor<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			let policy = SponsorPolicies::<T>::take(&sponsor).ok_or(Error::<T>::SponsorPolicyNotFound)?;
+			// the calls are bounded by `MaxSponsoredCalls`
+			SponsoredCalls::<T>::remove_prefix(&sponsor, None);
+			<T as Config>::Currency::unreserve(&sponsor, policy.deposit);
+			Self::deposit_event(Event::SponsorPolicyRevoked { sponsor });
+			Ok(())
+		}
 	}
 }
 
@@ -581,6 +767,19 @@ where
 		Self::compute_fee_details(len, &dispatch_info, 0u32.into())
 	}
 
+	/// Query the detailed fee of a given `call`, quoted in `currency_id`.
+	pub fn query_fee_details_in_currency<Extrinsic: GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		currency_id: CurrencyId,
+	) -> Option<FeeDetails<PalletBalanceOf<T>>>
+	where
+		T::Call: Dispatchable<Info = DispatchInfo>,
+	{
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		Self::compute_fee_details_in_currency(len, &dispatch_info, 0u32.into(), currency_id)
+	}
+
 	/// Compute the fee details for a particular transaction.
 	pub fn compute_fee_details(
 		len: u32,
@@ -706,6 +905,70 @@ where
 		T::WeightToFee::calc(&capped_weight)
 	}
 
+	/// The index of the current period of a sponsor policy.
+	fn sponsor_period_index(period: T::BlockNumber) -> T::BlockNumber {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&period)
+			.unwrap_or_default()
+	}
+
+	/// Ensure the policy of `sponsor` allows to pay `fee` for `who` to dispatch `call`.
+	pub fn ensure_sponsored(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::Call,
+		fee: PalletBalanceOf<T>,
+	) -> Result<(), TransactionValidityError>
+	where
+		<T as frame_system::Config>::Call: GetCallMetadata,
+	{
+		let policy = Self::sponsor_policies(sponsor).ok_or(InvalidTransaction::Payment)?;
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = call.get_call_metadata();
+		let sponsored_call: Option<(SponsoredCallNameOf<T>, SponsoredCallNameOf<T>)> = pallet_name
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.ok()
+			.zip(function_name.as_bytes().to_vec().try_into().ok());
+		ensure!(
+			sponsored_call.map_or(false, |sponsored_call| SponsoredCalls::<T>::contains_key(
+				sponsor,
+				sponsored_call
+			)),
+			InvalidTransaction::Payment
+		);
+
+		let period_index = Self::sponsor_period_index(policy.period);
+		let used = |(index, amount): (T::BlockNumber, Balance)| if index == period_index { amount } else { 0 };
+		ensure!(
+			used(Self::sponsor_usage(sponsor)).saturating_add(fee) <= policy.per_period_limit
+				&& used(Self::sponsored_user_usage(sponsor, who)).saturating_add(fee) <= policy.per_user_limit,
+			InvalidTransaction::Payment
+		);
+		Ok(())
+	}
+
+	/// Update the fee paid by `sponsor` for `who` in the current period: `paid` is added and
+	/// `refunded` is subtracted.
+	fn note_sponsored_fee(sponsor: &T::AccountId, who: &T::AccountId, paid: Balance, refunded: Balance) {
+		let period_index = match Self::sponsor_policies(sponsor) {
+			Some(policy) => Self::sponsor_period_index(policy.period),
+			None => return,
+		};
+		let update = |(index, amount): &mut (T::BlockNumber, Balance)| {
+			if *index != period_index {
+				*index = period_index;
+				*amount = Zero::zero();
+			}
+			*amount = amount.saturating_add(paid).saturating_sub(refunded);
+		};
+		SponsorUsage::<T>::mutate(sponsor, update);
+		SponsoredUserUsage::<T>::mutate(sponsor, who, update);
+	}
+
 	pub fn ensure_can_charge_fee(who: &T::AccountId, fee: PalletBalanceOf<T>, reason: WithdrawReasons) {
 		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
 		let total_native = <T as Config>::Currency::total_balance(who);
@@ -787,6 +1050,99 @@ where
 		Ok(())
 	}
 
+	/// Find the swap path from `currency_id` to native currency to get `native_amount`, whose
+	/// price impact is under `MaxFeeSwapPriceImpact`. Returns the path and the supply amount of
+	/// `currency_id`.
+	pub fn get_fee_swap_path(currency_id: CurrencyId, native_amount: Balance) -> Option<(Vec<CurrencyId>, Balance)> {
+		let limit = SwapLimit::ExactTarget(Balance::MAX, native_amount);
+		let path = T::DEX::get_best_price_swap_path(
+			currency_id,
+			T::NativeCurrencyId::get(),
+			limit,
+			T::AlternativeSwapPathJointList::get(),
+		)?;
+		let (supply_amount, _) = T::DEX::get_swap_amount(&path, limit)?;
+
+		// the supply amount at the spot price of the pools in the path
+		let spot_supply_amount = path.windows(2).rev().fold(native_amount, |amount, pair| {
+			let (supply_pool, target_pool) = T::DEX::get_liquidity_pool(pair[0], pair[1]);
+			Ratio::checked_from_rational(supply_pool, target_pool)
+				.unwrap_or_default()
+				.saturating_mul_int(amount)
+		});
+		let price_impact = Ratio::one()
+			.saturating_sub(Ratio::checked_from_rational(spot_supply_amount, supply_amount).unwrap_or_default());
+		if price_impact > T::MaxFeeSwapPriceImpact::get() {
+			return None;
+		}
+
+		Some((path, supply_amount))
+	}
+
+	/// Swap the fee currency named by the user to native currency to pay `fee`, along with the
+	/// gap to keep alive. Returns the swap path and the price of native currency paid in the fee
+	/// currency.
+	#[transactional]
+	fn swap_fee_currency(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		fee: PalletBalanceOf<T>,
+	) -> Result<(Vec<CurrencyId>, Ratio), DispatchError> {
+		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
+		let total_native = <T as Config>::Currency::total_balance(who);
+		let amount = fee.saturating_add(native_existential_deposit.saturating_sub(total_native));
+
+		let (path, supply_amount) = Self::get_fee_swap_path(currency_id, amount).ok_or(Error::<T>::InvalidSwapPath)?;
+		let (supply_amount, target_amount) =
+			T::DEX::swap_with_specific_path(who, &path, SwapLimit::ExactTarget(supply_amount, amount))?;
+		let price = Ratio::checked_from_rational(supply_amount, target_amount).ok_or(Error::<T>::InvalidSwapPath)?;
+		Ok((path, price))
+	}
+
+	/// Compute the fee details for a particular transaction, quoted in `currency_id`.
+	/// Returns `None` if there is no swap path from `currency_id` to native currency.
+	pub fn compute_fee_details_in_currency(
+		len: u32,
+		info: &DispatchInfoOf<T::Call>,
+		tip: PalletBalanceOf<T>,
+		currency_id: CurrencyId,
+	) -> Option<FeeDetails<PalletBalanceOf<T>>>
+	where
+		T::Call: Dispatchable<Info = DispatchInfo>,
+	{
+		let fee_details = Self::compute_fee_details(len, info, tip);
+		let final_fee = fee_details.final_fee();
+		if currency_id == T::NativeCurrencyId::get() || final_fee.is_zero() {
+			return Some(fee_details);
+		}
+
+		let (_, supply_amount) = Self::get_fee_swap_path(currency_id, final_fee)?;
+		let rate = Ratio::checked_from_rational(supply_amount, final_fee)?;
+		Some(match fee_details.inclusion_fee {
+			Some(inclusion_fee) => {
+				let base_fee = rate.saturating_mul_int(inclusion_fee.base_fee);
+				let len_fee = rate.saturating_mul_int(inclusion_fee.len_fee);
+				let tip = rate.saturating_mul_int(fee_details.tip);
+				FeeDetails {
+					inclusion_fee: Some(InclusionFee {
+						base_fee,
+						len_fee,
+						// the remainder of rounding goes to the weight fee.
+						adjusted_weight_fee: supply_amount
+							.saturating_sub(base_fee)
+							.saturating_sub(len_fee)
+							.saturating_sub(tip),
+					}),
+					tip,
+				}
+			}
+			None => FeeDetails {
+				inclusion_fee: None,
+				tip: supply_amount,
+			},
+		})
+	}
+
 	/// Get trading path by user.
 	fn get_trading_path(who: &T::AccountId) -> Vec<Vec<CurrencyId>> {
 		let mut default_fee_swap_path_list = T::DefaultFeeSwapPathList::get();
@@ -867,6 +1223,14 @@ where
 	}
 }
 
+/// Currencies that have a charge fee pool enabled.
+pub struct FeePoolCurrencies<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Contains<CurrencyId> for FeePoolCurrencies<T> {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		TokenExchangeRate::<T>::contains_key(currency_id) || !PoolSize::<T>::get(currency_id).is_zero()
+	}
+}
+
 /// `WeightTrader` implementation used for `Trader`, the `rate` is read from storage,
 /// and `token_per_second` is calculated by `rate` * `native_asset_per_second`.
 pub struct TransactionFeePoolTrader<T, C, K: Get<u128>, R: TakeRevenue> {
@@ -981,14 +1345,29 @@ where
 ///
 /// Operational transactions will receive an additional priority bump, so that they are normally
 /// considered before regular transactions.
+///
+/// # Fee Currency
+///
+/// The transactor can name a non-native fee currency, the fee is swapped from it over the DEX and
+/// the unused fee is refunded in it.
+///
+/// # Sponsor
+///
+/// The transactor can name a sponsor to pay the fee, if the call and the fee are allowed by the
+/// policy of the sponsor. The fee is charged from the sponsor in native currency, the fee currency
+/// is ignored.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
-pub struct ChargeTransactionPayment<T: Config + Send + Sync>(#[codec(compact)] pub PalletBalanceOf<T>);
+pub struct ChargeTransactionPayment<T: Config + Send + Sync>(
+	#[codec(compact)] pub PalletBalanceOf<T>,
+	pub Option<CurrencyId>,
+	pub Option<T::AccountId>,
+);
 
 impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeTransactionPayment<T> {
 	#[cfg(feature = "std")]
 	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
-		write!(f, "ChargeTransactionPayment<{:?}>", self.0)
+		write!(f, "ChargeTransactionPayment<{:?}, {:?}, {:?}>", self.0, self.1, self.2)
 	}
 	#[cfg(not(feature = "std"))]
 	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
@@ -998,27 +1377,47 @@ impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeTransactionPayment<T>
 
 impl<T: Config + Send + Sync> ChargeTransactionPayment<T>
 where
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + GetCallMetadata,
 	PalletBalanceOf<T>: Send + Sync + FixedPointOperand,
 {
 	/// utility constructor. Used only in client/factory code.
 	pub fn from(fee: PalletBalanceOf<T>) -> Self {
-		Self(fee)
+		Self(fee, None, None)
+	}
+
+	/// Pay the fee in `currency_id` instead of native currency.
+	pub fn with_fee_currency(mut self, currency_id: CurrencyId) -> Self {
+		self.1 = Some(currency_id);
+		self
+	}
+
+	/// Charge the fee from `sponsor` instead of the transactor.
+	pub fn with_sponsor(mut self, sponsor: T::AccountId) -> Self {
+		self.2 = Some(sponsor);
+		self
 	}
 
+	#[allow(clippy::type_complexity)]
 	fn withdraw_fee(
 		&self,
 		who: &T::AccountId,
-		_call: &<T as frame_system::Config>::Call,
+		call: &<T as frame_system::Config>::Call,
 		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
 		len: usize,
-	) -> Result<(PalletBalanceOf<T>, Option<NegativeImbalanceOf<T>>), TransactionValidityError> {
+	) -> Result<
+		(
+			PalletBalanceOf<T>,
+			Option<NegativeImbalanceOf<T>>,
+			Option<(Vec<CurrencyId>, Ratio)>,
+		),
+		TransactionValidityError,
+	> {
 		let tip = self.0;
 		let fee = Pallet::<T>::compute_fee(len as u32, info, tip);
 
 		// Only mess with balances if fee is not zero.
 		if fee.is_zero() {
-			return Ok((fee, None));
+			return Ok((fee, None, None));
 		}
 
 		let reason = if tip.is_zero() {
@@ -1027,11 +1426,30 @@ where
 			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
 		};
 
-		Pallet::<T>::ensure_can_charge_fee(who, fee, reason);
+		if let Some(sponsor) = &self.2 {
+			Pallet::<T>::ensure_sponsored(sponsor, who, call, fee)?;
+			Pallet::<T>::ensure_can_charge_fee(sponsor, fee, reason);
+
+			// withdraw native currency as fee from the sponsor
+			let imbalance = <T as Config>::Currency::withdraw(sponsor, fee, reason, ExistenceRequirement::KeepAlive)
+				.map_err(|_| InvalidTransaction::Payment)?;
+			Pallet::<T>::note_sponsored_fee(sponsor, who, fee, Zero::zero());
+			return Ok((fee, Some(imbalance), None));
+		}
+
+		let fee_swap_path = match self.1 {
+			Some(currency_id) if currency_id != T::NativeCurrencyId::get() => {
+				Some(Pallet::<T>::swap_fee_currency(who, currency_id, fee).map_err(|_| InvalidTransaction::Payment)?)
+			}
+			_ => {
+				Pallet::<T>::ensure_can_charge_fee(who, fee, reason);
+				None
+			}
+		};
 
 		// withdraw native currency as fee
 		match <T as Config>::Currency::withdraw(who, fee, reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok((fee, Some(imbalance))),
+			Ok(imbalance) => Ok((fee, Some(imbalance), fee_swap_path)),
 			Err(_) => Err(InvalidTransaction::Payment.into()),
 		}
 	}
@@ -1119,7 +1537,7 @@ where
 impl<T: Config + Send + Sync> SignedExtension for ChargeTransactionPayment<T>
 where
 	PalletBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + GetCallMetadata,
 {
 	const IDENTIFIER: &'static str = "ChargeTransactionPayment";
 	type AccountId = T::AccountId;
@@ -1130,6 +1548,8 @@ where
 		Self::AccountId,
 		Option<NegativeImbalanceOf<T>>,
 		PalletBalanceOf<T>,
+		Option<(Vec<CurrencyId>, Ratio)>,
+		Option<Self::AccountId>,
 	);
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -1143,7 +1563,7 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (final_fee, _) = self.withdraw_fee(who, call, info, len)?;
+		let (final_fee, _, _) = self.withdraw_fee(who, call, info, len)?;
 		let tip = self.0;
 		Ok(ValidTransaction {
 			priority: Self::get_priority(info, len, tip, final_fee),
@@ -1158,8 +1578,14 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, who.clone(), imbalance, fee))
+		let (fee, imbalance, fee_swap_path) = self.withdraw_fee(who, call, info, len)?;
+		// the fee is refunded to the payer, the sponsored account is noted to correct the usage of
+		// the sponsor.
+		let (payer, sponsored) = match self.2 {
+			Some(sponsor) => (sponsor, Some(who.clone())),
+			None => (who.clone(), None),
+		};
+		Ok((self.0, payer, imbalance, fee, fee_swap_path, sponsored))
 	}
 
 	fn post_dispatch(
@@ -1169,7 +1595,7 @@ where
 		len: usize,
 		_result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		let (tip, who, imbalance, fee) = pre;
+		let (tip, who, imbalance, fee, fee_swap_path, sponsored) = pre;
 		if let Some(payed) = imbalance {
 			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
 			let refund_fee = fee.saturating_sub(actual_fee);
@@ -1185,8 +1611,10 @@ where
 				refund = refund_fee.saturating_add(refund_tip);
 				actual_tip = tip.saturating_sub(refund_tip);
 			}
+			let mut refunded: PalletBalanceOf<T> = Zero::zero();
 			let actual_payment = match <T as Config>::Currency::deposit_into_existing(&who, refund) {
 				Ok(refund_imbalance) => {
+					refunded = refund_imbalance.peek();
 					// The refund cannot be larger than the up front payed max weight.
 					// `PostDispatchInfo::calc_unspent` guards against such a case.
 					match payed.offset(refund_imbalance) {
@@ -1199,8 +1627,36 @@ where
 				// is gone in that case.
 				Err(_) => payed,
 			};
+
+			if let Some(sponsored) = sponsored {
+				Pallet::<T>::note_sponsored_fee(&who, &sponsored, Zero::zero(), refunded);
+				Pallet::<T>::deposit_event(Event::FeeSponsored {
+					sponsor: who.clone(),
+					who: sponsored,
+					fee: actual_payment.peek(),
+				});
+			}
+
 			let (tip, fee) = actual_payment.split(actual_tip);
 
+			// refund in the fee currency named by the user, at no worse than the price of the charge
+			// swap less `MaxFeeSwapPriceImpact`.
+			if let Some((mut path, price)) = fee_swap_path {
+				path.reverse();
+				if !refunded.is_zero() {
+					let min_target_amount = Ratio::one()
+						.saturating_sub(T::MaxFeeSwapPriceImpact::get())
+						.saturating_mul(price)
+						.saturating_mul_int(refunded);
+					// ignore the result, the refund stays in native currency if the swap fails.
+					let _ = T::DEX::swap_with_specific_path(
+						&who,
+						&path,
+						SwapLimit::ExactSupply(refunded, min_target_amount),
+					);
+				}
+			}
+
 			// distribute fee
 			<T as Config>::OnTransactionPayment::on_unbalanceds(Some(fee).into_iter().chain(Some(tip)));
 		}
@@ -107,6 +107,10 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -115,7 +119,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -192,6 +201,11 @@ parameter_types! {
 	pub MaxTipsOfPriority: u128 = 1000;
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![AUSD, ACA], vec![DOT, AUSD, ACA]];
 	pub AlternativeFeeSwapDeposit: Balance = 1000;
+	pub AlternativeSwapPathJointList: Vec<Vec<CurrencyId>> = vec![vec![AUSD]];
+	pub MaxFeeSwapPriceImpact: Ratio = Ratio::saturating_from_rational(1, 5);
+	pub const MaxSponsoredCalls: u32 = 2;
+	pub const MaxSponsoredCallNameLength: u32 = 32;
+	pub SponsoredCallDeposit: Balance = 10;
 }
 
 thread_local! {
@@ -262,10 +276,15 @@ impl Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
+	type AlternativeSwapPathJointList = AlternativeSwapPathJointList;
+	type MaxFeeSwapPriceImpact = MaxFeeSwapPriceImpact;
 	type WeightInfo = ();
 	type PalletId = TransactionPaymentPalletId;
 	type TreasuryAccount = KaruraTreasuryAccount;
 	type UpdateOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type MaxSponsoredCalls = MaxSponsoredCalls;
+	type MaxSponsoredCallNameLength = MaxSponsoredCallNameLength;
+	type SponsoredCallDeposit = SponsoredCallDeposit;
 }
 
 thread_local! {
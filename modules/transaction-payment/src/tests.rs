@@ -28,9 +28,9 @@ use frame_support::{
 	weights::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, AlternativeFeeSwapDeposit, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, FeePoolSize,
-	MockPriceSource, Origin, Runtime, TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DOT, FEE_UNBALANCED_AMOUNT,
-	TIP_UNBALANCED_AMOUNT,
+	AccountId, AlternativeFeeSwapDeposit, BlockWeights, Call, Currencies, DEXModule, Event, ExtBuilder, FeePoolSize,
+	MockPriceSource, Origin, Runtime, SponsoredCallDeposit, System, TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE,
+	DOT, FEE_UNBALANCED_AMOUNT, TIP_UNBALANCED_AMOUNT,
 };
 use orml_traits::MultiCurrency;
 use primitives::currency::*;
@@ -1225,3 +1225,406 @@ fn enable_init_pool_works() {
 		);
 	});
 }
+
+#[test]
+fn charge_fee_in_named_fee_currency_and_refund() {
+	builder_with_upgraded_executed(true).execute_with(|| {
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+		assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000, 1000));
+		assert_eq!(Currencies::total_balance(ACA, &BOB), 0);
+
+		// fee = len * byte + weight, swap ED more to keep alive.
+		let fee = 10 * 2 + 100;
+		assert_eq!(
+			Pallet::<Runtime>::get_fee_swap_path(AUSD, fee + 10),
+			Some((vec![AUSD, ACA], 14))
+		);
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.with_fee_currency(AUSD)
+			.pre_dispatch(&BOB, CALL, &INFO2, 10)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 10);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 986);
+		assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (9870, 1014));
+
+		// refund 50 in AUSD
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			pre,
+			&INFO2,
+			&PostDispatchInfo {
+				actual_weight: Some(50),
+				pays_fee: Pays::Yes,
+			},
+			10,
+			&Ok(())
+		));
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 10);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 991);
+		assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (9920, 1009));
+		assert_eq!(FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow()), fee - 50);
+	});
+}
+
+#[test]
+fn refund_stays_in_native_currency_if_fee_currency_price_moved() {
+	builder_with_upgraded_executed(true).execute_with(|| {
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+
+		// charge 14 AUSD for 130 ACA
+		let fee = 10 * 2 + 100;
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.with_fee_currency(AUSD)
+			.pre_dispatch(&BOB, CALL, &INFO2, 10)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 10);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 986);
+
+		// the price of ACA is dumped before the refund
+		assert_ok!(DEXModule::swap_with_exact_supply(
+			Origin::signed(ALICE),
+			vec![ACA, AUSD],
+			10000,
+			0
+		));
+
+		// the refund of 50 ACA would get less than 4 AUSD, keep it in ACA
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			pre,
+			&INFO2,
+			&PostDispatchInfo {
+				actual_weight: Some(50),
+				pays_fee: Pays::Yes,
+			},
+			10,
+			&Ok(())
+		));
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 60);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 986);
+		assert_eq!(FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow()), fee - 50);
+	});
+}
+
+#[test]
+fn charge_fee_in_named_fee_currency_by_discovered_path() {
+	builder_with_upgraded_executed(true).execute_with(|| {
+		assert_ok!(Currencies::update_balance(
+			Origin::root(),
+			ALICE,
+			DOT,
+			10000.unique_saturated_into(),
+		));
+		assert_ok!(Currencies::update_balance(
+			Origin::root(),
+			ALICE,
+			AUSD,
+			100000.unique_saturated_into(),
+		));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(ALICE),
+			DOT,
+			AUSD,
+			9000,
+			90000,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (9100, 91000));
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+
+		// there is no DOT-ACA pool, swap by the joint AUSD
+		let fee = 23 * 2 + 1000;
+		assert_eq!(
+			Pallet::<Runtime>::get_fee_swap_path(DOT, fee + 10),
+			Some((vec![DOT, AUSD, ACA], 12))
+		);
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0)
+			.with_fee_currency(DOT)
+			.pre_dispatch(&BOB, CALL, &INFO, 23));
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 10);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 88);
+	});
+}
+
+#[test]
+fn charge_fee_in_named_fee_currency_failed_by_price_impact() {
+	builder_with_upgraded_executed(true).execute_with(|| {
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 1000));
+
+		// fee = len * byte + weight, the price impact of swapping it is over 20%.
+		let fee = 1000 * 2 + 1000;
+		assert_eq!(
+			DEXModule::get_swap_amount(&vec![AUSD, ACA], SwapLimit::ExactTarget(Balance::MAX, fee + 10)),
+			Some((431, fee + 10))
+		);
+		assert_eq!(Pallet::<Runtime>::get_fee_swap_path(AUSD, fee + 10), None);
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0)
+				.with_fee_currency(AUSD)
+				.validate(&BOB, CALL, &INFO, 1000),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+		assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000, 1000));
+	});
+}
+
+#[test]
+fn compute_fee_details_in_currency_works() {
+	builder_with_upgraded_executed(true).execute_with(|| {
+		let fee_details = Pallet::<Runtime>::compute_fee_details(10, &INFO2, 0);
+		assert_eq!(fee_details.final_fee(), 120);
+		assert_eq!(
+			Pallet::<Runtime>::compute_fee_details_in_currency(10, &INFO2, 0, ACA),
+			Some(fee_details)
+		);
+
+		// quoted 13 AUSD for 120 ACA
+		assert_eq!(
+			Pallet::<Runtime>::compute_fee_details_in_currency(10, &INFO2, 0, AUSD),
+			Some(FeeDetails {
+				inclusion_fee: Some(InclusionFee {
+					base_fee: 0,
+					len_fee: 2,
+					adjusted_weight_fee: 11,
+				}),
+				tip: 0,
+			})
+		);
+
+		// the price impact of DOT is over the limit
+		assert_eq!(
+			Pallet::<Runtime>::compute_fee_details_in_currency(10, &INFO2, 0, DOT),
+			None
+		);
+	});
+}
+
+#[test]
+fn register_and_revoke_sponsor_policy_work() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let transfer = (b"Currencies".to_vec(), b"transfer".to_vec());
+			let transfer_native = (b"Currencies".to_vec(), b"transfer_native_currency".to_vec());
+			let bounded = |(pallet_name, function_name): (Vec<u8>, Vec<u8>)| {
+				(
+					SponsoredCallNameOf::<Runtime>::try_from(pallet_name).unwrap(),
+					SponsoredCallNameOf::<Runtime>::try_from(function_name).unwrap(),
+				)
+			};
+
+			assert_noop!(
+				TransactionPayment::register_sponsor_policy(
+					Origin::signed(ALICE),
+					100,
+					1000,
+					0,
+					vec![transfer.clone()]
+				),
+				Error::<Runtime>::InvalidSponsorPolicy
+			);
+			assert_noop!(
+				TransactionPayment::register_sponsor_policy(Origin::signed(ALICE), 100, 0, 10, vec![transfer.clone()]),
+				Error::<Runtime>::InvalidSponsorPolicy
+			);
+			assert_noop!(
+				TransactionPayment::register_sponsor_policy(Origin::signed(ALICE), 100, 1000, 10, vec![]),
+				Error::<Runtime>::InvalidSponsorPolicy
+			);
+			assert_noop!(
+				TransactionPayment::register_sponsor_policy(
+					Origin::signed(ALICE),
+					100,
+					1000,
+					10,
+					vec![
+						transfer.clone(),
+						transfer_native.clone(),
+						(b"DEX".to_vec(), b"swap".to_vec())
+					]
+				),
+				Error::<Runtime>::TooManySponsoredCalls
+			);
+			assert_noop!(
+				TransactionPayment::register_sponsor_policy(
+					Origin::signed(ALICE),
+					100,
+					1000,
+					10,
+					vec![(b"Currencies".to_vec(), vec![0u8; 33])]
+				),
+				Error::<Runtime>::SponsoredCallNameTooLong
+			);
+
+			assert_ok!(TransactionPayment::register_sponsor_policy(
+				Origin::signed(ALICE),
+				100,
+				1000,
+				10,
+				vec![transfer.clone(), transfer_native.clone()]
+			));
+			let policy = SponsorPolicy {
+				per_user_limit: 100,
+				per_period_limit: 1000,
+				period: 10,
+				deposit: 2 * SponsoredCallDeposit::get(),
+			};
+			System::assert_last_event(Event::TransactionPayment(crate::Event::SponsorPolicyRegistered {
+				sponsor: ALICE,
+				policy: policy.clone(),
+			}));
+			assert_eq!(TransactionPayment::sponsor_policies(ALICE), Some(policy));
+			assert_eq!(
+				TransactionPayment::sponsored_calls(ALICE, bounded(transfer_native.clone())),
+				Some(())
+			);
+			assert_eq!(
+				Currencies::reserved_balance(ACA, &ALICE),
+				2 * SponsoredCallDeposit::get()
+			);
+
+			// register again to replace the policy, the deposit of the removed call is returned
+			assert_ok!(TransactionPayment::register_sponsor_policy(
+				Origin::signed(ALICE),
+				200,
+				1000,
+				10,
+				vec![transfer.clone()]
+			));
+			assert_eq!(
+				TransactionPayment::sponsored_calls(ALICE, bounded(transfer.clone())),
+				Some(())
+			);
+			assert_eq!(
+				TransactionPayment::sponsored_calls(ALICE, bounded(transfer_native.clone())),
+				None
+			);
+			assert_eq!(Currencies::reserved_balance(ACA, &ALICE), SponsoredCallDeposit::get());
+
+			assert_ok!(TransactionPayment::revoke_sponsor_policy(Origin::signed(ALICE)));
+			System::assert_last_event(Event::TransactionPayment(crate::Event::SponsorPolicyRevoked {
+				sponsor: ALICE,
+			}));
+			assert_eq!(TransactionPayment::sponsor_policies(ALICE), None);
+			assert_eq!(TransactionPayment::sponsored_calls(ALICE, bounded(transfer)), None);
+			assert_eq!(Currencies::reserved_balance(ACA, &ALICE), 0);
+			assert_noop!(
+				TransactionPayment::revoke_sponsor_policy(Origin::signed(ALICE)),
+				Error::<Runtime>::SponsorPolicyNotFound
+			);
+
+			// the deposit must be reserved
+			assert_noop!(
+				TransactionPayment::register_sponsor_policy(Origin::signed(BOB), 100, 1000, 10, vec![transfer_native]),
+				pallet_balances::Error::<Runtime>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn charge_fee_by_sponsor_works() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(TransactionPayment::register_sponsor_policy(
+				Origin::signed(ALICE),
+				2000,
+				3000,
+				10,
+				vec![(b"Currencies".to_vec(), b"transfer".to_vec())]
+			));
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+
+			// fee = len * byte + weight
+			let fee = 10 + 1000;
+			let pre = ChargeTransactionPayment::<Runtime>::from(0)
+				.with_sponsor(ALICE)
+				.pre_dispatch(&BOB, CALL, &INFO, 10)
+				.unwrap();
+			let deposit = SponsoredCallDeposit::get();
+			assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - deposit - fee);
+			assert_eq!(TransactionPayment::sponsor_usage(ALICE), (0, fee));
+
+			// refund 200 to the sponsor
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				pre,
+				&INFO,
+				&POST_INFO,
+				10,
+				&Ok(())
+			));
+			assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - deposit - fee + 200);
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+			System::assert_last_event(Event::TransactionPayment(crate::Event::FeeSponsored {
+				sponsor: ALICE,
+				who: BOB,
+				fee: fee - 200,
+			}));
+			assert_eq!(TransactionPayment::sponsor_usage(ALICE), (0, fee - 200));
+			assert_eq!(TransactionPayment::sponsored_user_usage(ALICE, BOB), (0, fee - 200));
+
+			// the call is not sponsored
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.with_sponsor(ALICE)
+					.validate(&BOB, CALL2, &INFO, 10),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+			// CHARLIE is not a sponsor
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.with_sponsor(CHARLIE)
+					.validate(&BOB, CALL, &INFO, 10),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+
+			assert_ok!(ChargeTransactionPayment::<Runtime>::from(0)
+				.with_sponsor(ALICE)
+				.pre_dispatch(&BOB, CALL, &INFO, 10));
+			assert_eq!(TransactionPayment::sponsored_user_usage(ALICE, BOB), (0, 2 * fee - 200));
+
+			// exceed the per user limit
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.with_sponsor(ALICE)
+					.validate(&BOB, CALL, &INFO, 10),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+
+			assert_ok!(ChargeTransactionPayment::<Runtime>::from(0)
+				.with_sponsor(ALICE)
+				.pre_dispatch(&CHARLIE, CALL, &INFO, 10));
+			assert_eq!(TransactionPayment::sponsor_usage(ALICE), (0, 3 * fee - 200));
+
+			// exceed the per period limit
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.with_sponsor(ALICE)
+					.validate(&CHARLIE, CALL, &INFO, 10),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+
+			// the usage is reset in the next period
+			System::set_block_number(10);
+			assert_ok!(ChargeTransactionPayment::<Runtime>::from(0)
+				.with_sponsor(ALICE)
+				.pre_dispatch(&BOB, CALL, &INFO, 10));
+			assert_eq!(TransactionPayment::sponsor_usage(ALICE), (1, fee));
+			assert_eq!(TransactionPayment::sponsored_user_usage(ALICE, BOB), (1, fee));
+			assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - deposit - 4 * fee + 200);
+
+			// the usage is kept when the policy is revoked
+			assert_ok!(TransactionPayment::revoke_sponsor_policy(Origin::signed(ALICE)));
+			assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - 4 * fee + 200);
+			assert_eq!(TransactionPayment::sponsored_user_usage(ALICE, BOB), (1, fee));
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.with_sponsor(ALICE)
+					.validate(&BOB, CALL, &INFO, 10),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+		});
+}
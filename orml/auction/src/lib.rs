@@ -7,17 +7,27 @@
 //!
 //! The auction logic can be customized by implement and supplying
 //! `AuctionHandler` trait.
+//!
+//! Auctions can be created with a reserve price and a minimum bid increment.
+//! A candle auction ends retroactively at a block drawn from the `Randomness`
+//! source among the last blocks of the auction, and is won by the best bid at
+//! that block. The best bid at the end of each block of its candle period is
+//! held until the auction ends, when the losing ones are released by
+//! `AuctionHandler::on_losing_bid`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 // Disable the following two lints since they originate from an external macro (namely decl_storage)
 #![allow(clippy::string_lit_as_bytes)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::Randomness};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use orml_traits::{Auction, AuctionHandler, AuctionInfo, Change};
+use orml_traits::{Auction, AuctionHandler, AuctionInfo, AuctionParams, Change};
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, Bounded, MaybeSerializeDeserialize, Member, One, Zero},
+	traits::{
+		AtLeast32BitUnsigned, Bounded, MaybeSerializeDeserialize, Member, One, SaturatedConversion, Saturating,
+		TrailingZeroInput, Zero,
+	},
 	DispatchError, DispatchResult,
 };
 
@@ -53,6 +63,9 @@ pub mod module {
 		/// auction result.
 		type Handler: AuctionHandler<Self::AccountId, Self::Balance, Self::BlockNumber, Self::AuctionId>;
 
+		/// The randomness source drawing the end block of candle auctions.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -64,6 +77,9 @@ pub mod module {
 		BidNotAccepted,
 		InvalidBidPrice,
 		NoAvailableAuctionId,
+		BidBelowReservePrice,
+		BidIncrementTooLow,
+		InvalidAuctionParams,
 	}
 
 	#[pallet::event]
@@ -75,6 +91,12 @@ pub mod module {
 			bidder: T::AccountId,
 			amount: T::Balance,
 		},
+		/// A candle auction ended at a randomly drawn candle block
+		CandleAuctionEnded {
+			auction_id: T::AuctionId,
+			candle_block: T::BlockNumber,
+			winner: Option<(T::AccountId, T::Balance)>,
+		},
 	}
 
 	/// Stores on-going and future auctions. Closed auction are removed.
@@ -94,6 +116,26 @@ pub mod module {
 	pub type AuctionEndTime<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Blake2_128Concat, T::AuctionId, (), OptionQuery>;
 
+	/// Stores the parameters of the auctions which have any.
+	#[pallet::storage]
+	#[pallet::getter(fn auction_parameters)]
+	pub type AuctionParameters<T: Config> =
+		StorageMap<_, Twox64Concat, T::AuctionId, AuctionParams<T::Balance, T::BlockNumber>, OptionQuery>;
+
+	/// The best bid at the end of each block of the candle period of candle
+	/// auctions, for the blocks which changed it.
+	#[pallet::storage]
+	#[pallet::getter(fn candle_bids)]
+	pub type CandleBids<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AuctionId,
+		Twox64Concat,
+		T::BlockNumber,
+		(T::AccountId, T::Balance),
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	#[pallet::without_storage_info]
@@ -102,13 +144,34 @@ pub mod module {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(now: T::BlockNumber) -> Weight {
-			T::WeightInfo::on_finalize(AuctionEndTime::<T>::iter_prefix(&now).count() as u32)
+			let (auctions, candle_bids) =
+				AuctionEndTime::<T>::iter_prefix(&now).fold((0u32, 0u32), |(auctions, candle_bids), (id, _)| {
+					// the held bids of a candle auction are released when it ends
+					let bids = CandleBids::<T>::iter_prefix(id).count() as u32;
+					(auctions.saturating_add(1), candle_bids.saturating_add(bids))
+				});
+			T::WeightInfo::on_finalize(auctions, candle_bids)
 		}
 
 		fn on_finalize(now: T::BlockNumber) {
 			for (auction_id, _) in AuctionEndTime::<T>::drain_prefix(&now) {
 				if let Some(auction) = Auctions::<T>::take(&auction_id) {
-					T::Handler::on_auction_ended(auction_id, auction.bid);
+					let params = AuctionParameters::<T>::take(&auction_id).unwrap_or_default();
+					let winner = match params.candle_period {
+						Some(candle_period) => {
+							let candle_block = Self::draw_candle_block(auction_id, now, candle_period);
+							let winner = Self::candle_bid_at(auction_id, candle_block, auction.bid);
+							Self::release_candle_bids(auction_id, winner.as_ref());
+							Self::deposit_event(Event::CandleAuctionEnded {
+								auction_id,
+								candle_block,
+								winner: winner.clone(),
+							});
+							winner
+						}
+						None => auction.bid,
+					};
+					T::Handler::on_auction_ended(auction_id, winner);
 				}
 			}
 		}
@@ -132,16 +195,54 @@ pub mod module {
 				// make sure auction is started
 				ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
 
+				let params = AuctionParameters::<T>::get(id).unwrap_or_default();
 				if let Some(ref current_bid) = auction.bid {
 					ensure!(value > current_bid.1, Error::<T>::InvalidBidPrice);
+					ensure!(
+						value >= current_bid.1.saturating_add(params.min_increment),
+						Error::<T>::BidIncrementTooLow
+					);
 				} else {
 					ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
 				}
-				let bid_result = T::Handler::on_new_bid(block_number, id, (from.clone(), value), auction.bid.clone());
+				ensure!(value >= params.reserve_price, Error::<T>::BidBelowReservePrice);
+
+				let candle_start = match (params.candle_period, auction.end) {
+					(Some(candle_period), Some(end)) => {
+						let candle_start = end.saturating_sub(candle_period).saturating_add(One::one());
+						Some(candle_start).filter(|candle_start| block_number >= *candle_start)
+					}
+					_ => None,
+				};
+				let last_bid = match candle_start {
+					// the last bid is the best bid at the end of a previous block of the candle
+					// period, which may win the auction
+					Some(candle_start)
+						if !CandleBids::<T>::contains_key(id, block_number)
+							&& (block_number > candle_start || CandleBids::<T>::iter_prefix(id).next().is_some()) =>
+					{
+						None
+					}
+					_ => auction.bid.clone(),
+				};
+
+				let bid_result = T::Handler::on_new_bid(block_number, id, (from.clone(), value), last_bid);
 
 				ensure!(bid_result.accept_bid, Error::<T>::BidNotAccepted);
+
+				if let Some(candle_start) = candle_start {
+					// keep the best bid from before the candle period
+					if CandleBids::<T>::iter_prefix(id).next().is_none() {
+						if let Some(ref current_bid) = auction.bid {
+							CandleBids::<T>::insert(id, candle_start, current_bid);
+						}
+					}
+					CandleBids::<T>::insert(id, block_number, (from.clone(), value));
+				}
+
 				match bid_result.auction_end_change {
-					Change::NewValue(new_end) => {
+					// the end of candle auctions is fixed
+					Change::NewValue(new_end) if params.candle_period.is_none() => {
 						if let Some(old_end_block) = auction.end {
 							AuctionEndTime::<T>::remove(&old_end_block, id);
 						}
@@ -150,7 +251,7 @@ pub mod module {
 						}
 						auction.end = new_end;
 					}
-					Change::NoChange => {}
+					_ => {}
 				}
 				auction.bid = Some((from.clone(), value));
 
@@ -194,6 +295,22 @@ impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
 		start: T::BlockNumber,
 		end: Option<T::BlockNumber>,
 	) -> sp_std::result::Result<Self::AuctionId, DispatchError> {
+		Self::new_auction_with_params(start, end, Default::default())
+	}
+
+	fn new_auction_with_params(
+		start: T::BlockNumber,
+		end: Option<T::BlockNumber>,
+		params: AuctionParams<Self::Balance, T::BlockNumber>,
+	) -> sp_std::result::Result<Self::AuctionId, DispatchError> {
+		if let Some(candle_period) = params.candle_period {
+			let end = end.ok_or(Error::<T>::InvalidAuctionParams)?;
+			ensure!(
+				!candle_period.is_zero() && candle_period <= end.saturating_sub(start),
+				Error::<T>::InvalidAuctionParams
+			);
+		}
+
 		let auction = AuctionInfo { bid: None, start, end };
 		let auction_id =
 			<AuctionsIndex<T>>::try_mutate(|n| -> sp_std::result::Result<Self::AuctionId, DispatchError> {
@@ -206,6 +323,9 @@ impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
 		if let Some(end_block) = end {
 			AuctionEndTime::<T>::insert(&end_block, auction_id, ());
 		}
+		if params != Default::default() {
+			AuctionParameters::<T>::insert(auction_id, params);
+		}
 
 		Ok(auction_id)
 	}
@@ -215,6 +335,53 @@ impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
 			if let Some(end_block) = auction.end {
 				AuctionEndTime::<T>::remove(end_block, id);
 			}
+			AuctionParameters::<T>::remove(id);
+			Self::release_candle_bids(id, auction.bid.as_ref());
+		}
+	}
+
+	fn auction_params(id: Self::AuctionId) -> Option<AuctionParams<Self::Balance, T::BlockNumber>> {
+		Self::auctions(id).map(|_| Self::auction_parameters(id).unwrap_or_default())
+	}
+
+	fn winning_bid_at(id: Self::AuctionId, at: T::BlockNumber) -> Option<(T::AccountId, Self::Balance)> {
+		Self::candle_bid_at(id, at, Self::auctions(id)?.bid)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Draw the candle block of a candle auction ending at `end`, among the
+	/// last `candle_period` blocks.
+	fn draw_candle_block(id: T::AuctionId, end: T::BlockNumber, candle_period: T::BlockNumber) -> T::BlockNumber {
+		let (random_seed, _) = T::Randomness::random(&(b"orml-auction/candle", id).encode());
+		let random_number = u32::decode(&mut TrailingZeroInput::new(random_seed.as_ref())).unwrap_or_default();
+		let offset = random_number % candle_period.saturated_into::<u32>().max(1);
+		end.saturating_sub(offset.into())
+	}
+
+	/// Release the held bids of the candle auction `id`, except `kept`.
+	fn release_candle_bids(id: T::AuctionId, kept: Option<&(T::AccountId, T::Balance)>) {
+		for (_, bid) in CandleBids::<T>::drain_prefix(id) {
+			if Some(&bid) != kept {
+				T::Handler::on_losing_bid(id, bid);
+			}
+		}
+	}
+
+	/// The best bid at the end of block `at`, given the current best bid.
+	fn candle_bid_at(
+		id: T::AuctionId,
+		at: T::BlockNumber,
+		current_bid: Option<(T::AccountId, T::Balance)>,
+	) -> Option<(T::AccountId, T::Balance)> {
+		let mut candle_bids = CandleBids::<T>::iter_prefix(id).peekable();
+		if candle_bids.peek().is_none() {
+			// no bid in the candle period
+			return current_bid;
 		}
+		candle_bids
+			.filter(|(block, _)| *block <= at)
+			.max_by_key(|(block, _)| *block)
+			.map(|(_, bid)| bid)
 	}
 }
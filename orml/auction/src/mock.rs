@@ -3,10 +3,14 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{Everything, ReservableCurrency},
+};
 use orml_traits::OnNewBidResult;
 use sp_core::H256;
 use sp_runtime::{testing::Header, traits::IdentityLookup};
+use std::cell::RefCell;
 
 use crate as auction;
 
@@ -35,7 +39,7 @@ impl frame_system::Config for Runtime {
 	type BlockLength = ();
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type DbWeight = ();
@@ -46,6 +50,23 @@ impl frame_system::Config for Runtime {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+/// Escrows the bids by reserving them, and pays the winning bid.
 pub struct Handler;
 
 impl AuctionHandler<AccountId, Balance, BlockNumber, AuctionId> for Handler {
@@ -53,22 +74,50 @@ impl AuctionHandler<AccountId, Balance, BlockNumber, AuctionId> for Handler {
 		now: BlockNumber,
 		_id: AuctionId,
 		new_bid: (AccountId, Balance),
-		_last_bid: Option<(AccountId, Balance)>,
+		last_bid: Option<(AccountId, Balance)>,
 	) -> OnNewBidResult<BlockNumber> {
-		if new_bid.0 == ALICE {
-			OnNewBidResult {
-				accept_bid: true,
-				auction_end_change: Change::NewValue(Some(now + BID_EXTEND_BLOCK)),
-			}
-		} else {
-			OnNewBidResult {
+		if Balances::reserve(&new_bid.0, new_bid.1).is_err() {
+			return OnNewBidResult {
 				accept_bid: false,
 				auction_end_change: Change::NoChange,
-			}
+			};
+		}
+		if let Some((last_bidder, last_amount)) = last_bid {
+			Balances::unreserve(&last_bidder, last_amount);
+		}
+		OnNewBidResult {
+			accept_bid: true,
+			auction_end_change: Change::NewValue(Some(now + BID_EXTEND_BLOCK)),
+		}
+	}
+
+	fn on_auction_ended(_id: AuctionId, winner: Option<(AccountId, Balance)>) {
+		if let Some((winner, amount)) = winner {
+			let _ = Balances::slash_reserved(&winner, amount);
 		}
 	}
 
-	fn on_auction_ended(_id: AuctionId, _winner: Option<(AccountId, Balance)>) {}
+	fn on_losing_bid(_id: AuctionId, (bidder, amount): (AccountId, Balance)) {
+		Balances::unreserve(&bidder, amount);
+	}
+}
+
+thread_local! {
+	static RANDOM_NUMBER: RefCell<u32> = RefCell::new(0);
+}
+
+pub fn set_random_number(number: u32) {
+	RANDOM_NUMBER.with(|v| *v.borrow_mut() = number);
+}
+
+pub struct MockRandomness;
+
+impl Randomness<H256, BlockNumber> for MockRandomness {
+	fn random(_subject: &[u8]) -> (H256, BlockNumber) {
+		let mut seed = H256::zero();
+		seed[..4].copy_from_slice(&RANDOM_NUMBER.with(|v| *v.borrow()).to_le_bytes());
+		(seed, 0)
+	}
 }
 
 impl Config for Runtime {
@@ -76,6 +125,7 @@ impl Config for Runtime {
 	type Balance = Balance;
 	type AuctionId = AuctionId;
 	type Handler = Handler;
+	type Randomness = MockRandomness;
 	type WeightInfo = ();
 }
 
@@ -89,12 +139,14 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		Balances: pallet_balances::{Pallet, Storage, Call, Event<T>, Config<T>},
 		AuctionModule: auction::{Pallet, Storage, Call, Event<T>},
 	}
 );
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 pub const BID_EXTEND_BLOCK: BlockNumber = 10;
 
 pub struct ExtBuilder;
@@ -107,10 +159,17 @@ impl Default for ExtBuilder {
 
 impl ExtBuilder {
 	pub fn build(self) -> sp_io::TestExternalities {
-		let t = frame_system::GenesisConfig::default()
+		let mut t = frame_system::GenesisConfig::default()
 			.build_storage::<Runtime>()
 			.unwrap();
 
+		// BOB can't afford any bid
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, 1_000), (CHARLIE, 1_000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		t.into()
 	}
 }
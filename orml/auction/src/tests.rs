@@ -157,3 +157,254 @@ fn cannot_add_new_auction_when_no_available_id() {
 		);
 	});
 }
+
+#[test]
+fn new_auction_with_params_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let params = AuctionParams {
+			reserve_price: 50,
+			min_increment: 10,
+			candle_period: Some(10),
+		};
+		assert_noop!(
+			AuctionModule::new_auction_with_params(0, None, params.clone()),
+			Error::<Runtime>::InvalidAuctionParams
+		);
+		assert_noop!(
+			AuctionModule::new_auction_with_params(
+				0,
+				Some(100),
+				AuctionParams {
+					candle_period: Some(0),
+					..params.clone()
+				}
+			),
+			Error::<Runtime>::InvalidAuctionParams
+		);
+		assert_noop!(
+			AuctionModule::new_auction_with_params(
+				95,
+				Some(100),
+				AuctionParams {
+					candle_period: Some(10),
+					..params.clone()
+				}
+			),
+			Error::<Runtime>::InvalidAuctionParams
+		);
+
+		assert_ok!(AuctionModule::new_auction_with_params(0, Some(100), params.clone()), 0);
+		assert_eq!(AuctionModule::auction_params(0), Some(params));
+		assert_ok!(AuctionModule::new_auction(0, Some(100)), 1);
+		assert_eq!(AuctionModule::auction_parameters(1), None);
+		assert_eq!(AuctionModule::auction_params(1), Some(Default::default()));
+		assert_eq!(AuctionModule::auction_params(2), None);
+	});
+}
+
+#[test]
+fn reserve_price_and_min_increment_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(
+			AuctionModule::new_auction_with_params(
+				0,
+				Some(100),
+				AuctionParams {
+					reserve_price: 50,
+					min_increment: 10,
+					candle_period: None,
+				}
+			),
+			0
+		);
+
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 49),
+			Error::<Runtime>::BidBelowReservePrice
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 50));
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 50),
+			Error::<Runtime>::InvalidBidPrice
+		);
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 59),
+			Error::<Runtime>::BidIncrementTooLow
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 60));
+		assert_eq!(AuctionModule::auction_info(0).unwrap().bid, Some((ALICE, 60)));
+	});
+}
+
+#[test]
+fn candle_auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(
+			AuctionModule::new_auction_with_params(
+				0,
+				Some(20),
+				AuctionParams {
+					candle_period: Some(10),
+					..Default::default()
+				}
+			),
+			0
+		);
+
+		System::set_block_number(5);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 10));
+		assert_eq!(CandleBids::<Runtime>::iter_prefix(0).count(), 0);
+
+		System::set_block_number(12);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 20));
+		assert_eq!(AuctionModule::candle_bids(0, 11), Some((ALICE, 10)));
+		assert_eq!(AuctionModule::candle_bids(0, 12), Some((ALICE, 20)));
+		// the handler cannot extend a candle auction
+		assert_eq!(AuctionModule::auction_info(0).unwrap().end, Some(20));
+
+		System::set_block_number(15);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 30));
+
+		assert_eq!(AuctionModule::winning_bid_at(0, 11), Some((ALICE, 10)));
+		assert_eq!(AuctionModule::winning_bid_at(0, 14), Some((ALICE, 20)));
+		assert_eq!(AuctionModule::winning_bid_at(0, 20), Some((ALICE, 30)));
+
+		// the held candle bids are accounted for
+		assert_eq!(AuctionModule::on_initialize(20), <() as WeightInfo>::on_finalize(1, 3));
+		assert!(AuctionModule::on_initialize(20) > <() as WeightInfo>::on_finalize(1, 0));
+
+		set_random_number(27);
+		AuctionModule::on_finalize(20);
+		System::assert_last_event(Event::AuctionModule(crate::Event::CandleAuctionEnded {
+			auction_id: 0,
+			candle_block: 13,
+			winner: Some((ALICE, 20)),
+		}));
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::auction_parameters(0), None);
+		assert_eq!(CandleBids::<Runtime>::iter_prefix(0).count(), 0);
+		// only the winning bid is paid
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 980);
+	});
+}
+
+#[test]
+fn candle_auction_should_hold_bids_until_the_end() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(
+			AuctionModule::new_auction_with_params(
+				0,
+				Some(20),
+				AuctionParams {
+					candle_period: Some(10),
+					..Default::default()
+				}
+			),
+			0
+		);
+
+		// a bid before the candle period is refunded when outbid
+		System::set_block_number(5);
+		assert_ok!(AuctionModule::bid(Origin::signed(CHARLIE), 0, 5));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 10));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+
+		// the best bid at the end of a block of the candle period may win, and is held
+		System::set_block_number(12);
+		assert_ok!(AuctionModule::bid(Origin::signed(CHARLIE), 0, 20));
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 20);
+
+		// a bid outbid in the same block can't win, and is refunded
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 25));
+		assert_eq!(Balances::reserved_balance(ALICE), 35);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+
+		System::set_block_number(15);
+		assert_ok!(AuctionModule::bid(Origin::signed(CHARLIE), 0, 30));
+		assert_eq!(Balances::reserved_balance(ALICE), 35);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 30);
+
+		// the last bid loses at the candle block, the losing bids are refunded
+		set_random_number(27);
+		AuctionModule::on_finalize(20);
+		System::assert_last_event(Event::AuctionModule(crate::Event::CandleAuctionEnded {
+			auction_id: 0,
+			candle_block: 13,
+			winner: Some((ALICE, 25)),
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 975);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), 1_000);
+	});
+}
+
+#[test]
+fn candle_auction_winner_without_candle_bids_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let params = AuctionParams {
+			candle_period: Some(10),
+			..Default::default()
+		};
+		assert_ok!(AuctionModule::new_auction_with_params(0, Some(20), params.clone()), 0);
+		assert_ok!(AuctionModule::new_auction_with_params(0, Some(20), params), 1);
+
+		// no bid in the candle period
+		System::set_block_number(5);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 10));
+
+		// no bid before the candle block
+		System::set_block_number(15);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 1, 10));
+
+		set_random_number(7);
+		AuctionModule::on_finalize(20);
+		System::assert_has_event(Event::AuctionModule(crate::Event::CandleAuctionEnded {
+			auction_id: 0,
+			candle_block: 13,
+			winner: Some((ALICE, 10)),
+		}));
+		System::assert_has_event(Event::AuctionModule(crate::Event::CandleAuctionEnded {
+			auction_id: 1,
+			candle_block: 13,
+			winner: None,
+		}));
+		// the bid of the auction 0 is paid, the one of the auction 1 is refunded
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 990);
+	});
+}
+
+#[test]
+fn remove_candle_auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(
+			AuctionModule::new_auction_with_params(
+				0,
+				Some(20),
+				AuctionParams {
+					candle_period: Some(10),
+					..Default::default()
+				}
+			),
+			0
+		);
+		System::set_block_number(15);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 10));
+		System::set_block_number(16);
+		assert_ok!(AuctionModule::bid(Origin::signed(CHARLIE), 0, 20));
+		assert_eq!(CandleBids::<Runtime>::iter_prefix(0).count(), 2);
+
+		AuctionModule::remove_auction(0);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::auction_parameters(0), None);
+		assert_eq!(CandleBids::<Runtime>::iter_prefix(0).count(), 0);
+		// the held bid is released, the current bid is left to the caller
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 20);
+	});
+}
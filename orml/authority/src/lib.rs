@@ -8,9 +8,13 @@
 //!
 //! ## Overview
 //!
-//! Two functionalities are provided by this module:
+//! Three functionalities are provided by this module:
 //! - schedule a dispatchable
 //! - dispatch method with on behalf of other origins
+//! - multi-party approval tracks, where calls are scheduled once enough
+//!   approvers agreed, with a minimum delay depending on the call. The calls
+//!   dispatching other calls, given by `WrapperCalls`, get the longest delay
+//!   of their track
 
 #![cfg_attr(not(feature = "std"), no_std)]
 // Disable the following three lints since they originate from an external macro
@@ -23,9 +27,10 @@ use frame_support::{
 	dispatch::PostDispatchInfo,
 	pallet_prelude::*,
 	traits::{
-		schedule::{DispatchTime, Named as ScheduleNamed, Priority},
-		EnsureOneOf, EnsureOrigin, Get, IsType, OriginTrait,
+		schedule::{DispatchTime, Named as ScheduleNamed, Priority, LOWEST_PRIORITY},
+		CallMetadata, Contains, EnsureOneOf, EnsureOrigin, Get, GetCallMetadata, IsType, OriginTrait,
 	},
+	transactional,
 	weights::{DispatchClass, GetDispatchInfo, Pays},
 };
 use frame_system::{pallet_prelude::*, EnsureRoot, EnsureSigned};
@@ -117,6 +122,86 @@ pub trait AsOriginId<Origin, PalletsOrigin> {
 /// The schedule task index type.
 pub type ScheduleTaskIndex = u32;
 
+/// The approval track id type.
+pub type TrackId = u32;
+
+/// The track proposal index type.
+pub type TrackProposalIndex = u32;
+
+/// The delay of the calls of a pallet, or of a single function of it.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct CallDelay<BlockNumber> {
+	/// The name of the pallet.
+	pub pallet_name: Vec<u8>,
+	/// The name of the function, or `None` for all the functions of the
+	/// pallet.
+	pub function_name: Option<Vec<u8>>,
+	/// The delay of the matching calls.
+	pub delay: BlockNumber,
+}
+
+/// The policy of an approval track.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct TrackPolicy<AccountId, BlockNumber, AsOriginId> {
+	/// The origin the approved calls are dispatched with.
+	pub origin: AsOriginId,
+	/// The accounts that can propose and approve calls.
+	pub approvers: Vec<AccountId>,
+	/// The number of approvals required to schedule a call.
+	pub threshold: u32,
+	/// The delay of the calls not matching any of `call_delays`.
+	pub min_delay: BlockNumber,
+	/// The delays of specific pallets and functions.
+	pub call_delays: Vec<CallDelay<BlockNumber>>,
+}
+
+impl<AccountId: Ord, BlockNumber: Copy, AsOriginId> TrackPolicy<AccountId, BlockNumber, AsOriginId> {
+	/// The delay of a call. A function delay takes precedence over a pallet
+	/// delay, which takes precedence over `min_delay`.
+	pub fn delay_of(&self, metadata: &CallMetadata) -> BlockNumber {
+		let matching = |call_delay: &&CallDelay<BlockNumber>, function: bool| {
+			call_delay.pallet_name == metadata.pallet_name.as_bytes()
+				&& match &call_delay.function_name {
+					Some(name) => function && name == metadata.function_name.as_bytes(),
+					None => !function,
+				}
+		};
+		self.call_delays
+			.iter()
+			.find(|call_delay| matching(call_delay, true))
+			.or_else(|| self.call_delays.iter().find(|call_delay| matching(call_delay, false)))
+			.map_or(self.min_delay, |call_delay| call_delay.delay)
+	}
+
+	/// The longest delay of the track, applied to the calls dispatching other
+	/// calls.
+	pub fn max_delay(&self) -> BlockNumber
+	where
+		BlockNumber: Ord,
+	{
+		self.call_delays
+			.iter()
+			.map(|call_delay| call_delay.delay)
+			.fold(self.min_delay, sp_std::cmp::max)
+	}
+
+	fn is_valid(&self) -> bool {
+		let mut approvers: Vec<&AccountId> = self.approvers.iter().collect();
+		approvers.sort();
+		approvers.dedup();
+		approvers.len() == self.approvers.len() && self.threshold > 0 && self.threshold as usize <= self.approvers.len()
+	}
+}
+
+/// A call proposed on an approval track.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct TrackProposal<AccountId, Call> {
+	/// The proposed call.
+	pub call: Call,
+	/// The approvers who approved the call.
+	pub approvals: Vec<AccountId>,
+}
+
 pub use module::*;
 
 #[frame_support::pallet]
@@ -127,6 +212,12 @@ pub mod module {
 	#[pallet::origin]
 	pub type Origin<T> = DelayedOrigin<<T as frame_system::Config>::BlockNumber, <T as Config>::PalletsOrigin>;
 	pub(crate) type CallOf<T> = <T as Config>::Call;
+	pub(crate) type TrackPolicyOf<T> = TrackPolicy<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::AsOriginId,
+	>;
+	pub(crate) type TrackProposalOf<T> = TrackProposal<<T as frame_system::Config>::AccountId, CallOf<T>>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -144,7 +235,8 @@ pub mod module {
 		/// The aggregated call type.
 		type Call: Parameter
 			+ Dispatchable<Origin = <Self as frame_system::Config>::Origin, PostInfo = PostDispatchInfo>
-			+ GetDispatchInfo;
+			+ GetDispatchInfo
+			+ GetCallMetadata;
 
 		/// The Scheduler.
 		type Scheduler: ScheduleNamed<Self::BlockNumber, <Self as Config>::Call, Self::PalletsOrigin>;
@@ -159,6 +251,14 @@ pub mod module {
 			Self::BlockNumber,
 		>;
 
+		/// The origin which may veto the calls of the approval tracks.
+		type VetoOrigin: EnsureOrigin<<Self as frame_system::Config>::Origin>;
+
+		/// The calls dispatching other calls, like batches or `dispatch_as`.
+		/// They are delayed by the longest delay of their approval track, as
+		/// their inner calls can't be inspected.
+		type WrapperCalls: Contains<<Self as Config>::Call>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -179,6 +279,18 @@ pub mod module {
 		TriggerCallNotPermitted,
 		/// Call weight bound is wrong.
 		WrongCallWeightBound,
+		/// The track does not exist.
+		TrackNotFound,
+		/// The track policy is invalid.
+		InvalidTrackPolicy,
+		/// The account is not an approver of the track.
+		NotTrackApprover,
+		/// The track proposal does not exist.
+		TrackProposalNotFound,
+		/// The call was already approved by the account.
+		AlreadyApproved,
+		/// The track has proposals waiting for approvals.
+		TrackHasProposals,
 	}
 
 	#[pallet::event]
@@ -217,6 +329,35 @@ pub mod module {
 		RemovedAuthorizedCall { hash: T::Hash },
 		/// An authorized call was triggered.
 		TriggeredCallBy { hash: T::Hash, caller: T::AccountId },
+		/// The policy of a track was set.
+		TrackSet { track_id: TrackId },
+		/// A track was removed.
+		TrackRemoved { track_id: TrackId },
+		/// A call was proposed on a track.
+		TrackDispatchProposed {
+			track_id: TrackId,
+			index: TrackProposalIndex,
+			proposer: T::AccountId,
+			call_hash: T::Hash,
+		},
+		/// A call proposed on a track was approved.
+		TrackDispatchApproved {
+			track_id: TrackId,
+			index: TrackProposalIndex,
+			approver: T::AccountId,
+			approvals: u32,
+		},
+		/// A call reached the approval threshold of its track and was scheduled.
+		TrackDispatchScheduled {
+			track_id: TrackId,
+			index: TrackProposalIndex,
+			delay: T::BlockNumber,
+		},
+		/// A call proposed on a track was vetoed.
+		TrackDispatchVetoed {
+			track_id: TrackId,
+			index: TrackProposalIndex,
+		},
 	}
 
 	#[pallet::storage]
@@ -227,6 +368,21 @@ pub mod module {
 	#[pallet::getter(fn saved_calls)]
 	pub type SavedCalls<T: Config> = StorageMap<_, Identity, T::Hash, (CallOf<T>, Option<T::AccountId>), OptionQuery>;
 
+	/// The policies of the approval tracks.
+	#[pallet::storage]
+	#[pallet::getter(fn tracks)]
+	pub type Tracks<T: Config> = StorageMap<_, Twox64Concat, TrackId, TrackPolicyOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_track_proposal_index)]
+	pub type NextTrackProposalIndex<T: Config> = StorageValue<_, TrackProposalIndex, ValueQuery>;
+
+	/// The calls proposed on the approval tracks, waiting for approvals.
+	#[pallet::storage]
+	#[pallet::getter(fn track_proposals)]
+	pub type TrackProposals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, TrackId, Twox64Concat, TrackProposalIndex, TrackProposalOf<T>, OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	#[pallet::without_storage_info]
@@ -438,5 +594,156 @@ pub mod module {
 				Ok(Pays::No.into())
 			})
 		}
+
+		/// Set the policy of an approval track, or remove the track if
+		/// `policy` is `None`. A track can only be removed once its proposals
+		/// waiting for approvals are vetoed.
+		#[pallet::weight(T::WeightInfo::set_track())]
+		pub fn set_track(origin: OriginFor<T>, track_id: TrackId, policy: Option<TrackPolicyOf<T>>) -> DispatchResult {
+			ensure_root(origin)?;
+			match policy {
+				Some(policy) => {
+					ensure!(policy.is_valid(), Error::<T>::InvalidTrackPolicy);
+					Tracks::<T>::insert(track_id, policy);
+					Self::deposit_event(Event::TrackSet { track_id });
+				}
+				None => {
+					ensure!(Tracks::<T>::contains_key(track_id), Error::<T>::TrackNotFound);
+					ensure!(
+						TrackProposals::<T>::iter_prefix(track_id).next().is_none(),
+						Error::<T>::TrackHasProposals
+					);
+					Tracks::<T>::remove(track_id);
+					Self::deposit_event(Event::TrackRemoved { track_id });
+				}
+			}
+			Ok(())
+		}
+
+		/// Propose a call on an approval track, approving it. The call is
+		/// scheduled once it has the approvals required by the track.
+		#[pallet::weight(T::WeightInfo::propose_track_dispatch())]
+		#[transactional]
+		pub fn propose_track_dispatch(origin: OriginFor<T>, track_id: TrackId, call: Box<CallOf<T>>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let policy = Tracks::<T>::get(track_id).ok_or(Error::<T>::TrackNotFound)?;
+			ensure!(policy.approvers.contains(&who), Error::<T>::NotTrackApprover);
+
+			let index = NextTrackProposalIndex::<T>::try_mutate(
+				|index| -> sp_std::result::Result<TrackProposalIndex, DispatchError> {
+					let current_index = *index;
+					*index = index.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+					Ok(current_index)
+				},
+			)?;
+
+			Self::deposit_event(Event::TrackDispatchProposed {
+				track_id,
+				index,
+				proposer: who.clone(),
+				call_hash: T::Hashing::hash_of(&call),
+			});
+
+			let proposal = TrackProposal {
+				call: *call,
+				approvals: sp_std::vec![who],
+			};
+			if let Some(proposal) = Self::try_schedule_track_dispatch(track_id, index, &policy, proposal)? {
+				TrackProposals::<T>::insert(track_id, index, proposal);
+			}
+			Ok(())
+		}
+
+		/// Approve a call proposed on an approval track. The call is scheduled
+		/// once it has the approvals required by the track.
+		#[pallet::weight(T::WeightInfo::approve_track_dispatch())]
+		#[transactional]
+		pub fn approve_track_dispatch(
+			origin: OriginFor<T>,
+			track_id: TrackId,
+			index: TrackProposalIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let policy = Tracks::<T>::get(track_id).ok_or(Error::<T>::TrackNotFound)?;
+			ensure!(policy.approvers.contains(&who), Error::<T>::NotTrackApprover);
+
+			TrackProposals::<T>::try_mutate_exists(track_id, index, |maybe_proposal| -> DispatchResult {
+				let mut proposal = maybe_proposal.take().ok_or(Error::<T>::TrackProposalNotFound)?;
+				ensure!(!proposal.approvals.contains(&who), Error::<T>::AlreadyApproved);
+				proposal.approvals.push(who.clone());
+
+				Self::deposit_event(Event::TrackDispatchApproved {
+					track_id,
+					index,
+					approver: who,
+					approvals: proposal.approvals.len() as u32,
+				});
+
+				*maybe_proposal = Self::try_schedule_track_dispatch(track_id, index, &policy, proposal)?;
+				Ok(())
+			})
+		}
+
+		/// Veto a call proposed on an approval track, whether it is still
+		/// waiting for approvals or already scheduled.
+		#[pallet::weight(T::WeightInfo::veto_track_dispatch())]
+		pub fn veto_track_dispatch(
+			origin: OriginFor<T>,
+			track_id: TrackId,
+			index: TrackProposalIndex,
+		) -> DispatchResult {
+			T::VetoOrigin::ensure_origin(origin)?;
+
+			if TrackProposals::<T>::take(track_id, index).is_none() {
+				T::Scheduler::cancel_named(Self::track_task_name(track_id, index))
+					.map_err(|_| Error::<T>::TrackProposalNotFound)?;
+			}
+
+			Self::deposit_event(Event::TrackDispatchVetoed { track_id, index });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn track_task_name(track_id: TrackId, index: TrackProposalIndex) -> Vec<u8> {
+		(b"orml-authority/track", track_id, index).encode()
+	}
+
+	/// Schedule the proposed call if it has the approvals required by the
+	/// track. Returns the proposal if it is still waiting for approvals.
+	fn try_schedule_track_dispatch(
+		track_id: TrackId,
+		index: TrackProposalIndex,
+		policy: &TrackPolicyOf<T>,
+		proposal: TrackProposalOf<T>,
+	) -> Result<Option<TrackProposalOf<T>>, DispatchError> {
+		// only count the approvals of the current approvers
+		let approvals = proposal
+			.approvals
+			.iter()
+			.filter(|approver| policy.approvers.contains(approver))
+			.count();
+		if approvals < policy.threshold as usize {
+			return Ok(Some(proposal));
+		}
+
+		let delay = if T::WrapperCalls::contains(&proposal.call) {
+			policy.max_delay()
+		} else {
+			policy.delay_of(&proposal.call.get_call_metadata())
+		};
+		T::Scheduler::schedule_named(
+			Self::track_task_name(track_id, index),
+			DispatchTime::After(delay),
+			None,
+			LOWEST_PRIORITY,
+			policy.origin.clone().into_origin(),
+			proposal.call,
+		)
+		.map_err(|_| Error::<T>::FailedToSchedule)?;
+
+		Self::deposit_event(Event::TrackDispatchScheduled { track_id, index, delay });
+		Ok(None)
 	}
 }
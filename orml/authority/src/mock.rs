@@ -5,11 +5,11 @@
 use super::*;
 use codec::{Decode, Encode};
 use frame_support::{
-	parameter_types,
-	traits::{EqualPrivilegeOnly, Everything},
+	ord_parameter_types, parameter_types,
+	traits::{Contains, EqualPrivilegeOnly, Everything},
 	weights::Weight,
 };
-use frame_system::{ensure_root, ensure_signed, EnsureRoot};
+use frame_system::{ensure_root, ensure_signed, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -153,6 +153,22 @@ impl AsOriginId<Origin, OriginCaller> for MockAsOriginId {
 	}
 }
 
+ord_parameter_types! {
+	pub const Veto: AccountId = 4;
+}
+
+pub struct WrapperCalls;
+impl Contains<Call> for WrapperCalls {
+	fn contains(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::Authority(authority::Call::dispatch_as { .. })
+				| Call::Authority(authority::Call::schedule_dispatch { .. })
+				| Call::Authority(authority::Call::trigger_call { .. })
+		)
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type Origin = Origin;
@@ -161,6 +177,8 @@ impl Config for Runtime {
 	type Call = Call;
 	type AsOriginId = MockAsOriginId;
 	type AuthorityConfig = AuthorityConfigImpl;
+	type VetoOrigin = EnsureSignedBy<Veto, AccountId>;
+	type WrapperCalls = WrapperCalls;
 	type WeightInfo = ();
 }
 
@@ -570,3 +570,338 @@ fn trigger_call_should_be_free_and_operational() {
 		);
 	});
 }
+
+fn track_policy() -> TrackPolicy<u128, BlockNumber, MockAsOriginId> {
+	TrackPolicy {
+		origin: MockAsOriginId::Root,
+		approvers: vec![1, 2, 3],
+		threshold: 2,
+		min_delay: 10,
+		call_delays: vec![
+			CallDelay {
+				pallet_name: b"System".to_vec(),
+				function_name: None,
+				delay: 5,
+			},
+			CallDelay {
+				pallet_name: b"System".to_vec(),
+				function_name: Some(b"remark".to_vec()),
+				delay: 2,
+			},
+		],
+	}
+}
+
+#[test]
+fn set_track_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+		assert_noop!(
+			Authority::set_track(Origin::signed(1), 0, Some(track_policy())),
+			BadOrigin
+		);
+		assert_noop!(
+			Authority::set_track(
+				Origin::root(),
+				0,
+				Some(TrackPolicy {
+					threshold: 0,
+					..track_policy()
+				})
+			),
+			Error::<Runtime>::InvalidTrackPolicy
+		);
+		assert_noop!(
+			Authority::set_track(
+				Origin::root(),
+				0,
+				Some(TrackPolicy {
+					threshold: 4,
+					..track_policy()
+				})
+			),
+			Error::<Runtime>::InvalidTrackPolicy
+		);
+		assert_noop!(
+			Authority::set_track(
+				Origin::root(),
+				0,
+				Some(TrackPolicy {
+					approvers: vec![1, 2, 1],
+					..track_policy()
+				})
+			),
+			Error::<Runtime>::InvalidTrackPolicy
+		);
+		assert_noop!(
+			Authority::set_track(Origin::root(), 0, None),
+			Error::<Runtime>::TrackNotFound
+		);
+
+		assert_ok!(Authority::set_track(Origin::root(), 0, Some(track_policy())));
+		assert_eq!(Authority::tracks(0), Some(track_policy()));
+		System::assert_last_event(mock::Event::Authority(Event::TrackSet { track_id: 0 }));
+
+		// the proposals waiting for approvals must be vetoed first
+		assert_ok!(Authority::propose_track_dispatch(
+			Origin::signed(1),
+			0,
+			Box::new(Call::System(frame_system::Call::remark { remark: vec![] }))
+		));
+		assert_noop!(
+			Authority::set_track(Origin::root(), 0, None),
+			Error::<Runtime>::TrackHasProposals
+		);
+		assert_ok!(Authority::veto_track_dispatch(Origin::signed(4), 0, 0));
+
+		assert_ok!(Authority::set_track(Origin::root(), 0, None));
+		assert_eq!(Authority::tracks(0), None);
+		System::assert_last_event(mock::Event::Authority(Event::TrackRemoved { track_id: 0 }));
+	});
+}
+
+#[test]
+fn track_call_delays_work() {
+	let policy = track_policy();
+	let remark = Call::System(frame_system::Call::remark { remark: vec![] });
+	let fill_block = Call::System(frame_system::Call::fill_block { ratio: Perbill::one() });
+	let authorize_call = Call::Authority(authority::Call::authorize_call {
+		call: Box::new(remark.clone()),
+		caller: None,
+	});
+	assert_eq!(policy.delay_of(&remark.get_call_metadata()), 2);
+	assert_eq!(policy.delay_of(&fill_block.get_call_metadata()), 5);
+	assert_eq!(policy.delay_of(&authorize_call.get_call_metadata()), 10);
+	assert_eq!(policy.max_delay(), 10);
+	assert_eq!(
+		TrackPolicy {
+			min_delay: 1,
+			..track_policy()
+		}
+		.max_delay(),
+		5
+	);
+}
+
+#[test]
+fn track_dispatch_of_wrapper_call_gets_max_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+		let call = Call::Authority(authority::Call::dispatch_as {
+			as_origin: MockAsOriginId::Root,
+			call: Box::new(Call::System(frame_system::Call::remark { remark: vec![] })),
+		});
+		assert_ok!(Authority::set_track(
+			Origin::root(),
+			0,
+			Some(TrackPolicy {
+				threshold: 1,
+				min_delay: 1,
+				..track_policy()
+			})
+		));
+
+		// the wrapped remark would be delayed by 2 blocks
+		assert_ok!(Authority::propose_track_dispatch(Origin::signed(1), 0, Box::new(call)));
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchScheduled {
+			track_id: 0,
+			index: 0,
+			delay: 5,
+		}));
+	});
+}
+
+#[test]
+fn track_dispatch_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+		let call = Call::System(frame_system::Call::fill_block { ratio: Perbill::one() });
+		let call_hash = <Runtime as frame_system::Config>::Hashing::hash_of(&call);
+
+		assert_noop!(
+			Authority::propose_track_dispatch(Origin::signed(1), 0, Box::new(call.clone())),
+			Error::<Runtime>::TrackNotFound
+		);
+		assert_ok!(Authority::set_track(Origin::root(), 0, Some(track_policy())));
+		assert_noop!(
+			Authority::propose_track_dispatch(Origin::signed(4), 0, Box::new(call.clone())),
+			Error::<Runtime>::NotTrackApprover
+		);
+
+		assert_ok!(Authority::propose_track_dispatch(
+			Origin::signed(1),
+			0,
+			Box::new(call.clone())
+		));
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchProposed {
+			track_id: 0,
+			index: 0,
+			proposer: 1,
+			call_hash,
+		}));
+		assert_eq!(
+			Authority::track_proposals(0, 0),
+			Some(TrackProposal {
+				call: call.clone(),
+				approvals: vec![1],
+			})
+		);
+
+		assert_noop!(
+			Authority::approve_track_dispatch(Origin::signed(4), 0, 0),
+			Error::<Runtime>::NotTrackApprover
+		);
+		assert_noop!(
+			Authority::approve_track_dispatch(Origin::signed(1), 0, 0),
+			Error::<Runtime>::AlreadyApproved
+		);
+		assert_noop!(
+			Authority::approve_track_dispatch(Origin::signed(2), 0, 1),
+			Error::<Runtime>::TrackProposalNotFound
+		);
+
+		assert_ok!(Authority::approve_track_dispatch(Origin::signed(2), 0, 0));
+		System::assert_has_event(mock::Event::Authority(Event::TrackDispatchApproved {
+			track_id: 0,
+			index: 0,
+			approver: 2,
+			approvals: 2,
+		}));
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchScheduled {
+			track_id: 0,
+			index: 0,
+			delay: 5,
+		}));
+		assert_eq!(Authority::track_proposals(0, 0), None);
+
+		run_to_block(6);
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchScheduled {
+			track_id: 0,
+			index: 0,
+			delay: 5,
+		}));
+
+		run_to_block(7);
+		System::assert_last_event(mock::Event::Scheduler(pallet_scheduler::Event::<Runtime>::Dispatched {
+			task: (7, 0),
+			id: Some(Authority::track_task_name(0, 0)),
+			result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn track_dispatch_with_single_approval_is_scheduled_on_proposal() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+		let call = Call::System(frame_system::Call::remark { remark: vec![] });
+		assert_ok!(Authority::set_track(
+			Origin::root(),
+			1,
+			Some(TrackPolicy {
+				threshold: 1,
+				..track_policy()
+			})
+		));
+
+		assert_ok!(Authority::propose_track_dispatch(Origin::signed(3), 1, Box::new(call)));
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchScheduled {
+			track_id: 1,
+			index: 0,
+			delay: 2,
+		}));
+		assert_eq!(Authority::track_proposals(1, 0), None);
+
+		run_to_block(4);
+		System::assert_last_event(mock::Event::Scheduler(pallet_scheduler::Event::<Runtime>::Dispatched {
+			task: (4, 0),
+			id: Some(Authority::track_task_name(1, 0)),
+			result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn track_dispatch_only_counts_current_approvers() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+		let call = Call::System(frame_system::Call::remark { remark: vec![] });
+		assert_ok!(Authority::set_track(Origin::root(), 0, Some(track_policy())));
+		assert_ok!(Authority::propose_track_dispatch(
+			Origin::signed(1),
+			0,
+			Box::new(call.clone())
+		));
+
+		assert_ok!(Authority::set_track(
+			Origin::root(),
+			0,
+			Some(TrackPolicy {
+				approvers: vec![2, 3],
+				..track_policy()
+			})
+		));
+		assert_ok!(Authority::approve_track_dispatch(Origin::signed(2), 0, 0));
+		assert_eq!(
+			Authority::track_proposals(0, 0),
+			Some(TrackProposal {
+				call,
+				approvals: vec![1, 2],
+			})
+		);
+
+		assert_ok!(Authority::approve_track_dispatch(Origin::signed(3), 0, 0));
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchScheduled {
+			track_id: 0,
+			index: 0,
+			delay: 2,
+		}));
+	});
+}
+
+#[test]
+fn veto_track_dispatch_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+		let call = Call::System(frame_system::Call::fill_block { ratio: Perbill::one() });
+		assert_ok!(Authority::set_track(Origin::root(), 0, Some(track_policy())));
+
+		// veto a proposal waiting for approvals
+		assert_ok!(Authority::propose_track_dispatch(
+			Origin::signed(1),
+			0,
+			Box::new(call.clone())
+		));
+		assert_noop!(Authority::veto_track_dispatch(Origin::signed(1), 0, 0), BadOrigin);
+		assert_ok!(Authority::veto_track_dispatch(Origin::signed(4), 0, 0));
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchVetoed {
+			track_id: 0,
+			index: 0,
+		}));
+		assert_eq!(Authority::track_proposals(0, 0), None);
+		assert_noop!(
+			Authority::approve_track_dispatch(Origin::signed(2), 0, 0),
+			Error::<Runtime>::TrackProposalNotFound
+		);
+
+		// veto a scheduled proposal
+		assert_ok!(Authority::propose_track_dispatch(Origin::signed(1), 0, Box::new(call)));
+		assert_ok!(Authority::approve_track_dispatch(Origin::signed(2), 0, 1));
+		assert_ok!(Authority::veto_track_dispatch(Origin::signed(4), 0, 1));
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchVetoed {
+			track_id: 0,
+			index: 1,
+		}));
+
+		run_to_block(7);
+		System::assert_last_event(mock::Event::Authority(Event::TrackDispatchVetoed {
+			track_id: 0,
+			index: 1,
+		}));
+
+		assert_noop!(
+			Authority::veto_track_dispatch(Origin::signed(4), 0, 1),
+			Error::<Runtime>::TrackProposalNotFound
+		);
+	});
+}
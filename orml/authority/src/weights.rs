@@ -38,6 +38,10 @@ pub trait WeightInfo {
 	fn authorize_call() -> Weight;
 	fn remove_authorized_call() -> Weight;
 	fn trigger_call() -> Weight;
+	fn set_track() -> Weight;
+	fn propose_track_dispatch() -> Weight;
+	fn approve_track_dispatch() -> Weight;
+	fn veto_track_dispatch() -> Weight;
 }
 
 /// Default weights.
@@ -84,4 +88,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_track() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn propose_track_dispatch() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn approve_track_dispatch() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn veto_track_dispatch() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
@@ -17,6 +17,21 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(1 as Weight))
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
+	fn schedule_curve_update() -> Weight {
+		(60_115_000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn pause_gradually_update() -> Weight {
+		(31_402_000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn resume_gradually_update() -> Weight {
+		(35_270_000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
 	fn on_finalize(u: u32) -> Weight {
 		(37_067_000 as Weight)
 			.saturating_add((20_890_000 as Weight).saturating_mul(u as Weight))
@@ -15,6 +15,11 @@
 //! NOTE: Only unsigned integer value up to 128 bits are supported. But a
 //! "newtype" pattern struct that wraps an unsigned integer works too such as
 //! `Permill` and `FixedU128`.
+//!
+//! Typed `FixedU128`, `Permill` and `Balance` values can also be moved along a
+//! linear, exponential or ease-in/ease-out curve which reaches the target value
+//! at a given block, see `schedule_curve_update`. Any update can be paused and
+//! resumed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 // Disable the following two lints since they originate from an external macro (namely decl_storage)
@@ -31,7 +36,10 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
-use sp_runtime::{traits::SaturatedConversion, DispatchResult, RuntimeDebug};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating, Zero},
+	DispatchResult, FixedPointNumber, FixedU128, Permill, RuntimeDebug,
+};
 
 mod default_weight;
 mod mock;
@@ -47,6 +55,138 @@ pub struct GraduallyUpdate<Key, Value> {
 	pub per_block: Value,
 }
 
+/// A typed value which can be updated along a curve.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum UpdateValue {
+	FixedU128(FixedU128),
+	Permill(Permill),
+	Balance(u128),
+}
+
+impl UpdateValue {
+	/// The inner integer representation of the value.
+	fn into_inner(self) -> u128 {
+		match self {
+			UpdateValue::FixedU128(value) => value.into_inner(),
+			UpdateValue::Permill(value) => value.deconstruct().into(),
+			UpdateValue::Balance(value) => value,
+		}
+	}
+
+	/// A value of the same type from its inner integer representation.
+	fn with_inner(self, inner: u128) -> Self {
+		match self {
+			UpdateValue::FixedU128(_) => UpdateValue::FixedU128(FixedU128::from_inner(inner)),
+			UpdateValue::Permill(_) => UpdateValue::Permill(Permill::from_parts(inner.saturated_into())),
+			UpdateValue::Balance(_) => UpdateValue::Balance(inner),
+		}
+	}
+
+	/// Read the value of the same type stored at `key`. Returns zero if there
+	/// is no value, and `None` if the stored value is not of this type.
+	fn read(self, key: &[u8]) -> Option<Self> {
+		let raw = match storage::unhashed::get_raw(key) {
+			Some(raw) => raw,
+			None => return Some(self.with_inner(Zero::zero())),
+		};
+		let input = &mut &raw[..];
+		let value = match self {
+			UpdateValue::FixedU128(_) => FixedU128::decode(input).map(UpdateValue::FixedU128),
+			UpdateValue::Permill(_) => Permill::decode(input).map(UpdateValue::Permill),
+			UpdateValue::Balance(_) => u128::decode(input).map(UpdateValue::Balance),
+		}
+		.ok()?;
+		if input.is_empty() {
+			Some(value)
+		} else {
+			None
+		}
+	}
+
+	/// Store the value at `key`, encoded as its own type.
+	fn write(self, key: &[u8]) {
+		match self {
+			UpdateValue::FixedU128(value) => storage::unhashed::put(key, &value),
+			UpdateValue::Permill(value) => storage::unhashed::put(key, &value),
+			UpdateValue::Balance(value) => storage::unhashed::put(key, &value),
+		}
+	}
+}
+
+/// The shape of a curve update.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum Curve {
+	/// Move by the same amount over every block.
+	Linear,
+	/// Start slowly and speed up, doubling the speed every eighth of the
+	/// duration. Interpolated linearly between the doublings.
+	Exponential,
+	/// Start and finish slowly, fastest halfway through (smoothstep).
+	EaseInOut,
+}
+
+impl Curve {
+	/// The fraction of the total change reached at `progress`, both between
+	/// zero and one.
+	fn fraction(&self, progress: FixedU128) -> FixedU128 {
+		let one = FixedU128::one();
+		let progress = progress.min(one);
+		let fraction = match self {
+			Curve::Linear => progress,
+			Curve::Exponential => {
+				// (2^(8p) - 1) / (2^8 - 1)
+				let x = progress.saturating_mul(FixedU128::saturating_from_integer(8u128));
+				let octave = x.into_inner() / FixedU128::accuracy();
+				let power =
+					FixedU128::saturating_from_integer(1u128 << octave).saturating_mul(one.saturating_add(x.frac()));
+				FixedU128::saturating_from_rational(power.saturating_sub(one).into_inner(), 255 * FixedU128::accuracy())
+			}
+			Curve::EaseInOut => {
+				// 3p^2 - 2p^3
+				let three_minus_two_p = FixedU128::saturating_from_integer(3u128)
+					.saturating_sub(progress.saturating_mul(FixedU128::saturating_from_integer(2u128)));
+				progress.saturating_mul(progress).saturating_mul(three_minus_two_p)
+			}
+		};
+		fraction.min(one)
+	}
+}
+
+/// Update a typed value stored at `key` from `start_value` at `start_at` to
+/// `target_value` at `end_at`, following `curve`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct CurveUpdate<Key, BlockNumber> {
+	pub key: Key,
+	pub start_value: UpdateValue,
+	pub target_value: UpdateValue,
+	pub start_at: BlockNumber,
+	pub end_at: BlockNumber,
+	pub curve: Curve,
+}
+
+impl<Key, BlockNumber: AtLeast32BitUnsigned + Copy> CurveUpdate<Key, BlockNumber> {
+	/// The value on the curve at `now`.
+	fn value_at(&self, now: BlockNumber) -> UpdateValue {
+		if now >= self.end_at {
+			return self.target_value;
+		}
+		let elapsed = now.saturating_sub(self.start_at);
+		let duration = self.end_at.saturating_sub(self.start_at);
+		let progress =
+			FixedU128::saturating_from_rational(elapsed.saturated_into::<u128>(), duration.saturated_into::<u128>());
+		let fraction = self.curve.fraction(progress);
+
+		let start = self.start_value.into_inner();
+		let target = self.target_value.into_inner();
+		let value = if target >= start {
+			start.saturating_add(fraction.saturating_mul_int(target - start))
+		} else {
+			start.saturating_sub(fraction.saturating_mul_int(start - target))
+		};
+		self.target_value.with_inner(value)
+	}
+}
+
 pub use module::*;
 
 #[frame_support::pallet]
@@ -56,6 +196,9 @@ pub mod module {
 	pub trait WeightInfo {
 		fn gradually_update() -> Weight;
 		fn cancel_gradually_update() -> Weight;
+		fn schedule_curve_update() -> Weight;
+		fn pause_gradually_update() -> Weight;
+		fn resume_gradually_update() -> Weight;
 		fn on_finalize(u: u32) -> Weight;
 	}
 
@@ -63,6 +206,7 @@ pub mod module {
 	pub(crate) type StorageValueBytes<T> = BoundedVec<u8, <T as Config>::MaxStorageValueBytes>;
 
 	type GraduallyUpdateOf<T> = GraduallyUpdate<StorageKeyBytes<T>, StorageValueBytes<T>>;
+	pub(crate) type CurveUpdateOf<T> = CurveUpdate<StorageKeyBytes<T>, <T as frame_system::Config>::BlockNumber>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -104,6 +248,12 @@ pub mod module {
 		MaxStorageKeyBytesExceeded,
 		/// Maximum value size exceeded
 		MaxStorageValueBytesExceeded,
+		/// The end block of a curve update is not in the future.
+		InvalidEndBlock,
+		/// The update is already paused.
+		GraduallyUpdateAlreadyPaused,
+		/// The update is not paused.
+		GraduallyUpdateNotPaused,
 	}
 
 	#[pallet::event]
@@ -121,8 +271,28 @@ pub mod module {
 		Updated {
 			block_number: T::BlockNumber,
 			key: StorageKeyBytes<T>,
-			target_value: StorageValueBytes<T>,
+			old_value: StorageValueBytes<T>,
+			new_value: StorageValueBytes<T>,
+		},
+		/// Curve update added.
+		CurveUpdateAdded {
+			key: StorageKeyBytes<T>,
+			start_value: UpdateValue,
+			target_value: UpdateValue,
+			end_at: T::BlockNumber,
+			curve: Curve,
 		},
+		/// Curve update applied.
+		CurveUpdated {
+			block_number: T::BlockNumber,
+			key: StorageKeyBytes<T>,
+			old_value: UpdateValue,
+			new_value: UpdateValue,
+		},
+		/// Gradually update paused.
+		GraduallyUpdatePaused { key: StorageKeyBytes<T> },
+		/// Gradually update resumed.
+		GraduallyUpdateResumed { key: StorageKeyBytes<T> },
 	}
 
 	/// All the on-going updates
@@ -131,6 +301,20 @@ pub mod module {
 	pub(crate) type GraduallyUpdates<T: Config> =
 		StorageValue<_, BoundedVec<GraduallyUpdateOf<T>, T::MaxGraduallyUpdate>, ValueQuery>;
 
+	/// All the on-going curve updates
+	#[pallet::storage]
+	#[pallet::getter(fn curve_updates)]
+	pub(crate) type CurveUpdates<T: Config> =
+		StorageValue<_, BoundedVec<CurveUpdateOf<T>, T::MaxGraduallyUpdate>, ValueQuery>;
+
+	/// The paused updates and the block number they were paused at.
+	///
+	/// PausedUpdates: map StorageKeyBytes => BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn paused_updates)]
+	pub(crate) type PausedUpdates<T: Config> =
+		StorageMap<_, Blake2_128Concat, StorageKeyBytes<T>, T::BlockNumber, OptionQuery>;
+
 	/// The last updated block number
 	#[pallet::storage]
 	#[pallet::getter(fn last_updated_at)]
@@ -144,7 +328,11 @@ pub mod module {
 		/// `on_initialize` to return the weight used in `on_finalize`.
 		fn on_initialize(now: T::BlockNumber) -> Weight {
 			if Self::_need_update(now) {
-				T::WeightInfo::on_finalize(GraduallyUpdates::<T>::get().len() as u32)
+				T::WeightInfo::on_finalize(
+					GraduallyUpdates::<T>::decode_len()
+						.unwrap_or_default()
+						.saturating_add(CurveUpdates::<T>::decode_len().unwrap_or_default()) as u32,
+				)
 			} else {
 				0
 			}
@@ -177,12 +365,9 @@ pub mod module {
 				);
 			}
 
-			GraduallyUpdates::<T>::try_mutate(|gradually_updates| -> DispatchResult {
-				ensure!(
-					!gradually_updates.contains(&update),
-					Error::<T>::GraduallyUpdateHasExisted
-				);
+			ensure!(!Self::update_exists(&update.key), Error::<T>::GraduallyUpdateHasExisted);
 
+			GraduallyUpdates::<T>::try_mutate(|gradually_updates| -> DispatchResult {
 				gradually_updates
 					.try_push(update.clone())
 					.map_err(|_| Error::<T>::MaxGraduallyUpdateExceeded)?;
@@ -203,18 +388,101 @@ pub mod module {
 		pub fn cancel_gradually_update(origin: OriginFor<T>, key: StorageKeyBytes<T>) -> DispatchResult {
 			T::DispatchOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
 
-			GraduallyUpdates::<T>::try_mutate(|gradually_updates| -> DispatchResult {
-				let old_len = gradually_updates.len();
-				gradually_updates.retain(|item| item.key != key);
+			let mut gradually_updates = GraduallyUpdates::<T>::get();
+			let mut curve_updates = CurveUpdates::<T>::get();
+			let old_len = gradually_updates.len() + curve_updates.len();
+			gradually_updates.retain(|item| item.key != key);
+			curve_updates.retain(|item| item.key != key);
 
-				ensure!(gradually_updates.len() != old_len, Error::<T>::GraduallyUpdateNotFound);
+			ensure!(
+				gradually_updates.len() + curve_updates.len() != old_len,
+				Error::<T>::GraduallyUpdateNotFound
+			);
 
-				Ok(())
-			})?;
+			GraduallyUpdates::<T>::put(gradually_updates);
+			CurveUpdates::<T>::put(curve_updates);
+			PausedUpdates::<T>::remove(&key);
 
 			Self::deposit_event(Event::GraduallyUpdateCancelled { key });
 			Ok(())
 		}
+
+		/// Schedule an update of the typed value stored at `key` to
+		/// `target_value`, following `curve` from the current value now to the
+		/// target value at `end_at`.
+		#[pallet::weight(T::WeightInfo::schedule_curve_update())]
+		pub fn schedule_curve_update(
+			origin: OriginFor<T>,
+			key: StorageKeyBytes<T>,
+			target_value: UpdateValue,
+			end_at: T::BlockNumber,
+			curve: Curve,
+		) -> DispatchResult {
+			T::DispatchOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(end_at > now, Error::<T>::InvalidEndBlock);
+			ensure!(!Self::update_exists(&key), Error::<T>::GraduallyUpdateHasExisted);
+
+			let start_value = target_value.read(&key).ok_or(Error::<T>::InvalidTargetValue)?;
+
+			CurveUpdates::<T>::try_append(CurveUpdate {
+				key: key.clone(),
+				start_value,
+				target_value,
+				start_at: now,
+				end_at,
+				curve,
+			})
+			.map_err(|_| Error::<T>::MaxGraduallyUpdateExceeded)?;
+
+			Self::deposit_event(Event::CurveUpdateAdded {
+				key,
+				start_value,
+				target_value,
+				end_at,
+				curve,
+			});
+			Ok(())
+		}
+
+		/// Pause the update of the value stored at `key`. A paused curve
+		/// update continues from where it was paused when resumed, its end
+		/// block is postponed by the paused duration.
+		#[pallet::weight(T::WeightInfo::pause_gradually_update())]
+		pub fn pause_gradually_update(origin: OriginFor<T>, key: StorageKeyBytes<T>) -> DispatchResult {
+			T::DispatchOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+
+			ensure!(Self::update_exists(&key), Error::<T>::GraduallyUpdateNotFound);
+			ensure!(
+				!PausedUpdates::<T>::contains_key(&key),
+				Error::<T>::GraduallyUpdateAlreadyPaused
+			);
+
+			PausedUpdates::<T>::insert(&key, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::GraduallyUpdatePaused { key });
+			Ok(())
+		}
+
+		/// Resume the paused update of the value stored at `key`.
+		#[pallet::weight(T::WeightInfo::resume_gradually_update())]
+		pub fn resume_gradually_update(origin: OriginFor<T>, key: StorageKeyBytes<T>) -> DispatchResult {
+			T::DispatchOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+
+			let paused_at = PausedUpdates::<T>::take(&key).ok_or(Error::<T>::GraduallyUpdateNotPaused)?;
+			let paused_for = frame_system::Pallet::<T>::block_number().saturating_sub(paused_at);
+
+			CurveUpdates::<T>::mutate(|curve_updates| {
+				if let Some(update) = curve_updates.iter_mut().find(|update| update.key == key) {
+					update.start_at = update.start_at.saturating_add(paused_for);
+					update.end_at = update.end_at.saturating_add(paused_for);
+				}
+			});
+
+			Self::deposit_event(Event::GraduallyUpdateResumed { key });
+			Ok(())
+		}
 	}
 }
 
@@ -223,6 +491,11 @@ impl<T: Config> Pallet<T> {
 		now >= Self::last_updated_at() + T::UpdateFrequency::get()
 	}
 
+	fn update_exists(key: &StorageKeyBytes<T>) -> bool {
+		GraduallyUpdates::<T>::get().iter().any(|update| &update.key == key)
+			|| CurveUpdates::<T>::get().iter().any(|update| &update.key == key)
+	}
+
 	fn _on_finalize(now: T::BlockNumber) {
 		if !Self::_need_update(now) {
 			return;
@@ -232,6 +505,10 @@ impl<T: Config> Pallet<T> {
 		let initial_count = gradually_updates.len();
 
 		gradually_updates.retain(|update| {
+			if PausedUpdates::<T>::contains_key(&update.key) {
+				return true;
+			}
+
 			let mut keep = true;
 			let current_value = storage::unhashed::get::<StorageValueBytes<T>>(&update.key).unwrap_or_default();
 			let current_value_u128 = u128::from_le_bytes(Self::convert_vec_to_u8(&current_value));
@@ -264,7 +541,8 @@ impl<T: Config> Pallet<T> {
 			Self::deposit_event(Event::Updated {
 				block_number: now,
 				key: update.key.clone(),
-				target_value: bounded_value,
+				old_value: current_value,
+				new_value: bounded_value,
 			});
 
 			keep
@@ -275,6 +553,33 @@ impl<T: Config> Pallet<T> {
 			GraduallyUpdates::<T>::put(gradually_updates);
 		}
 
+		let mut curve_updates = CurveUpdates::<T>::get();
+		let initial_count = curve_updates.len();
+
+		curve_updates.retain(|update| {
+			if PausedUpdates::<T>::contains_key(&update.key) {
+				return true;
+			}
+
+			let old_value = update.target_value.read(&update.key).unwrap_or(update.start_value);
+			let new_value = update.value_at(now);
+			new_value.write(&update.key);
+
+			Self::deposit_event(Event::CurveUpdated {
+				block_number: now,
+				key: update.key.clone(),
+				old_value,
+				new_value,
+			});
+
+			// the target value is reached at the end block, remove the curve update
+			now < update.end_at
+		});
+
+		if curve_updates.len() < initial_count {
+			CurveUpdates::<T>::put(curve_updates);
+		}
+
 		LastUpdatedAt::<T>::put(now);
 	}
 
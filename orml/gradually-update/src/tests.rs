@@ -145,7 +145,8 @@ fn add_on_finalize_should_work() {
 		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::Updated {
 			block_number: 10,
 			key: update.key.clone(),
-			target_value: vec![10].try_into().unwrap(),
+			old_value: vec![].try_into().unwrap(),
+			new_value: vec![10].try_into().unwrap(),
 		}));
 		assert_eq!(System::events().len(), 2);
 
@@ -158,7 +159,8 @@ fn add_on_finalize_should_work() {
 		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::Updated {
 			block_number: 20,
 			key: update.key.clone(),
-			target_value: vec![20].try_into().unwrap(),
+			old_value: vec![10].try_into().unwrap(),
+			new_value: vec![20].try_into().unwrap(),
 		}));
 		assert_eq!(System::events().len(), 3);
 
@@ -167,7 +169,8 @@ fn add_on_finalize_should_work() {
 		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::Updated {
 			block_number: 40,
 			key: update.key,
-			target_value: vec![30].try_into().unwrap(),
+			old_value: vec![20].try_into().unwrap(),
+			new_value: vec![30].try_into().unwrap(),
 		}));
 	});
 }
@@ -192,7 +195,8 @@ fn sub_on_finalize_should_work() {
 		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::Updated {
 			block_number: 10,
 			key: update.key.clone(),
-			target_value: vec![20].try_into().unwrap(),
+			old_value: vec![30].try_into().unwrap(),
+			new_value: vec![20].try_into().unwrap(),
 		}));
 		assert_eq!(System::events().len(), 2);
 
@@ -205,7 +209,8 @@ fn sub_on_finalize_should_work() {
 		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::Updated {
 			block_number: 20,
 			key: update.key.clone(),
-			target_value: vec![10].try_into().unwrap(),
+			old_value: vec![20].try_into().unwrap(),
+			new_value: vec![10].try_into().unwrap(),
 		}));
 		assert_eq!(System::events().len(), 3);
 
@@ -214,7 +219,8 @@ fn sub_on_finalize_should_work() {
 		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::Updated {
 			block_number: 40,
 			key: update.key,
-			target_value: vec![5].try_into().unwrap(),
+			old_value: vec![10].try_into().unwrap(),
+			new_value: vec![5].try_into().unwrap(),
 		}));
 	});
 }
@@ -424,3 +430,435 @@ fn exceeding_max_gradually_updates_should_fail() {
 		assert_eq!(storage_get(&update4.key), vec![120]);
 	});
 }
+
+fn balance_get(key: &Vec<u8>) -> Option<u128> {
+	frame_support::storage::unhashed::get::<u128>(key)
+}
+
+#[test]
+fn schedule_curve_update_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::Balance(1000),
+			100,
+			Curve::Linear
+		));
+		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::CurveUpdateAdded {
+			key: key.clone(),
+			start_value: UpdateValue::Balance(0),
+			target_value: UpdateValue::Balance(1000),
+			end_at: 100,
+			curve: Curve::Linear,
+		}));
+		assert_eq!(
+			GraduallyUpdateModule::curve_updates().into_inner(),
+			vec![CurveUpdate {
+				key,
+				start_value: UpdateValue::Balance(0),
+				target_value: UpdateValue::Balance(1000),
+				start_at: 0,
+				end_at: 100,
+				curve: Curve::Linear,
+			}]
+		);
+	});
+}
+
+#[test]
+fn schedule_curve_update_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+
+		assert_noop!(
+			GraduallyUpdateModule::schedule_curve_update(
+				Origin::root(),
+				key.clone(),
+				UpdateValue::Balance(1000),
+				10,
+				Curve::Linear
+			),
+			Error::<Runtime>::InvalidEndBlock
+		);
+
+		frame_support::storage::unhashed::put(&key, &Permill::from_percent(10));
+		assert_noop!(
+			GraduallyUpdateModule::schedule_curve_update(
+				Origin::root(),
+				key.clone(),
+				UpdateValue::Balance(1000),
+				100,
+				Curve::Linear
+			),
+			Error::<Runtime>::InvalidTargetValue
+		);
+
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::Permill(Permill::from_percent(20)),
+			100,
+			Curve::Linear
+		));
+		assert_noop!(
+			GraduallyUpdateModule::schedule_curve_update(
+				Origin::root(),
+				key,
+				UpdateValue::Permill(Permill::from_percent(30)),
+				100,
+				Curve::Linear
+			),
+			Error::<Runtime>::GraduallyUpdateHasExisted
+		);
+
+		let update: GraduallyUpdate<StorageKeyBytes<Runtime>, StorageValueBytes<Runtime>> = GraduallyUpdate {
+			key: vec![2].try_into().unwrap(),
+			target_value: vec![30].try_into().unwrap(),
+			per_block: vec![1].try_into().unwrap(),
+		};
+		assert_ok!(GraduallyUpdateModule::gradually_update(Origin::root(), update.clone()));
+		assert_noop!(
+			GraduallyUpdateModule::schedule_curve_update(
+				Origin::root(),
+				update.key,
+				UpdateValue::Balance(1000),
+				100,
+				Curve::Linear
+			),
+			Error::<Runtime>::GraduallyUpdateHasExisted
+		);
+
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			vec![3].try_into().unwrap(),
+			UpdateValue::Balance(1000),
+			100,
+			Curve::Linear
+		));
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			vec![4].try_into().unwrap(),
+			UpdateValue::Balance(1000),
+			100,
+			Curve::Linear
+		));
+		assert_noop!(
+			GraduallyUpdateModule::schedule_curve_update(
+				Origin::root(),
+				vec![5].try_into().unwrap(),
+				UpdateValue::Balance(1000),
+				100,
+				Curve::Linear
+			),
+			Error::<Runtime>::MaxGraduallyUpdateExceeded
+		);
+	});
+}
+
+#[test]
+fn gradually_update_and_curve_update_on_same_key_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+
+		// a curve update blocks a raw update of the same key
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+		frame_support::storage::unhashed::put(&key, &1000u128);
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::Balance(2000),
+			100,
+			Curve::Linear
+		));
+		let update: GraduallyUpdate<StorageKeyBytes<Runtime>, StorageValueBytes<Runtime>> = GraduallyUpdate {
+			key: key.clone(),
+			target_value: 3000u128.encode().try_into().unwrap(),
+			per_block: 100u128.encode().try_into().unwrap(),
+		};
+		assert_noop!(
+			GraduallyUpdateModule::gradually_update(Origin::root(), update),
+			Error::<Runtime>::GraduallyUpdateHasExisted
+		);
+
+		// a raw update blocks another raw update and a curve update of the same key
+		let update: GraduallyUpdate<StorageKeyBytes<Runtime>, StorageValueBytes<Runtime>> = GraduallyUpdate {
+			key: vec![2].try_into().unwrap(),
+			target_value: vec![30].try_into().unwrap(),
+			per_block: vec![1].try_into().unwrap(),
+		};
+		assert_ok!(GraduallyUpdateModule::gradually_update(Origin::root(), update.clone()));
+		assert_noop!(
+			GraduallyUpdateModule::gradually_update(
+				Origin::root(),
+				GraduallyUpdate {
+					per_block: vec![2].try_into().unwrap(),
+					..update.clone()
+				}
+			),
+			Error::<Runtime>::GraduallyUpdateHasExisted
+		);
+		assert_noop!(
+			GraduallyUpdateModule::schedule_curve_update(
+				Origin::root(),
+				update.key.clone(),
+				UpdateValue::Balance(1000),
+				100,
+				Curve::Linear
+			),
+			Error::<Runtime>::GraduallyUpdateHasExisted
+		);
+
+		// the key can be updated again once the update is cancelled
+		assert_ok!(GraduallyUpdateModule::cancel_gradually_update(
+			Origin::root(),
+			key.clone()
+		));
+		assert_ok!(GraduallyUpdateModule::gradually_update(
+			Origin::root(),
+			GraduallyUpdate {
+				key,
+				target_value: 3000u128.encode().try_into().unwrap(),
+				per_block: 100u128.encode().try_into().unwrap(),
+			}
+		));
+	});
+}
+
+#[test]
+fn linear_curve_update_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::Balance(1000),
+			100,
+			Curve::Linear
+		));
+
+		GraduallyUpdateModule::on_finalize(10);
+		assert_eq!(balance_get(&key), Some(100));
+		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::CurveUpdated {
+			block_number: 10,
+			key: key.clone(),
+			old_value: UpdateValue::Balance(0),
+			new_value: UpdateValue::Balance(100),
+		}));
+		assert_eq!(System::events().len(), 2);
+
+		GraduallyUpdateModule::on_finalize(15);
+		assert_eq!(balance_get(&key), Some(100));
+		assert_eq!(System::events().len(), 2);
+
+		GraduallyUpdateModule::on_finalize(50);
+		assert_eq!(balance_get(&key), Some(500));
+		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::CurveUpdated {
+			block_number: 50,
+			key: key.clone(),
+			old_value: UpdateValue::Balance(100),
+			new_value: UpdateValue::Balance(500),
+		}));
+
+		GraduallyUpdateModule::on_finalize(100);
+		assert_eq!(balance_get(&key), Some(1000));
+		assert!(GraduallyUpdateModule::curve_updates().is_empty());
+
+		GraduallyUpdateModule::on_finalize(110);
+		assert_eq!(System::events().len(), 4);
+	});
+}
+
+#[test]
+fn ease_in_out_curve_update_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+		frame_support::storage::unhashed::put(&key, &Permill::from_percent(50));
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::Permill(Permill::from_percent(10)),
+			100,
+			Curve::EaseInOut
+		));
+
+		GraduallyUpdateModule::on_finalize(10);
+		assert_eq!(
+			frame_support::storage::unhashed::get::<Permill>(&key),
+			Some(Permill::from_parts(488_800))
+		);
+
+		GraduallyUpdateModule::on_finalize(50);
+		assert_eq!(
+			frame_support::storage::unhashed::get::<Permill>(&key),
+			Some(Permill::from_percent(30))
+		);
+		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::CurveUpdated {
+			block_number: 50,
+			key: key.clone(),
+			old_value: UpdateValue::Permill(Permill::from_parts(488_800)),
+			new_value: UpdateValue::Permill(Permill::from_percent(30)),
+		}));
+
+		GraduallyUpdateModule::on_finalize(100);
+		assert_eq!(
+			frame_support::storage::unhashed::get::<Permill>(&key),
+			Some(Permill::from_percent(10))
+		);
+		assert!(GraduallyUpdateModule::curve_updates().is_empty());
+	});
+}
+
+#[test]
+fn exponential_curve_update_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+		frame_support::storage::unhashed::put(&key, &FixedU128::one());
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::FixedU128(FixedU128::saturating_from_integer(2)),
+			100,
+			Curve::Exponential
+		));
+
+		GraduallyUpdateModule::on_finalize(50);
+		// 1 + (2^4 - 1) / (2^8 - 1)
+		assert_eq!(
+			frame_support::storage::unhashed::get::<FixedU128>(&key),
+			Some(FixedU128::from_inner(1_058_823_529_411_764_705))
+		);
+
+		GraduallyUpdateModule::on_finalize(100);
+		assert_eq!(
+			frame_support::storage::unhashed::get::<FixedU128>(&key),
+			Some(FixedU128::saturating_from_integer(2))
+		);
+		assert!(GraduallyUpdateModule::curve_updates().is_empty());
+	});
+}
+
+#[test]
+fn pause_and_resume_curve_update_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::Balance(1000),
+			100,
+			Curve::Linear
+		));
+
+		GraduallyUpdateModule::on_finalize(10);
+		assert_eq!(balance_get(&key), Some(100));
+
+		System::set_block_number(15);
+		assert_ok!(GraduallyUpdateModule::pause_gradually_update(
+			Origin::root(),
+			key.clone()
+		));
+		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::GraduallyUpdatePaused {
+			key: key.clone(),
+		}));
+		assert_eq!(GraduallyUpdateModule::paused_updates(&key), Some(15));
+
+		let events = System::events().len();
+		GraduallyUpdateModule::on_finalize(20);
+		assert_eq!(balance_get(&key), Some(100));
+		assert_eq!(System::events().len(), events);
+
+		System::set_block_number(35);
+		assert_ok!(GraduallyUpdateModule::resume_gradually_update(
+			Origin::root(),
+			key.clone()
+		));
+		System::assert_last_event(Event::GraduallyUpdateModule(crate::Event::GraduallyUpdateResumed {
+			key: key.clone(),
+		}));
+		assert_eq!(GraduallyUpdateModule::paused_updates(&key), None);
+		assert_eq!(GraduallyUpdateModule::curve_updates()[0].start_at, 20);
+		assert_eq!(GraduallyUpdateModule::curve_updates()[0].end_at, 120);
+
+		GraduallyUpdateModule::on_finalize(40);
+		assert_eq!(balance_get(&key), Some(200));
+
+		GraduallyUpdateModule::on_finalize(110);
+		assert_eq!(balance_get(&key), Some(900));
+		assert_eq!(GraduallyUpdateModule::curve_updates().len(), 1);
+
+		GraduallyUpdateModule::on_finalize(120);
+		assert_eq!(balance_get(&key), Some(1000));
+		assert!(GraduallyUpdateModule::curve_updates().is_empty());
+	});
+}
+
+#[test]
+fn pause_gradually_update_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let update: GraduallyUpdate<StorageKeyBytes<Runtime>, StorageValueBytes<Runtime>> = GraduallyUpdate {
+			key: vec![1].try_into().unwrap(),
+			target_value: vec![30].try_into().unwrap(),
+			per_block: vec![1].try_into().unwrap(),
+		};
+		assert_ok!(GraduallyUpdateModule::gradually_update(Origin::root(), update.clone()));
+		assert_ok!(GraduallyUpdateModule::pause_gradually_update(
+			Origin::root(),
+			update.key.clone()
+		));
+
+		GraduallyUpdateModule::on_finalize(10);
+		assert_eq!(storage_get(&update.key), Vec::<u8>::new());
+
+		assert_ok!(GraduallyUpdateModule::resume_gradually_update(
+			Origin::root(),
+			update.key.clone()
+		));
+		GraduallyUpdateModule::on_finalize(20);
+		assert_eq!(storage_get(&update.key), vec![10]);
+	});
+}
+
+#[test]
+fn pause_and_resume_gradually_update_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key: StorageKeyBytes<Runtime> = vec![1].try_into().unwrap();
+		assert_noop!(
+			GraduallyUpdateModule::pause_gradually_update(Origin::root(), key.clone()),
+			Error::<Runtime>::GraduallyUpdateNotFound
+		);
+		assert_noop!(
+			GraduallyUpdateModule::resume_gradually_update(Origin::root(), key.clone()),
+			Error::<Runtime>::GraduallyUpdateNotPaused
+		);
+
+		assert_ok!(GraduallyUpdateModule::schedule_curve_update(
+			Origin::root(),
+			key.clone(),
+			UpdateValue::Balance(1000),
+			100,
+			Curve::Linear
+		));
+		assert_ok!(GraduallyUpdateModule::pause_gradually_update(
+			Origin::root(),
+			key.clone()
+		));
+		assert_noop!(
+			GraduallyUpdateModule::pause_gradually_update(Origin::root(), key.clone()),
+			Error::<Runtime>::GraduallyUpdateAlreadyPaused
+		);
+
+		assert_ok!(GraduallyUpdateModule::cancel_gradually_update(
+			Origin::root(),
+			key.clone()
+		));
+		assert!(GraduallyUpdateModule::curve_updates().is_empty());
+		assert_eq!(GraduallyUpdateModule::paused_updates(&key), None);
+		assert_noop!(
+			GraduallyUpdateModule::resume_gradually_update(Origin::root(), key),
+			Error::<Runtime>::GraduallyUpdateNotPaused
+		);
+	});
+}
@@ -0,0 +1,335 @@
+//! # Token Compliance Module
+//!
+//! ## Overview
+//!
+//! A reference compliance policy for regulated tokens, to be used as the
+//! `TransferHooks` of `orml-tokens`. The policy of a currency is set by
+//! `PolicyOrigin` and names the issuer of the currency, who can freeze
+//! accounts and maintain the allowlist of the currency.
+//!
+//! Transfers of a currency with a policy are rejected if the sender or the
+//! recipient is frozen, or, if the policy is `allowlist_only`, not in the
+//! allowlist. The issuer is always allowed. Currencies without a policy are
+//! not restricted.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `set_policy` - Set the issuer and the allowlist requirement of a
+//!   currency, `PolicyOrigin` required.
+//! - `remove_policy` - Remove the policy of a currency in batches of frozen
+//!   accounts and allowlist entries, `PolicyOrigin` required.
+//! - `freeze` - Freeze an account, issuer required.
+//! - `thaw` - Thaw a frozen account, issuer required.
+//! - `update_allowlist` - Add or remove an account from the allowlist, issuer
+//!   required.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::MaxEncodedLen;
+use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use orml_traits::TransferHooks;
+use scale_info::TypeInfo;
+use sp_io::KillStorageResult;
+use sp_runtime::{
+	traits::{MaybeSerializeDeserialize, Member, StaticLookup},
+	DispatchResult, RuntimeDebug,
+};
+
+mod mock;
+mod tests;
+mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The compliance policy of a currency.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct CurrencyPolicy<AccountId> {
+	/// The issuer who manages the frozen accounts and the allowlist.
+	pub issuer: AccountId,
+	/// Only the accounts in the allowlist can send or receive the currency.
+	pub allowlist_only: bool,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency ID type
+		type CurrencyId: Parameter + Member + Copy + MaybeSerializeDeserialize + Ord + TypeInfo + MaxEncodedLen;
+
+		/// The origin which can set and remove the policies of currencies.
+		type PolicyOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The currency has no policy
+		PolicyNotFound,
+		/// The origin is not the issuer of the currency
+		NotIssuer,
+		/// The account is frozen for the currency
+		AccountFrozen,
+		/// The account is not in the allowlist of the currency
+		NotAllowlisted,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The policy of a currency was set.
+		PolicySet {
+			currency_id: T::CurrencyId,
+			issuer: T::AccountId,
+			allowlist_only: bool,
+		},
+		/// The policy of a currency was removed.
+		PolicyRemoved { currency_id: T::CurrencyId },
+		/// An account was frozen.
+		Frozen {
+			currency_id: T::CurrencyId,
+			who: T::AccountId,
+		},
+		/// A frozen account was thawed.
+		Thawed {
+			currency_id: T::CurrencyId,
+			who: T::AccountId,
+		},
+		/// An account was added to or removed from the allowlist.
+		AllowlistUpdated {
+			currency_id: T::CurrencyId,
+			who: T::AccountId,
+			allowed: bool,
+		},
+	}
+
+	/// The compliance policies of currencies.
+	///
+	/// Policies: map CurrencyId => Option<CurrencyPolicy>
+	#[pallet::storage]
+	#[pallet::getter(fn policies)]
+	pub type Policies<T: Config> =
+		StorageMap<_, Twox64Concat, T::CurrencyId, CurrencyPolicy<T::AccountId>, OptionQuery>;
+
+	/// The frozen accounts of currencies.
+	///
+	/// FrozenAccounts: double_map CurrencyId, AccountId => Option<()>
+	#[pallet::storage]
+	pub type FrozenAccounts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::CurrencyId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// The allowlists of currencies.
+	///
+	/// Allowlists: double_map CurrencyId, AccountId => Option<()>
+	#[pallet::storage]
+	pub type Allowlists<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::CurrencyId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the policy of a currency, replacing the existing one. The
+		/// frozen accounts and the allowlist are kept.
+		///
+		/// The dispatch origin for this call must be `PolicyOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `issuer`: the account managing the policy.
+		/// - `allowlist_only`: only allowlisted accounts can transfer.
+		#[pallet::weight(T::WeightInfo::set_policy())]
+		pub fn set_policy(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			allowlist_only: bool,
+		) -> DispatchResult {
+			T::PolicyOrigin::ensure_origin(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+
+			Policies::<T>::insert(
+				currency_id,
+				CurrencyPolicy {
+					issuer: issuer.clone(),
+					allowlist_only,
+				},
+			);
+			Self::deposit_event(Event::PolicySet {
+				currency_id,
+				issuer,
+				allowlist_only,
+			});
+			Ok(())
+		}
+
+		/// Remove at most `limit` frozen accounts and allowlist entries of a
+		/// currency. The policy is removed once all of them are removed, call
+		/// again until `PolicyRemoved` is emitted.
+		///
+		/// The dispatch origin for this call must be `PolicyOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `limit`: the max count of frozen accounts and allowlist entries to
+		///   remove.
+		#[pallet::weight(T::WeightInfo::remove_policy(*limit))]
+		pub fn remove_policy(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			T::PolicyOrigin::ensure_origin(origin)?;
+			ensure!(Policies::<T>::contains_key(currency_id), Error::<T>::PolicyNotFound);
+
+			let (removed, all_removed) = match FrozenAccounts::<T>::remove_prefix(currency_id, Some(limit)) {
+				KillStorageResult::AllRemoved(frozen) => {
+					match Allowlists::<T>::remove_prefix(currency_id, Some(limit.saturating_sub(frozen))) {
+						KillStorageResult::AllRemoved(allowed) => (frozen.saturating_add(allowed), true),
+						KillStorageResult::SomeRemaining(allowed) => (frozen.saturating_add(allowed), false),
+					}
+				}
+				KillStorageResult::SomeRemaining(frozen) => (frozen, false),
+			};
+
+			if all_removed {
+				Policies::<T>::remove(currency_id);
+				Self::deposit_event(Event::PolicyRemoved { currency_id });
+			}
+			Ok(Some(T::WeightInfo::remove_policy(removed)).into())
+		}
+
+		/// Freeze an account, it can not send or receive the currency.
+		///
+		/// The dispatch origin for this call must be `Signed` by the issuer.
+		///
+		/// - `currency_id`: currency type.
+		/// - `who`: the account to freeze.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			Self::ensure_issuer(currency_id, &issuer)?;
+			let who = T::Lookup::lookup(who)?;
+
+			FrozenAccounts::<T>::insert(currency_id, &who, ());
+			Self::deposit_event(Event::Frozen { currency_id, who });
+			Ok(())
+		}
+
+		/// Thaw a frozen account.
+		///
+		/// The dispatch origin for this call must be `Signed` by the issuer.
+		///
+		/// - `currency_id`: currency type.
+		/// - `who`: the account to thaw.
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			Self::ensure_issuer(currency_id, &issuer)?;
+			let who = T::Lookup::lookup(who)?;
+
+			FrozenAccounts::<T>::remove(currency_id, &who);
+			Self::deposit_event(Event::Thawed { currency_id, who });
+			Ok(())
+		}
+
+		/// Add an account to or remove it from the allowlist.
+		///
+		/// The dispatch origin for this call must be `Signed` by the issuer.
+		///
+		/// - `currency_id`: currency type.
+		/// - `who`: the account to update.
+		/// - `allowed`: whether the account is in the allowlist.
+		#[pallet::weight(T::WeightInfo::update_allowlist())]
+		pub fn update_allowlist(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+			allowed: bool,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			Self::ensure_issuer(currency_id, &issuer)?;
+			let who = T::Lookup::lookup(who)?;
+
+			if allowed {
+				Allowlists::<T>::insert(currency_id, &who, ());
+			} else {
+				Allowlists::<T>::remove(currency_id, &who);
+			}
+			Self::deposit_event(Event::AllowlistUpdated {
+				currency_id,
+				who,
+				allowed,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn ensure_issuer(currency_id: T::CurrencyId, who: &T::AccountId) -> DispatchResult {
+		let policy = Self::policies(currency_id).ok_or(Error::<T>::PolicyNotFound)?;
+		ensure!(policy.issuer == *who, Error::<T>::NotIssuer);
+		Ok(())
+	}
+
+	/// Check that `who` can send or receive `currency_id` under `policy`.
+	fn ensure_compliant(
+		currency_id: T::CurrencyId,
+		policy: &CurrencyPolicy<T::AccountId>,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		if policy.issuer == *who {
+			return Ok(());
+		}
+		ensure!(
+			!FrozenAccounts::<T>::contains_key(currency_id, who),
+			Error::<T>::AccountFrozen
+		);
+		ensure!(
+			!policy.allowlist_only || Allowlists::<T>::contains_key(currency_id, who),
+			Error::<T>::NotAllowlisted
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config, Balance> TransferHooks<T::AccountId, T::CurrencyId, Balance> for Pallet<T> {
+	fn pre_transfer(
+		currency_id: T::CurrencyId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
+		if let Some(policy) = Self::policies(currency_id) {
+			Self::ensure_compliant(currency_id, &policy, from)?;
+			Self::ensure_compliant(currency_id, &policy, to)?;
+		}
+		Ok(())
+	}
+
+	fn post_transfer(_currency_id: T::CurrencyId, _from: &T::AccountId, _to: &T::AccountId, _amount: Balance) {}
+}
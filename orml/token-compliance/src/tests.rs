@@ -0,0 +1,197 @@
+//! Unit tests for the token compliance module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn set_and_remove_policy_should_work() {
+	ExtBuilder::build().execute_with(|| {
+		assert_noop!(
+			TokenCompliance::set_policy(Origin::signed(ALICE), DOT, ISSUER, false),
+			BadOrigin
+		);
+		assert_ok!(TokenCompliance::set_policy(Origin::root(), DOT, ISSUER, true));
+		System::assert_last_event(Event::TokenCompliance(crate::Event::PolicySet {
+			currency_id: DOT,
+			issuer: ISSUER,
+			allowlist_only: true,
+		}));
+		assert_eq!(
+			TokenCompliance::policies(DOT),
+			Some(CurrencyPolicy {
+				issuer: ISSUER,
+				allowlist_only: true,
+			})
+		);
+
+		assert_ok!(TokenCompliance::freeze(Origin::signed(ISSUER), DOT, ALICE));
+		assert_ok!(TokenCompliance::update_allowlist(
+			Origin::signed(ISSUER),
+			DOT,
+			BOB,
+			true
+		));
+
+		assert_noop!(
+			TokenCompliance::remove_policy(Origin::root(), BTC, 10),
+			Error::<Runtime>::PolicyNotFound
+		);
+		assert_ok!(TokenCompliance::remove_policy(Origin::root(), DOT, 10));
+		System::assert_last_event(Event::TokenCompliance(crate::Event::PolicyRemoved { currency_id: DOT }));
+		assert_eq!(TokenCompliance::policies(DOT), None);
+		assert!(!FrozenAccounts::<Runtime>::contains_key(DOT, ALICE));
+		assert!(!Allowlists::<Runtime>::contains_key(DOT, BOB));
+	});
+}
+
+#[test]
+fn remove_policy_in_batches_should_work() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(TokenCompliance::set_policy(Origin::root(), DOT, ISSUER, true));
+		assert_ok!(TokenCompliance::freeze(Origin::signed(ISSUER), DOT, ALICE));
+		assert_ok!(TokenCompliance::update_allowlist(
+			Origin::signed(ISSUER),
+			DOT,
+			BOB,
+			true
+		));
+		assert_ok!(TokenCompliance::update_allowlist(
+			Origin::signed(ISSUER),
+			DOT,
+			CHARLIE,
+			true
+		));
+
+		// the policy is kept until all entries are removed
+		assert_eq!(
+			TokenCompliance::remove_policy(Origin::root(), DOT, 2),
+			Ok(Some(<() as WeightInfo>::remove_policy(2)).into())
+		);
+		assert!(TokenCompliance::policies(DOT).is_some());
+		assert!(!FrozenAccounts::<Runtime>::contains_key(DOT, ALICE));
+		assert_eq!(Allowlists::<Runtime>::iter_prefix(DOT).count(), 1);
+
+		assert_eq!(
+			TokenCompliance::remove_policy(Origin::root(), DOT, 10),
+			Ok(Some(<() as WeightInfo>::remove_policy(1)).into())
+		);
+		System::assert_last_event(Event::TokenCompliance(crate::Event::PolicyRemoved { currency_id: DOT }));
+		assert_eq!(TokenCompliance::policies(DOT), None);
+		assert_eq!(Allowlists::<Runtime>::iter_prefix(DOT).count(), 0);
+	});
+}
+
+#[test]
+fn only_issuer_can_manage_policy() {
+	ExtBuilder::build().execute_with(|| {
+		assert_noop!(
+			TokenCompliance::freeze(Origin::signed(ISSUER), DOT, ALICE),
+			Error::<Runtime>::PolicyNotFound
+		);
+		assert_ok!(TokenCompliance::set_policy(Origin::root(), DOT, ISSUER, false));
+		assert_noop!(
+			TokenCompliance::freeze(Origin::signed(ALICE), DOT, BOB),
+			Error::<Runtime>::NotIssuer
+		);
+		assert_noop!(
+			TokenCompliance::thaw(Origin::signed(ALICE), DOT, BOB),
+			Error::<Runtime>::NotIssuer
+		);
+		assert_noop!(
+			TokenCompliance::update_allowlist(Origin::signed(ALICE), DOT, ALICE, true),
+			Error::<Runtime>::NotIssuer
+		);
+	});
+}
+
+#[test]
+fn frozen_accounts_cannot_transfer() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(TokenCompliance::set_policy(Origin::root(), DOT, ISSUER, false));
+		assert_ok!(TokenCompliance::freeze(Origin::signed(ISSUER), DOT, ALICE));
+		System::assert_last_event(Event::TokenCompliance(crate::Event::Frozen {
+			currency_id: DOT,
+			who: ALICE,
+		}));
+
+		assert_noop!(
+			Tokens::transfer(Origin::signed(ALICE), BOB, DOT, 10),
+			Error::<Runtime>::AccountFrozen
+		);
+		assert_noop!(
+			<Tokens as MultiCurrency<_>>::transfer(DOT, &BOB, &ALICE, 10),
+			Error::<Runtime>::AccountFrozen
+		);
+		// other currencies are not restricted
+		assert_ok!(Tokens::transfer(Origin::signed(ALICE), BOB, BTC, 10));
+		// the issuer is never restricted
+		assert_ok!(TokenCompliance::freeze(Origin::signed(ISSUER), DOT, ISSUER));
+		assert_ok!(Tokens::transfer(Origin::signed(ISSUER), BOB, DOT, 10));
+
+		assert_ok!(TokenCompliance::thaw(Origin::signed(ISSUER), DOT, ALICE));
+		System::assert_last_event(Event::TokenCompliance(crate::Event::Thawed {
+			currency_id: DOT,
+			who: ALICE,
+		}));
+		assert_ok!(Tokens::transfer(Origin::signed(ALICE), BOB, DOT, 10));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 120);
+	});
+}
+
+#[test]
+fn allowlist_only_policy_should_work() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(TokenCompliance::set_policy(Origin::root(), DOT, ISSUER, true));
+		assert_noop!(
+			Tokens::transfer(Origin::signed(ALICE), BOB, DOT, 10),
+			Error::<Runtime>::NotAllowlisted
+		);
+		assert_noop!(
+			Tokens::transfer(Origin::signed(ISSUER), CHARLIE, DOT, 10),
+			Error::<Runtime>::NotAllowlisted
+		);
+
+		assert_ok!(TokenCompliance::update_allowlist(
+			Origin::signed(ISSUER),
+			DOT,
+			ALICE,
+			true
+		));
+		System::assert_last_event(Event::TokenCompliance(crate::Event::AllowlistUpdated {
+			currency_id: DOT,
+			who: ALICE,
+			allowed: true,
+		}));
+		assert_ok!(TokenCompliance::update_allowlist(
+			Origin::signed(ISSUER),
+			DOT,
+			BOB,
+			true
+		));
+		assert_ok!(Tokens::transfer(Origin::signed(ALICE), BOB, DOT, 10));
+		assert_ok!(Tokens::transfer(Origin::signed(ISSUER), ALICE, DOT, 10));
+
+		// frozen accounts are rejected even if allowlisted
+		assert_ok!(TokenCompliance::freeze(Origin::signed(ISSUER), DOT, BOB));
+		assert_noop!(
+			Tokens::transfer(Origin::signed(ALICE), BOB, DOT, 10),
+			Error::<Runtime>::AccountFrozen
+		);
+
+		assert_ok!(TokenCompliance::update_allowlist(
+			Origin::signed(ISSUER),
+			DOT,
+			ALICE,
+			false
+		));
+		assert_noop!(
+			Tokens::transfer(Origin::signed(ALICE), ISSUER, DOT, 10),
+			Error::<Runtime>::NotAllowlisted
+		);
+	});
+}
@@ -67,11 +67,12 @@ use orml_traits::{
 	arithmetic::{self, Signed},
 	currency::TransferAll,
 	BalanceStatus, GetByKey, LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency,
-	MultiReservableCurrency, OnDust,
+	MultiReservableCurrency, NamedMultiReservableCurrency, OnDust, TransferHooks,
 };
 
 mod imbalances;
 mod impls;
+pub mod migrations;
 mod mock;
 mod tests;
 mod weights;
@@ -119,6 +120,24 @@ pub struct BalanceLock<Balance> {
 	pub amount: Balance,
 }
 
+/// Store named reserved balance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct ReserveData<ReserveIdentifier, Balance> {
+	/// The identifier for the named reserve.
+	pub id: ReserveIdentifier,
+	/// The amount of the named reserve.
+	pub amount: Balance,
+}
+
+/// An amount approved by an owner to be transferred by a delegate.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Approval<Balance> {
+	/// The amount the delegate may still transfer.
+	pub amount: Balance,
+	/// The deposit reserved from the owner for the approval.
+	pub deposit: Balance,
+}
+
 /// balance information for an account.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub struct AccountData<Balance> {
@@ -201,9 +220,29 @@ pub mod module {
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, Self::CurrencyId, Self::Balance>;
 
+		/// Hooks called before and after balance is transferred between
+		/// accounts, the transfer is rejected if the pre-transfer hook fails.
+		type TransferHooks: TransferHooks<Self::AccountId, Self::CurrencyId, Self::Balance>;
+
 		#[pallet::constant]
 		type MaxLocks: Get<u32>;
 
+		/// The maximum number of named reserves that can exist on an account.
+		#[pallet::constant]
+		type MaxReserves: Get<u32>;
+
+		/// The id type for named reserves.
+		type ReserveIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The deposit reserved from the owner, in the approved currency, for
+		/// an approval to exist. Currencies with a zero deposit cannot be
+		/// approved.
+		type ApprovalDeposit: GetByKey<Self::CurrencyId, Self::Balance>;
+
+		/// The named reserve id of approval deposits.
+		#[pallet::constant]
+		type ApprovalReserveId: Get<Self::ReserveIdentifier>;
+
 		// The whitelist of accounts that will not be reaped even if its total
 		// is zero or below ED.
 		type DustRemovalWhitelist: Contains<Self::AccountId>;
@@ -225,6 +264,16 @@ pub mod module {
 		ExistentialDeposit,
 		/// Beneficiary account must pre-exist
 		DeadAccount,
+		/// Number of named reserves exceed MaxReserves
+		TooManyReserves,
+		/// No approval exists for the owner and delegate
+		Unapproved,
+		/// The amount exceeds the approved amount
+		ApprovalExceeded,
+		/// The approved amount is zero
+		ZeroApproval,
+		/// The currency cannot be approved without an approval deposit
+		NoApprovalDeposit,
 	}
 
 	#[pallet::event]
@@ -278,6 +327,27 @@ pub mod module {
 			free: T::Balance,
 			reserved: T::Balance,
 		},
+		/// Some amount was approved to be transferred by a delegate.
+		ApprovedTransfer {
+			currency_id: T::CurrencyId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			amount: T::Balance,
+		},
+		/// An approval was cancelled and its deposit unreserved.
+		ApprovalCancelled {
+			currency_id: T::CurrencyId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+		},
+		/// Some approved amount was transferred by a delegate.
+		TransferredApproved {
+			currency_id: T::CurrencyId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			destination: T::AccountId,
+			amount: T::Balance,
+		},
 	}
 
 	/// The total issuance of a token type.
@@ -299,6 +369,34 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// Named reserves on some account balances.
+	#[pallet::storage]
+	#[pallet::getter(fn reserves)]
+	pub type Reserves<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::CurrencyId,
+		BoundedVec<ReserveData<T::ReserveIdentifier, T::Balance>, T::MaxReserves>,
+		ValueQuery,
+	>;
+
+	/// The amounts of a token type that delegates are approved to transfer
+	/// from an owner.
+	#[pallet::storage]
+	#[pallet::getter(fn approvals)]
+	pub type Approvals<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, T::CurrencyId>,
+		),
+		Approval<T::Balance>,
+		OptionQuery,
+	>;
+
 	/// The balance of a token type under an account.
 	///
 	/// NOTE: If the total is ever zero, decrease account ref account.
@@ -508,6 +606,83 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Approve a delegate to transfer some amount from the balance of the
+		/// sender. The amount is added to any existing approval.
+		///
+		/// The first approval of a delegate reserves `ApprovalDeposit` of the
+		/// currency from the sender, it is unreserved when the approval is
+		/// cancelled or used up. Currencies with a zero `ApprovalDeposit`
+		/// cannot be approved.
+		///
+		/// The dispatch origin for this call must be `Signed` by the owner.
+		///
+		/// - `delegate`: The account approved to transfer.
+		/// - `currency_id`: currency type.
+		/// - `amount`: the amount to approve.
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_approve_transfer(currency_id, &owner, &delegate, amount)
+		}
+
+		/// Cancel the approval of a delegate and unreserve its deposit.
+		///
+		/// The dispatch origin for this call must be `Signed` by the owner.
+		///
+		/// - `delegate`: The account approved to transfer.
+		/// - `currency_id`: currency type.
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let approval = Approvals::<T>::take((&owner, &delegate, currency_id)).ok_or(Error::<T>::Unapproved)?;
+			<Self as NamedMultiReservableCurrency<_>>::unreserve_named(
+				&T::ApprovalReserveId::get(),
+				currency_id,
+				&owner,
+				approval.deposit,
+			);
+
+			Self::deposit_event(Event::ApprovalCancelled {
+				currency_id,
+				owner,
+				delegate,
+			});
+			Ok(())
+		}
+
+		/// Transfer some amount approved by the owner to another account.
+		///
+		/// The dispatch origin for this call must be `Signed` by the delegate.
+		///
+		/// - `owner`: The account which approved the transfer.
+		/// - `dest`: The recipient of the transfer.
+		/// - `currency_id`: currency type.
+		/// - `amount`: free balance amount to tranfer.
+		#[pallet::weight(T::WeightInfo::transfer_approved())]
+		pub fn transfer_approved(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: T::CurrencyId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_transfer_approved(currency_id, &owner, &delegate, &dest, amount)
+		}
+
 		/// Set the balances of a given account.
 		///
 		/// This will alter `FreeBalance` and `ReservedBalance` in storage. it
@@ -569,6 +744,90 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Approve `delegate` to transfer `amount` more of `currency_id` from
+	/// `owner`, reserving the approval deposit if it is a new approval.
+	pub(crate) fn do_approve_transfer(
+		currency_id: T::CurrencyId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroApproval);
+
+		Approvals::<T>::try_mutate((owner, delegate, currency_id), |maybe_approval| -> DispatchResult {
+			let mut approval = match maybe_approval.take() {
+				Some(approval) => approval,
+				None => {
+					let deposit = T::ApprovalDeposit::get(&currency_id);
+					ensure!(!deposit.is_zero(), Error::<T>::NoApprovalDeposit);
+					<Self as NamedMultiReservableCurrency<_>>::reserve_named(
+						&T::ApprovalReserveId::get(),
+						currency_id,
+						owner,
+						deposit,
+					)?;
+					Approval {
+						amount: Zero::zero(),
+						deposit,
+					}
+				}
+			};
+			approval.amount = approval.amount.saturating_add(amount);
+			*maybe_approval = Some(approval);
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::ApprovedTransfer {
+			currency_id,
+			owner: owner.clone(),
+			delegate: delegate.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Transfer `amount` of `currency_id` from `owner` to `dest` out of the
+	/// approval of `delegate`. The approval is removed and its deposit
+	/// unreserved once it is used up.
+	pub(crate) fn do_transfer_approved(
+		currency_id: T::CurrencyId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Approvals::<T>::try_mutate_exists((owner, delegate, currency_id), |maybe_approval| -> DispatchResult {
+			let mut approval = maybe_approval.take().ok_or(Error::<T>::Unapproved)?;
+			approval.amount = approval
+				.amount
+				.checked_sub(&amount)
+				.ok_or(Error::<T>::ApprovalExceeded)?;
+
+			Self::do_transfer(currency_id, owner, dest, amount, ExistenceRequirement::AllowDeath)?;
+
+			if approval.amount.is_zero() {
+				<Self as NamedMultiReservableCurrency<_>>::unreserve_named(
+					&T::ApprovalReserveId::get(),
+					currency_id,
+					owner,
+					approval.deposit,
+				);
+			} else {
+				*maybe_approval = Some(approval);
+			}
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::TransferredApproved {
+			currency_id,
+			owner: owner.clone(),
+			delegate: delegate.clone(),
+			destination: dest.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
 	pub(crate) fn deposit_consequence(
 		_who: &T::AccountId,
 		currency_id: T::CurrencyId,
@@ -828,6 +1087,8 @@ impl<T: Config> Pallet<T> {
 			return Ok(());
 		}
 
+		T::TransferHooks::pre_transfer(currency_id, from, to, amount)?;
+
 		Self::try_mutate_account(to, currency_id, |to_account, _existed| -> DispatchResult {
 			Self::try_mutate_account(from, currency_id, |from_account, _existed| -> DispatchResult {
 				from_account.free = from_account
@@ -866,7 +1127,10 @@ impl<T: Config> Pallet<T> {
 				Ok(())
 			})?;
 			Ok(())
-		})
+		})?;
+
+		T::TransferHooks::post_transfer(currency_id, from, to, amount);
+		Ok(())
 	}
 
 	/// Withdraw some free balance from an account, respecting existence
@@ -1257,6 +1521,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 		let from_account = Self::accounts(slashed, currency_id);
 		let to_account = Self::accounts(beneficiary, currency_id);
 		let actual = from_account.reserved.min(value);
+		T::TransferHooks::pre_transfer(currency_id, slashed, beneficiary, actual)?;
 		match status {
 			BalanceStatus::Free => {
 				Self::set_free_balance(currency_id, beneficiary, to_account.free + actual);
@@ -1273,10 +1538,264 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 			amount: actual,
 			status,
 		});
+		T::TransferHooks::post_transfer(currency_id, slashed, beneficiary, actual);
 		Ok(value - actual)
 	}
 }
 
+impl<T: Config> NamedMultiReservableCurrency<T::AccountId> for Pallet<T> {
+	type ReserveIdentifier = T::ReserveIdentifier;
+
+	fn reserved_balance_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+	) -> Self::Balance {
+		let reserves = Self::reserves(who, currency_id);
+		reserves
+			.binary_search_by_key(id, |data| data.id)
+			.map(|index| reserves[index].amount)
+			.unwrap_or_default()
+	}
+
+	/// Move `value` from the free balance from `who` to a named reserve
+	/// balance.
+	///
+	/// Is a no-op if value to be reserved is zero.
+	fn reserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> DispatchResult {
+		if value.is_zero() {
+			return Ok(());
+		}
+
+		Reserves::<T>::try_mutate(who, currency_id, |reserves| -> DispatchResult {
+			match reserves.binary_search_by_key(id, |data| data.id) {
+				Ok(index) => {
+					// this add can't overflow but just to be defensive.
+					reserves[index].amount = reserves[index].amount.saturating_add(value);
+				}
+				Err(index) => {
+					reserves
+						.try_insert(index, ReserveData { id: *id, amount: value })
+						.map_err(|_| Error::<T>::TooManyReserves)?;
+				}
+			};
+			<Self as MultiReservableCurrency<_>>::reserve(currency_id, who, value)
+		})
+	}
+
+	/// Unreserve some funds, returning any amount that was unable to be
+	/// unreserved.
+	///
+	/// Is a no-op if the value to be unreserved is zero.
+	fn unreserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Self::Balance {
+		if value.is_zero() {
+			return Zero::zero();
+		}
+
+		Reserves::<T>::mutate_exists(who, currency_id, |maybe_reserves| -> Self::Balance {
+			if let Some(reserves) = maybe_reserves.as_mut() {
+				match reserves.binary_search_by_key(id, |data| data.id) {
+					Ok(index) => {
+						let to_change = reserves[index].amount.min(value);
+
+						let remain = <Self as MultiReservableCurrency<_>>::unreserve(currency_id, who, to_change);
+
+						// remain should always be zero but just to be defensive here.
+						let actual = to_change.saturating_sub(remain);
+
+						// `actual <= to_change` and `to_change <= amount`; qed;
+						reserves[index].amount -= actual;
+
+						if reserves[index].amount.is_zero() {
+							if reserves.len() == 1 {
+								// no more named reserves
+								*maybe_reserves = None;
+							} else {
+								// remove this named reserve
+								reserves.remove(index);
+							}
+						}
+						value - actual
+					}
+					Err(_) => value,
+				}
+			} else {
+				value
+			}
+		})
+	}
+
+	/// Slash from reserved balance, returning any amount that was unable to be
+	/// slashed.
+	///
+	/// Is a no-op if the value to be slashed is zero.
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Self::Balance {
+		if value.is_zero() {
+			return Zero::zero();
+		}
+
+		Reserves::<T>::mutate_exists(who, currency_id, |maybe_reserves| -> Self::Balance {
+			if let Some(reserves) = maybe_reserves.as_mut() {
+				match reserves.binary_search_by_key(id, |data| data.id) {
+					Ok(index) => {
+						let to_change = reserves[index].amount.min(value);
+
+						let remain = <Self as MultiReservableCurrency<_>>::slash_reserved(currency_id, who, to_change);
+
+						// remain should always be zero but just to be defensive here.
+						let actual = to_change.saturating_sub(remain);
+
+						// `actual <= to_change` and `to_change <= amount`; qed;
+						reserves[index].amount -= actual;
+
+						if reserves[index].amount.is_zero() {
+							if reserves.len() == 1 {
+								// no more named reserves
+								*maybe_reserves = None;
+							} else {
+								// remove this named reserve
+								reserves.remove(index);
+							}
+						}
+						value - actual
+					}
+					Err(_) => value,
+				}
+			} else {
+				value
+			}
+		})
+	}
+
+	/// Move the reserved balance of one account into the balance of another,
+	/// according to `status`. If `status` is `Reserved`, the balance will be
+	/// reserved with given `id`.
+	///
+	/// Is a no-op if:
+	/// - the value to be moved is zero; or
+	/// - the `slashed` id equal to `beneficiary` and the `status` is
+	///   `Reserved`.
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> Result<Self::Balance, DispatchError> {
+		if value.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		if slashed == beneficiary {
+			return match status {
+				BalanceStatus::Free => Ok(Self::unreserve_named(id, currency_id, slashed, value)),
+				BalanceStatus::Reserved => {
+					Ok(value.saturating_sub(Self::reserved_balance_named(id, currency_id, slashed)))
+				}
+			};
+		}
+
+		Reserves::<T>::try_mutate_exists(
+			slashed,
+			currency_id,
+			|maybe_reserves| -> Result<Self::Balance, DispatchError> {
+				let reserves = match maybe_reserves.as_mut() {
+					Some(reserves) => reserves,
+					None => return Ok(value),
+				};
+				let index = match reserves.binary_search_by_key(id, |data| data.id) {
+					Ok(index) => index,
+					Err(_) => return Ok(value),
+				};
+				let to_change = reserves[index].amount.min(value);
+
+				let actual = if status == BalanceStatus::Reserved {
+					// make it the reserved under same identifier
+					Reserves::<T>::try_mutate(
+						beneficiary,
+						currency_id,
+						|beneficiary_reserves| -> Result<T::Balance, DispatchError> {
+							// make room for the identifier before moving the funds.
+							let index = match beneficiary_reserves.binary_search_by_key(id, |data| data.id) {
+								Ok(index) => index,
+								Err(index) => {
+									beneficiary_reserves
+										.try_insert(
+											index,
+											ReserveData {
+												id: *id,
+												amount: Zero::zero(),
+											},
+										)
+										.map_err(|_| Error::<T>::TooManyReserves)?;
+									index
+								}
+							};
+
+							let remain = <Self as MultiReservableCurrency<_>>::repatriate_reserved(
+								currency_id,
+								slashed,
+								beneficiary,
+								to_change,
+								status,
+							)?;
+
+							// remain should always be zero but just to be defensive here.
+							let actual = to_change.saturating_sub(remain);
+
+							// this add can't overflow but just to be defensive.
+							beneficiary_reserves[index].amount =
+								beneficiary_reserves[index].amount.saturating_add(actual);
+							Ok(actual)
+						},
+					)?
+				} else {
+					let remain = <Self as MultiReservableCurrency<_>>::repatriate_reserved(
+						currency_id,
+						slashed,
+						beneficiary,
+						to_change,
+						status,
+					)?;
+
+					// remain should always be zero but just to be defensive here.
+					to_change.saturating_sub(remain)
+				};
+
+				// `actual <= to_change` and `to_change <= amount`; qed;
+				reserves[index].amount -= actual;
+
+				if reserves[index].amount.is_zero() {
+					if reserves.len() == 1 {
+						// no more named reserves
+						*maybe_reserves = None;
+					} else {
+						// remove this named reserve
+						reserves.remove(index);
+					}
+				}
+				Ok(value - actual)
+			},
+		)
+	}
+}
+
 impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 	type AssetId = T::CurrencyId;
 	type Balance = T::Balance;
@@ -1369,6 +1888,35 @@ impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
 	}
 }
 
+impl<T: Config> fungibles::approvals::Inspect<T::AccountId> for Pallet<T> {
+	fn allowance(asset_id: Self::AssetId, owner: &T::AccountId, delegate: &T::AccountId) -> Self::Balance {
+		Approvals::<T>::get((owner, delegate, asset_id))
+			.map(|approval| approval.amount)
+			.unwrap_or_default()
+	}
+}
+
+impl<T: Config> fungibles::approvals::Mutate<T::AccountId> for Pallet<T> {
+	fn approve(
+		asset_id: Self::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::do_approve_transfer(asset_id, owner, delegate, amount)
+	}
+
+	fn transfer_from(
+		asset_id: Self::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::do_transfer_approved(asset_id, owner, delegate, dest, amount)
+	}
+}
+
 impl<T: Config> fungibles::InspectHold<T::AccountId> for Pallet<T> {
 	fn balance_on_hold(asset_id: Self::AssetId, who: &T::AccountId) -> T::Balance {
 		Self::accounts(who, asset_id).reserved
@@ -0,0 +1,88 @@
+//! Storage migrations for the tokens module.
+
+use crate::{Accounts, Config, Error, ReserveData, Reserves};
+use frame_support::{
+	log,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Assign the reserved balances created before named reserves existed to named
+/// reserves, so they can be told apart and unreserved with
+/// `NamedMultiReservableCurrency`.
+///
+/// `NamedReserves` lists the `(who, currency_id, id, amount)` of the named
+/// reserves, usually collected from the storages of the pallets which reserved
+/// them. The named reserve is capped by the reserved balance not yet assigned
+/// to other named reserves; the rest of the reserved balance stays anonymous.
+pub struct MigrateToNamedReserves<T, NamedReserves>(PhantomData<(T, NamedReserves)>);
+
+impl<T, NamedReserves> OnRuntimeUpgrade for MigrateToNamedReserves<T, NamedReserves>
+where
+	T: Config,
+	NamedReserves: Get<Vec<(T::AccountId, T::CurrencyId, T::ReserveIdentifier, T::Balance)>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: Weight = 0;
+
+		for (who, currency_id, id, amount) in NamedReserves::get() {
+			count = count.saturating_add(1);
+
+			let named = Reserves::<T>::get(&who, currency_id)
+				.iter()
+				.fold(Zero::zero(), |total: T::Balance, data| {
+					total.saturating_add(data.amount)
+				});
+			let amount = amount.min(Accounts::<T>::get(&who, currency_id).reserved.saturating_sub(named));
+			if amount.is_zero() {
+				continue;
+			}
+
+			let result = Reserves::<T>::try_mutate(&who, currency_id, |reserves| -> Result<(), DispatchError> {
+				match reserves.binary_search_by_key(&id, |data| data.id) {
+					Ok(index) => {
+						reserves[index].amount = reserves[index].amount.saturating_add(amount);
+					}
+					Err(index) => {
+						reserves
+							.try_insert(index, ReserveData { id, amount })
+							.map_err(|_| Error::<T>::TooManyReserves)?;
+					}
+				}
+				Ok(())
+			});
+
+			if let Err(e) = result {
+				log::warn!(
+					target: "tokens",
+					"Failed to migrate the reserve {:?} of {:?} in {:?}: {:?}",
+					id,
+					who,
+					currency_id,
+					e
+				);
+			}
+		}
+
+		T::DbWeight::get().reads_writes(count.saturating_mul(2), count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		for (who, currency_id, reserves) in Reserves::<T>::iter() {
+			let named = reserves.iter().fold(Zero::zero(), |total: T::Balance, data| {
+				total.saturating_add(data.amount)
+			});
+			assert!(
+				named <= Accounts::<T>::get(&who, currency_id).reserved,
+				"Named reserves exceed the reserved balance."
+			);
+		}
+		Ok(())
+	}
+}
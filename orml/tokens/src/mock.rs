@@ -29,9 +29,16 @@ pub const BOB: AccountId = AccountId32::new([1u8; 32]);
 pub const CHARLIE: AccountId = AccountId32::new([2u8; 32]);
 pub const DAVE: AccountId = AccountId32::new([3u8; 32]);
 pub const TREASURY_ACCOUNT: AccountId = AccountId32::new([4u8; 32]);
+pub const FROZEN: AccountId = AccountId32::new([5u8; 32]);
 pub const ID_1: LockIdentifier = *b"1       ";
 pub const ID_2: LockIdentifier = *b"2       ";
 pub const ID_3: LockIdentifier = *b"3       ";
+pub const RID_1: ReserveIdentifier = [1u8; 8];
+pub const RID_2: ReserveIdentifier = [2u8; 8];
+pub const RID_3: ReserveIdentifier = [3u8; 8];
+pub const APPROVAL_RID: ReserveIdentifier = *b"approval";
+
+pub type ReserveIdentifier = [u8; 8];
 
 use crate as tokens;
 
@@ -224,9 +231,41 @@ parameter_type_with_key! {
 	};
 }
 
+thread_local! {
+	pub static LAST_TRANSFER: RefCell<Option<(CurrencyId, AccountId, AccountId, Balance)>> = RefCell::new(None);
+}
+
+pub struct MockTransferHooks;
+impl TransferHooks<AccountId, CurrencyId, Balance> for MockTransferHooks {
+	fn pre_transfer(_currency_id: CurrencyId, from: &AccountId, to: &AccountId, _amount: Balance) -> DispatchResult {
+		ensure!(*from != FROZEN && *to != FROZEN, DispatchError::Other("frozen"));
+		Ok(())
+	}
+
+	fn post_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, amount: Balance) {
+		LAST_TRANSFER.with(|v| *v.borrow_mut() = Some((currency_id, from.clone(), to.clone(), amount)));
+	}
+}
+
+pub fn last_transfer() -> Option<(CurrencyId, AccountId, AccountId, Balance)> {
+	LAST_TRANSFER.with(|v| v.borrow().clone())
+}
+
+parameter_type_with_key! {
+	pub ApprovalDeposits: |currency_id: CurrencyId| -> Balance {
+		#[allow(clippy::match_ref_pats)] // false positive
+		match currency_id {
+			&DOT => 5,
+			_ => 0,
+		}
+	};
+}
+
 parameter_types! {
+	pub const ApprovalReserveId: ReserveIdentifier = APPROVAL_RID;
 	pub DustReceiver: AccountId = PalletId(*b"orml/dst").into_account();
 	pub MaxLocks: u32 = 2;
+	pub const MaxReserves: u32 = 2;
 }
 
 impl Config for Runtime {
@@ -237,7 +276,12 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = TransferDust<Runtime, DustReceiver>;
+	type TransferHooks = MockTransferHooks;
 	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = ReserveIdentifier;
+	type ApprovalDeposit = ApprovalDeposits;
+	type ApprovalReserveId = ApprovalReserveId;
 	type DustRemovalWhitelist = MockDustRemovalWhitelist;
 }
 pub type TreasuryCurrencyAdapter = <Runtime as pallet_treasury::Config>::Currency;
@@ -3,7 +3,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::OnRuntimeUpgrade};
 use frame_system::RawOrigin;
 use mock::{Event, *};
 use sp_runtime::{traits::BadOrigin, TokenError};
@@ -260,6 +260,151 @@ fn set_balance_should_work() {
 		});
 }
 
+#[test]
+fn approve_transfer_and_cancel_approval_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Tokens::approve_transfer(Some(ALICE).into(), BOB, DOT, 30));
+			System::assert_last_event(Event::Tokens(crate::Event::ApprovedTransfer {
+				currency_id: DOT,
+				owner: ALICE,
+				delegate: BOB,
+				amount: 30,
+			}));
+			assert_eq!(
+				Tokens::approvals((ALICE, BOB, DOT)),
+				Some(Approval { amount: 30, deposit: 5 })
+			);
+			assert_eq!(Tokens::reserved_balance_named(&APPROVAL_RID, DOT, &ALICE), 5);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 95);
+
+			// approving again adds to the approval without another deposit
+			assert_ok!(Tokens::approve_transfer(Some(ALICE).into(), BOB, DOT, 20));
+			assert_eq!(
+				Tokens::approvals((ALICE, BOB, DOT)),
+				Some(Approval { amount: 50, deposit: 5 })
+			);
+			assert_eq!(Tokens::reserved_balance_named(&APPROVAL_RID, DOT, &ALICE), 5);
+
+			assert_ok!(Tokens::approve_transfer(Some(ALICE).into(), CHARLIE, DOT, 10));
+			assert_eq!(Tokens::reserved_balance_named(&APPROVAL_RID, DOT, &ALICE), 10);
+
+			// cannot pay the deposit
+			assert_noop!(
+				Tokens::approve_transfer(Some(BOB).into(), ALICE, DOT, 10),
+				Error::<Runtime>::BalanceTooLow
+			);
+			assert_noop!(
+				Tokens::approve_transfer(Some(ALICE).into(), DAVE, DOT, 0),
+				Error::<Runtime>::ZeroApproval
+			);
+			// BTC has no approval deposit
+			assert_noop!(
+				Tokens::approve_transfer(Some(ALICE).into(), BOB, BTC, 10),
+				Error::<Runtime>::NoApprovalDeposit
+			);
+
+			assert_noop!(
+				Tokens::cancel_approval(Some(BOB).into(), ALICE, DOT),
+				Error::<Runtime>::Unapproved
+			);
+			assert_ok!(Tokens::cancel_approval(Some(ALICE).into(), BOB, DOT));
+			System::assert_last_event(Event::Tokens(crate::Event::ApprovalCancelled {
+				currency_id: DOT,
+				owner: ALICE,
+				delegate: BOB,
+			}));
+			assert_eq!(Tokens::approvals((ALICE, BOB, DOT)), None);
+			assert_eq!(Tokens::reserved_balance_named(&APPROVAL_RID, DOT, &ALICE), 5);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 95);
+		});
+}
+
+#[test]
+fn transfer_approved_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Tokens::transfer_approved(Some(BOB).into(), ALICE, CHARLIE, DOT, 10),
+				Error::<Runtime>::Unapproved
+			);
+
+			assert_ok!(Tokens::approve_transfer(Some(ALICE).into(), BOB, DOT, 50));
+			assert_noop!(
+				Tokens::transfer_approved(Some(BOB).into(), ALICE, CHARLIE, DOT, 51),
+				Error::<Runtime>::ApprovalExceeded
+			);
+			// the approval does not bypass the balance of the owner
+			assert_ok!(Tokens::transfer(Some(ALICE).into(), DAVE, DOT, 60));
+			assert_noop!(
+				Tokens::transfer_approved(Some(BOB).into(), ALICE, CHARLIE, DOT, 40),
+				Error::<Runtime>::BalanceTooLow
+			);
+
+			assert_ok!(Tokens::transfer_approved(Some(BOB).into(), ALICE, CHARLIE, DOT, 30));
+			System::assert_last_event(Event::Tokens(crate::Event::TransferredApproved {
+				currency_id: DOT,
+				owner: ALICE,
+				delegate: BOB,
+				destination: CHARLIE,
+				amount: 30,
+			}));
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 5);
+			assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 30);
+			assert_eq!(
+				Tokens::approvals((ALICE, BOB, DOT)),
+				Some(Approval { amount: 20, deposit: 5 })
+			);
+
+			// the deposit is unreserved once the approval is used up
+			assert_ok!(Tokens::transfer(Some(DAVE).into(), ALICE, DOT, 15));
+			assert_ok!(Tokens::transfer_approved(Some(BOB).into(), ALICE, CHARLIE, DOT, 20));
+			assert_eq!(Tokens::approvals((ALICE, BOB, DOT)), None);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 5);
+			assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 50);
+		});
+}
+
+#[test]
+fn transfer_hooks_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, DOT, 100), (FROZEN, DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Tokens::transfer(Some(ALICE).into(), FROZEN, DOT, 10),
+				DispatchError::Other("frozen")
+			);
+			assert_noop!(
+				<Tokens as MultiCurrency<_>>::transfer(DOT, &FROZEN, &ALICE, 10),
+				DispatchError::Other("frozen")
+			);
+			assert_noop!(
+				<Tokens as fungibles::Transfer<_>>::transfer(DOT, &ALICE, &FROZEN, 10, true),
+				DispatchError::Other("frozen")
+			);
+			assert_ok!(Tokens::reserve(DOT, &ALICE, 20));
+			assert_noop!(
+				Tokens::repatriate_reserved(DOT, &ALICE, &FROZEN, 10, BalanceStatus::Free),
+				DispatchError::Other("frozen")
+			);
+			assert_eq!(last_transfer(), None);
+
+			assert_ok!(Tokens::transfer(Some(ALICE).into(), BOB, DOT, 10));
+			assert_eq!(last_transfer(), Some((DOT, ALICE, BOB, 10)));
+			assert_eq!(
+				Tokens::repatriate_reserved(DOT, &ALICE, &BOB, 30, BalanceStatus::Reserved),
+				Ok(10)
+			);
+			assert_eq!(last_transfer(), Some((DOT, ALICE, BOB, 20)));
+		});
+}
+
 // *************************************************
 // tests for inline impl
 // *************************************************
@@ -1540,6 +1685,135 @@ fn transfer_all_trait_should_work() {
 		});
 }
 
+#[test]
+fn named_multi_reservable_currency_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Tokens::reserve_named(&RID_1, DOT, &ALICE, 30));
+			assert_ok!(Tokens::reserve_named(&RID_2, DOT, &ALICE, 20));
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 30);
+			assert_eq!(Tokens::reserved_balance_named(&RID_2, DOT, &ALICE), 20);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 50);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 50);
+			assert_noop!(
+				Tokens::reserve_named(&RID_3, DOT, &ALICE, 10),
+				Error::<Runtime>::TooManyReserves
+			);
+			assert_noop!(
+				Tokens::reserve_named(&RID_1, DOT, &ALICE, 60),
+				Error::<Runtime>::BalanceTooLow
+			);
+
+			// unreserve more than the named reserve
+			assert_eq!(Tokens::unreserve_named(&RID_1, DOT, &ALICE, 40), 10);
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 0);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 20);
+			assert_eq!(Tokens::reserves(&ALICE, DOT).len(), 1);
+
+			assert_ok!(Tokens::ensure_reserved_named(&RID_2, DOT, &ALICE, 5));
+			assert_eq!(Tokens::reserved_balance_named(&RID_2, DOT, &ALICE), 5);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 5);
+			assert_eq!(Tokens::unreserve_all_named(&RID_2, DOT, &ALICE), 5);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+			assert!(!Reserves::<Runtime>::contains_key(&ALICE, DOT));
+
+			// the anonymous reserve is not touched by named reserves
+			assert_ok!(Tokens::reserve(DOT, &ALICE, 10));
+			assert_ok!(Tokens::reserve_named(&RID_1, DOT, &ALICE, 30));
+			assert_eq!(Tokens::unreserve_named(&RID_2, DOT, &ALICE, 10), 10);
+			assert_eq!(Tokens::slash_reserved_named(&RID_1, DOT, &ALICE, 20), 0);
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 10);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 20);
+			assert_eq!(Tokens::total_issuance(DOT), 80);
+			assert_eq!(Tokens::slash_all_reserved_named(&RID_1, DOT, &ALICE), 0);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 10);
+			assert_eq!(Tokens::total_issuance(DOT), 70);
+		});
+}
+
+#[test]
+fn repatriate_reserved_named_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, DOT, 100), (BOB, DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Tokens::reserve_named(&RID_1, DOT, &ALICE, 50));
+
+			assert_eq!(
+				Tokens::repatriate_reserved_named(&RID_1, DOT, &ALICE, &BOB, 20, BalanceStatus::Reserved),
+				Ok(0)
+			);
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 30);
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &BOB), 20);
+			assert_eq!(Tokens::reserved_balance(DOT, &BOB), 20);
+
+			assert_eq!(
+				Tokens::repatriate_reserved_named(&RID_1, DOT, &ALICE, &BOB, 40, BalanceStatus::Free),
+				Ok(10)
+			);
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 0);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+			assert!(!Reserves::<Runtime>::contains_key(&ALICE, DOT));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 130);
+
+			// no named reserve to repatriate
+			assert_eq!(
+				Tokens::repatriate_reserved_named(&RID_2, DOT, &BOB, &ALICE, 20, BalanceStatus::Free),
+				Ok(20)
+			);
+			assert_ok!(Tokens::repatriate_all_reserved_named(
+				&RID_1,
+				DOT,
+				&BOB,
+				&ALICE,
+				BalanceStatus::Reserved
+			));
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 20);
+			assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+
+			// the beneficiary has no room for the identifier, nothing is moved
+			assert_ok!(Tokens::reserve_named(&RID_2, DOT, &BOB, 10));
+			assert_ok!(Tokens::reserve_named(&RID_3, DOT, &BOB, 10));
+			assert_noop!(
+				Tokens::repatriate_reserved_named(&RID_1, DOT, &ALICE, &BOB, 10, BalanceStatus::Reserved),
+				Error::<Runtime>::TooManyReserves
+			);
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 20);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 20);
+			assert_eq!(Tokens::reserved_balance(DOT, &BOB), 20);
+		});
+}
+
+#[test]
+fn migrate_to_named_reserves_should_work() {
+	frame_support::parameter_types! {
+		pub NamedReservesToMigrate: Vec<(AccountId, CurrencyId, ReserveIdentifier, Balance)> = vec![
+			(ALICE, DOT, RID_1, 30),
+			(ALICE, DOT, RID_2, 30),
+			(BOB, DOT, RID_1, 10),
+		];
+	}
+
+	ExtBuilder::default()
+		.balances(vec![(ALICE, DOT, 100), (BOB, DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Tokens::reserve(DOT, &ALICE, 50));
+
+			migrations::MigrateToNamedReserves::<Runtime, NamedReservesToMigrate>::on_runtime_upgrade();
+			assert_eq!(Tokens::reserved_balance_named(&RID_1, DOT, &ALICE), 30);
+			// capped by the reserved balance
+			assert_eq!(Tokens::reserved_balance_named(&RID_2, DOT, &ALICE), 20);
+			assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 50);
+			assert!(!Reserves::<Runtime>::contains_key(&BOB, DOT));
+
+			assert_eq!(Tokens::unreserve_named(&RID_2, DOT, &ALICE, 20), 0);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 70);
+		});
+}
+
 // *************************************************
 // tests for CurrencyAdapter
 // *************************************************
@@ -2419,3 +2693,31 @@ fn fungibles_mutate_convert_should_work() {
 			);
 		});
 }
+
+#[test]
+fn fungibles_approvals_trait_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				<Tokens as fungibles::approvals::Inspect<_>>::allowance(DOT, &ALICE, &BOB),
+				0
+			);
+			assert_ok!(<Tokens as fungibles::approvals::Mutate<_>>::approve(
+				DOT, &ALICE, &BOB, 10
+			));
+			assert_eq!(
+				<Tokens as fungibles::approvals::Inspect<_>>::allowance(DOT, &ALICE, &BOB),
+				10
+			);
+			assert_ok!(<Tokens as fungibles::approvals::Mutate<_>>::transfer_from(
+				DOT, &ALICE, &BOB, &CHARLIE, 4
+			));
+			assert_eq!(
+				<Tokens as fungibles::approvals::Inspect<_>>::allowance(DOT, &ALICE, &BOB),
+				6
+			);
+			assert_eq!(<Tokens as fungibles::Inspect<_>>::balance(DOT, &CHARLIE), 4);
+		});
+}
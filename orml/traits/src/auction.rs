@@ -24,6 +24,19 @@ pub struct AuctionInfo<AccountId, Balance, BlockNumber> {
 	pub end: Option<BlockNumber>,
 }
 
+/// Auction parameters.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AuctionParams<Balance, BlockNumber> {
+	/// Bids lower than the reserve price are not accepted.
+	pub reserve_price: Balance,
+	/// The minimum amount a bid must exceed the current bid by.
+	pub min_increment: Balance,
+	/// Make it a candle auction ending at a random block of the last
+	/// `candle_period` blocks before `end`. The auction is won by the best
+	/// bid at the end of that candle block.
+	pub candle_period: Option<BlockNumber>,
+}
+
 /// Abstraction over a simple auction system.
 pub trait Auction<AccountId, BlockNumber> {
 	/// The id of an AuctionInfo
@@ -38,7 +51,20 @@ pub trait Auction<AccountId, BlockNumber> {
 	/// Create new auction with specific startblock and endblock, return the id
 	/// of the auction
 	fn new_auction(start: BlockNumber, end: Option<BlockNumber>) -> result::Result<Self::AuctionId, DispatchError>;
-	/// Remove auction by `id`
+	/// Create new auction with specific startblock, endblock and parameters,
+	/// return the id of the auction
+	fn new_auction_with_params(
+		start: BlockNumber,
+		end: Option<BlockNumber>,
+		params: AuctionParams<Self::Balance, BlockNumber>,
+	) -> result::Result<Self::AuctionId, DispatchError>;
+	/// The auction parameters of `id`
+	fn auction_params(id: Self::AuctionId) -> Option<AuctionParams<Self::Balance, BlockNumber>>;
+	/// The winning bid of the auction `id` if it ended at block `at`. For a
+	/// candle auction this is the winner if `at` is drawn as candle block.
+	fn winning_bid_at(id: Self::AuctionId, at: BlockNumber) -> Option<(AccountId, Self::Balance)>;
+	/// Remove auction by `id`. The held bids of a candle auction other than
+	/// the current bid are released by `AuctionHandler::on_losing_bid`.
 	fn remove_auction(id: Self::AuctionId);
 }
 
@@ -55,13 +81,18 @@ pub trait AuctionHandler<AccountId, Balance, BlockNumber, AuctionId> {
 	/// Called when new bid is received.
 	/// The return value determines if the bid should be accepted and update
 	/// auction end time. Implementation should reserve money from current
-	/// winner and refund previous winner.
+	/// winner and refund previous winner. `last_bid` is `None` if the previous
+	/// bid may still win a candle auction, and is held until it ends.
 	fn on_new_bid(
 		now: BlockNumber,
 		id: AuctionId,
 		new_bid: (AccountId, Balance),
 		last_bid: Option<(AccountId, Balance)>,
 	) -> OnNewBidResult<BlockNumber>;
-	/// End an auction with `winner`
+	/// End an auction with `winner`. The winner of a candle auction is the
+	/// best bid at its candle block, which may not be the last bid.
 	fn on_auction_ended(id: AuctionId, winner: Option<(AccountId, Balance)>);
+	/// Called for each held bid of a candle auction which did not win it, when
+	/// the auction ends or is removed. Implementation should refund the bid.
+	fn on_losing_bid(id: AuctionId, bid: (AccountId, Balance));
 }
@@ -201,6 +201,150 @@ pub trait MultiReservableCurrency<AccountId>: MultiCurrency<AccountId> {
 	) -> result::Result<Self::Balance, DispatchError>;
 }
 
+/// A fungible multi-currency system where funds can be reserved from the user
+/// with an identifier.
+pub trait NamedMultiReservableCurrency<AccountId>: MultiReservableCurrency<AccountId> {
+	/// An identifier for a reserve. Used for disambiguating different reserves
+	/// so that they can be individually replaced or removed.
+	type ReserveIdentifier;
+
+	/// Deducts up to `value` from reserved balance of `who`. This function
+	/// cannot fail.
+	///
+	/// As much funds up to `value` will be deducted as possible. If the reserve
+	/// balance of `who` is less than `value`, then a non-zero second item will
+	/// be returned.
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> Self::Balance;
+
+	/// The amount of the balance of a given account that is externally
+	/// reserved under the identifier `id`; this can still get slashed, but
+	/// gets slashed last of all.
+	fn reserved_balance_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+	) -> Self::Balance;
+
+	/// Moves `value` from balance to reserved balance under the identifier
+	/// `id`.
+	///
+	/// If the free balance is lower than `value`, then no funds will be moved
+	/// and an `Err` will be returned to notify of this. This is different
+	/// behavior than `unreserve_named`.
+	fn reserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> DispatchResult;
+
+	/// Moves up to `value` from reserved balance under the identifier `id` to
+	/// free balance. This function cannot fail.
+	///
+	/// As much funds up to `value` will be moved as possible. If the reserve
+	/// balance of `who` is less than `value`, then the remaining amount will be
+	/// returned.
+	fn unreserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> Self::Balance;
+
+	/// Moves up to `value` from reserved balance under the identifier `id` of
+	/// account `slashed` to balance of account `beneficiary`. `beneficiary`
+	/// must exist for this to succeed. If it does not, `Err` will be returned.
+	/// Funds will be placed in either the `free` balance or the `reserved`
+	/// balance under the same identifier, depending on the `status`.
+	///
+	/// As much funds up to `value` will be deducted as possible. If this is
+	/// less than `value`, then `Ok(non_zero)` will be returned.
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError>;
+
+	/// Ensure the reserved balance under the identifier `id` is equal to
+	/// `value`.
+	///
+	/// This will reserve extra amount of current reserved balance is less than
+	/// `value`. And unreserve if current reserved balance is greater than
+	/// `value`.
+	fn ensure_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> DispatchResult {
+		let current = Self::reserved_balance_named(id, currency_id, who);
+		if current > value {
+			// we always have enough balance to unreserve here
+			Self::unreserve_named(id, currency_id, who, current - value);
+			Ok(())
+		} else if value > current {
+			// we checked value > current
+			Self::reserve_named(id, currency_id, who, value - current)
+		} else {
+			// current == value
+			Ok(())
+		}
+	}
+
+	/// Unreserve all the named reserved balances, returning unreserved amount.
+	///
+	/// Is a no-op if the value to be unreserved is zero.
+	fn unreserve_all_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+	) -> Self::Balance {
+		let value = Self::reserved_balance_named(id, currency_id, who);
+		Self::unreserve_named(id, currency_id, who, value);
+		value
+	}
+
+	/// Slash all the reserved balance, returning the amount that was unable
+	/// to be slashed.
+	///
+	/// Is a no-op if the value to be slashed is zero.
+	fn slash_all_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+	) -> Self::Balance {
+		let value = Self::reserved_balance_named(id, currency_id, who);
+		Self::slash_reserved_named(id, currency_id, who, value)
+	}
+
+	/// Move all the named reserved balance of one account into the balance of
+	/// another, according to `status`. If `status` is `Reserved`, the balance
+	/// will be reserved with given `id`.
+	///
+	/// Is a no-op if:
+	/// - the value to be moved is zero; or
+	/// - the `slashed` id equal to `beneficiary` and the `status` is
+	///   `Reserved`.
+	fn repatriate_all_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		status: BalanceStatus,
+	) -> DispatchResult {
+		let value = Self::reserved_balance_named(id, currency_id, slashed);
+		Self::repatriate_reserved_named(id, currency_id, slashed, beneficiary, value, status).map(|_| ())
+	}
+}
+
 /// Abstraction over a fungible (single) currency system.
 pub trait BasicCurrency<AccountId> {
 	/// The balance of an account.
@@ -361,6 +505,30 @@ impl<AccountId, CurrencyId, Balance> OnDust<AccountId, CurrencyId, Balance> for
 	fn on_dust(_: &AccountId, _: CurrencyId, _: Balance) {}
 }
 
+/// Hooks called around the transfers of a currency, e.g. to enforce the
+/// compliance policy of the currency.
+pub trait TransferHooks<AccountId, CurrencyId, Balance> {
+	/// Called before `amount` of `currency_id` is transferred from `from` to
+	/// `to`. The transfer is rejected if it returns an error.
+	fn pre_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// Called after `amount` of `currency_id` was transferred from `from` to
+	/// `to`.
+	fn post_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, amount: Balance);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<AccountId, CurrencyId: Copy, Balance: Copy> TransferHooks<AccountId, CurrencyId, Balance> for Tuple {
+	fn pre_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult {
+		for_tuples!( #( Tuple::pre_transfer(currency_id, from, to, amount)?; )* );
+		Ok(())
+	}
+
+	fn post_transfer(currency_id: CurrencyId, from: &AccountId, to: &AccountId, amount: Balance) {
+		for_tuples!( #( Tuple::post_transfer(currency_id, from, to, amount); )* );
+	}
+}
+
 pub trait TransferAll<AccountId> {
 	fn transfer_all(source: &AccountId, dest: &AccountId) -> DispatchResult;
 }
@@ -11,10 +11,11 @@ use sp_std::{
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-pub use auction::{Auction, AuctionHandler, AuctionInfo, OnNewBidResult};
+pub use auction::{Auction, AuctionHandler, AuctionInfo, AuctionParams, OnNewBidResult};
 pub use currency::{
 	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency,
-	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency, OnDust,
+	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
+	NamedMultiReservableCurrency, OnDust, TransferHooks,
 };
 pub use data_provider::{DataFeeder, DataProvider, DataProviderExtended};
 pub use get_by_key::GetByKey;
@@ -23,7 +24,7 @@ pub use nft::InspectExtended;
 pub use price::{DefaultPriceProvider, PriceProvider};
 pub use rewards::RewardHandler;
 use scale_info::TypeInfo;
-pub use xcm_transfer::XcmTransfer;
+pub use xcm_transfer::{XcmTransfer, XcmTransferEstimation, XcmTransferHop};
 
 pub mod arithmetic;
 pub mod auction;
@@ -1,6 +1,11 @@
+use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
 use frame_support::weights::Weight;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
 use xcm::latest::prelude::*;
+use xcm::{VersionedMultiAsset, VersionedMultiLocation, VersionedXcm};
 
 /// Abstraction over cross-chain token transfers.
 pub trait XcmTransfer<AccountId, Balance, CurrencyId> {
@@ -21,3 +26,29 @@ pub trait XcmTransfer<AccountId, Balance, CurrencyId> {
 		dest_weight: Weight,
 	) -> DispatchResult;
 }
+
+/// An XCM message received by a remote chain in a cross-chain transfer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct XcmTransferHop {
+	/// The receiving chain, relative to the chain sending the message.
+	pub location: VersionedMultiLocation,
+	/// The received message, with the assets as seen from the sending
+	/// chain.
+	pub message: VersionedXcm<()>,
+	/// The estimated weight of the message.
+	pub weight: Weight,
+	/// The estimated fee for `weight`, `None` if the fee asset can't be
+	/// priced.
+	pub fee: Option<VersionedMultiAsset>,
+}
+
+/// The XCM messages and estimated costs of a cross-chain transfer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct XcmTransferEstimation {
+	/// The message executed locally.
+	pub local_message: VersionedXcm<()>,
+	/// The weight of executing `local_message`.
+	pub local_weight: Weight,
+	/// The messages received by remote chains, in the order they are sent.
+	pub hops: Vec<XcmTransferHop>,
+}
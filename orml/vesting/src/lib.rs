@@ -9,17 +9,32 @@
 //! ### Vesting Schedule
 //!
 //! The schedule of a vesting is described by data structure `VestingSchedule`:
-//! from the block number of `start`, for every `period` amount of blocks,
-//! `per_period` amount of balance would unlocked, until number of periods
-//! `period_count` reached. Note in vesting schedules, *time* is measured by
-//! block number. All `VestingSchedule`s under an account could be queried in
-//! chain state.
+//! - `Linear`: from the block number of `start`, for every `period` amount of
+//!   blocks, `per_period` amount of balance would unlocked, until number of
+//!   periods `period_count` reached.
+//! - `CliffLinear`: like `Linear`, but nothing is unlocked before the `cliff`
+//!   block.
+//! - `Stepped`: each `(block, amount)` step unlocks `amount` at `block`.
+//!
+//! Note in vesting schedules, *time* is measured by block number. All
+//! `VestingGrant`s under an account could be queried in chain state. A grant
+//! with a `grantor` can be revoked by the grantor, returning the unvested
+//! remainder.
 //!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
 //!
 //! - `vested_transfer` - Add a new vesting schedule for an account.
+//! - `revocable_vested_transfer` - Add a new vesting schedule for an account,
+//!   revocable by the sender.
+//! - `revoke` - Revoke a vesting schedule, grantor required.
+//! - `consent_schedule_transfer` - Consent to receive a vesting schedule
+//!   transfer.
+//! - `transfer_schedule` - Transfer a vesting schedule to a consenting
+//!   account.
+//! - `split_schedule` - Split a vesting schedule in two.
+//! - `merge_schedules` - Merge two vesting schedules.
 //! - `claim` - Claim unlocked balances.
 //! - `update_vesting_schedules` - Update all vesting schedules under an
 //!   account, `root` origin required.
@@ -27,11 +42,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use codec::{HasCompact, MaxEncodedLen};
+use codec::HasCompact;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, EnsureOrigin, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, WithdrawReasons},
+	traits::{
+		Currency, EnsureOrigin, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, StorageVersion,
+		WithdrawReasons,
+	},
 	transactional, BoundedVec,
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
@@ -45,6 +63,7 @@ use sp_std::{
 	vec::Vec,
 };
 
+pub mod migrations;
 mod mock;
 mod tests;
 mod weights;
@@ -54,35 +73,88 @@ pub use weights::WeightInfo;
 
 pub const VESTING_LOCK_ID: LockIdentifier = *b"ormlvest";
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 /// The vesting schedule.
 ///
-/// Benefits would be granted gradually, `per_period` amount every `period`
-/// of blocks after `start`.
-#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub struct VestingSchedule<BlockNumber, Balance: HasCompact> {
-	/// Vesting starting block
-	pub start: BlockNumber,
-	/// Number of blocks between vest
-	pub period: BlockNumber,
-	/// Number of vest
-	pub period_count: u32,
-	/// Amount of tokens to release per vest
-	#[codec(compact)]
-	pub per_period: Balance,
+/// Describes how the benefits of a vesting are unlocked over time.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum VestingSchedule<BlockNumber, Balance: HasCompact> {
+	/// Benefits would be granted gradually, `per_period` amount every
+	/// `period` of blocks after `start`.
+	Linear {
+		/// Vesting starting block
+		start: BlockNumber,
+		/// Number of blocks between vest
+		period: BlockNumber,
+		/// Number of vest
+		period_count: u32,
+		/// Amount of tokens to release per vest
+		#[codec(compact)]
+		per_period: Balance,
+	},
+	/// Like `Linear`, but nothing is granted before the `cliff` block, when
+	/// all the periods passed are granted at once.
+	CliffLinear {
+		/// Vesting starting block
+		start: BlockNumber,
+		/// The block before which nothing is granted
+		cliff: BlockNumber,
+		/// Number of blocks between vest
+		period: BlockNumber,
+		/// Number of vest
+		period_count: u32,
+		/// Amount of tokens to release per vest
+		#[codec(compact)]
+		per_period: Balance,
+	},
+	/// Benefits would be granted in steps, each `(block, amount)` step grants
+	/// `amount` at `block`. The steps are sorted by block.
+	Stepped {
+		/// The milestones of the vesting
+		steps: Vec<(BlockNumber, Balance)>,
+	},
 }
 
 impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + Copy> VestingSchedule<BlockNumber, Balance> {
 	/// Returns the end of all periods, `None` if calculation overflows.
 	pub fn end(&self) -> Option<BlockNumber> {
-		// period * period_count + start
-		self.period
-			.checked_mul(&self.period_count.into())?
-			.checked_add(&self.start)
+		match self {
+			VestingSchedule::Linear {
+				start,
+				period,
+				period_count,
+				..
+			} => linear_end(*start, *period, *period_count),
+			VestingSchedule::CliffLinear {
+				start,
+				cliff,
+				period,
+				period_count,
+				..
+			} => linear_end(*start, *period, *period_count).map(|end| end.max(*cliff)),
+			VestingSchedule::Stepped { steps } => steps.last().map(|(block, _)| *block),
+		}
 	}
 
 	/// Returns all locked amount, `None` if calculation overflows.
 	pub fn total_amount(&self) -> Option<Balance> {
-		self.per_period.checked_mul(&self.period_count.into())
+		match self {
+			VestingSchedule::Linear {
+				period_count,
+				per_period,
+				..
+			}
+			| VestingSchedule::CliffLinear {
+				period_count,
+				per_period,
+				..
+			} => per_period.checked_mul(&(*period_count).into()),
+			VestingSchedule::Stepped { steps } => steps
+				.iter()
+				.try_fold(Zero::zero(), |total: Balance, (_, amount)| total.checked_add(amount)),
+		}
 	}
 
 	/// Returns locked amount for a given `time`.
@@ -90,17 +162,249 @@ impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + Copy> VestingSche
 	/// Note this func assumes schedule is a valid one(non-zero period and
 	/// non-overflow total amount), and it should be guaranteed by callers.
 	pub fn locked_amount(&self, time: BlockNumber) -> Balance {
-		// full = (time - start) / period
-		// unrealized = period_count - full
-		// per_period * unrealized
-		let full = time
-			.saturating_sub(self.start)
-			.checked_div(&self.period)
-			.expect("ensured non-zero period; qed");
-		let unrealized = self.period_count.saturating_sub(full.unique_saturated_into());
-		self.per_period
-			.checked_mul(&unrealized.into())
-			.expect("ensured non-overflow total amount; qed")
+		match self {
+			VestingSchedule::Linear {
+				start,
+				period,
+				period_count,
+				per_period,
+			} => linear_locked_amount(*start, *period, *period_count, *per_period, time),
+			VestingSchedule::CliffLinear {
+				start,
+				cliff,
+				period,
+				period_count,
+				per_period,
+			} => {
+				if time < *cliff {
+					linear_locked_amount(*start, *period, *period_count, *per_period, *start)
+				} else {
+					linear_locked_amount(*start, *period, *period_count, *per_period, time)
+				}
+			}
+			VestingSchedule::Stepped { steps } => steps
+				.iter()
+				.filter(|(block, _)| *block > time)
+				.fold(Zero::zero(), |locked: Balance, (_, amount)| {
+					locked.saturating_add(*amount)
+				}),
+		}
+	}
+
+	/// Split `amount` of the total amount off into a new schedule, returns the
+	/// rest and the new schedule. Their locked amounts always add up to the
+	/// locked amount of `self`.
+	///
+	/// `Linear` and `CliffLinear` schedules are split by `per_period`, so
+	/// `amount` must be a multiple of `period_count`. `Stepped` schedules are
+	/// split from the last steps. Returns `None` if `amount` is zero, not less
+	/// than the total amount or can't be split exactly.
+	pub fn split(&self, amount: Balance) -> Option<(Self, Self)> {
+		if amount.is_zero() || amount >= self.total_amount()? {
+			return None;
+		}
+		match self {
+			VestingSchedule::Linear {
+				start,
+				period,
+				period_count,
+				per_period,
+			} => {
+				let split_per_period = split_per_period(amount, *period_count)?;
+				Some((
+					VestingSchedule::Linear {
+						start: *start,
+						period: *period,
+						period_count: *period_count,
+						per_period: per_period.saturating_sub(split_per_period),
+					},
+					VestingSchedule::Linear {
+						start: *start,
+						period: *period,
+						period_count: *period_count,
+						per_period: split_per_period,
+					},
+				))
+			}
+			VestingSchedule::CliffLinear {
+				start,
+				cliff,
+				period,
+				period_count,
+				per_period,
+			} => {
+				let split_per_period = split_per_period(amount, *period_count)?;
+				Some((
+					VestingSchedule::CliffLinear {
+						start: *start,
+						cliff: *cliff,
+						period: *period,
+						period_count: *period_count,
+						per_period: per_period.saturating_sub(split_per_period),
+					},
+					VestingSchedule::CliffLinear {
+						start: *start,
+						cliff: *cliff,
+						period: *period,
+						period_count: *period_count,
+						per_period: split_per_period,
+					},
+				))
+			}
+			VestingSchedule::Stepped { steps } => {
+				let mut rest = steps.clone();
+				let mut split = Vec::new();
+				let mut remaining = amount;
+				for (block, step_amount) in rest.iter_mut().rev() {
+					if remaining.is_zero() {
+						break;
+					}
+					let taken = remaining.min(*step_amount);
+					*step_amount = step_amount.saturating_sub(taken);
+					remaining = remaining.saturating_sub(taken);
+					split.push((*block, taken));
+				}
+				rest.retain(|(_, step_amount)| !step_amount.is_zero());
+				split.reverse();
+				Some((
+					VestingSchedule::Stepped { steps: rest },
+					VestingSchedule::Stepped { steps: split },
+				))
+			}
+		}
+	}
+
+	/// Merge `self` and `other` into one schedule, its locked amount always
+	/// equals the sum of theirs. Returns `None` if the schedules have different
+	/// variants or periods, or the amounts overflow.
+	pub fn merge(&self, other: &Self) -> Option<Self> {
+		match (self, other) {
+			(
+				VestingSchedule::Linear {
+					start,
+					period,
+					period_count,
+					per_period,
+				},
+				VestingSchedule::Linear {
+					start: other_start,
+					period: other_period,
+					period_count: other_period_count,
+					per_period: other_per_period,
+				},
+			) if start == other_start && period == other_period && period_count == other_period_count => {
+				Some(VestingSchedule::Linear {
+					start: *start,
+					period: *period,
+					period_count: *period_count,
+					per_period: per_period.checked_add(other_per_period)?,
+				})
+			}
+			(
+				VestingSchedule::CliffLinear {
+					start,
+					cliff,
+					period,
+					period_count,
+					per_period,
+				},
+				VestingSchedule::CliffLinear {
+					start: other_start,
+					cliff: other_cliff,
+					period: other_period,
+					period_count: other_period_count,
+					per_period: other_per_period,
+				},
+			) if start == other_start
+				&& cliff == other_cliff
+				&& period == other_period
+				&& period_count == other_period_count =>
+			{
+				Some(VestingSchedule::CliffLinear {
+					start: *start,
+					cliff: *cliff,
+					period: *period,
+					period_count: *period_count,
+					per_period: per_period.checked_add(other_per_period)?,
+				})
+			}
+			(VestingSchedule::Stepped { steps }, VestingSchedule::Stepped { steps: other_steps }) => {
+				let mut all_steps = steps.clone();
+				all_steps.extend_from_slice(other_steps);
+				all_steps.sort_by_key(|(block, _)| *block);
+
+				let mut merged: Vec<(BlockNumber, Balance)> = Vec::with_capacity(all_steps.len());
+				for (block, amount) in all_steps {
+					match merged.last_mut() {
+						Some((last_block, last_amount)) if *last_block == block => {
+							*last_amount = last_amount.checked_add(&amount)?;
+						}
+						_ => merged.push((block, amount)),
+					}
+				}
+				Some(VestingSchedule::Stepped { steps: merged })
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Returns `amount / period_count` if `amount` is a multiple of `period_count`.
+fn split_per_period<Balance: AtLeast32Bit + Copy>(amount: Balance, period_count: u32) -> Option<Balance> {
+	let period_count: Balance = period_count.into();
+	if period_count.is_zero() || !(amount % period_count).is_zero() {
+		return None;
+	}
+	Some(amount / period_count)
+}
+
+fn linear_end<BlockNumber: AtLeast32Bit + Copy>(
+	start: BlockNumber,
+	period: BlockNumber,
+	period_count: u32,
+) -> Option<BlockNumber> {
+	// period * period_count + start
+	period.checked_mul(&period_count.into())?.checked_add(&start)
+}
+
+fn linear_locked_amount<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + Copy>(
+	start: BlockNumber,
+	period: BlockNumber,
+	period_count: u32,
+	per_period: Balance,
+	time: BlockNumber,
+) -> Balance {
+	// full = (time - start) / period
+	// unrealized = period_count - full
+	// per_period * unrealized
+	let full = time
+		.saturating_sub(start)
+		.checked_div(&period)
+		.expect("ensured non-zero period; qed");
+	let unrealized = period_count.saturating_sub(full.unique_saturated_into());
+	per_period
+		.checked_mul(&unrealized.into())
+		.expect("ensured non-overflow total amount; qed")
+}
+
+/// A vesting schedule of an account.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VestingGrant<AccountId, BlockNumber, Balance: HasCompact> {
+	/// The vesting schedule.
+	pub schedule: VestingSchedule<BlockNumber, Balance>,
+	/// The account which can revoke the unvested remainder, `None` if the
+	/// grant is irrevocable.
+	pub grantor: Option<AccountId>,
+}
+
+impl<AccountId, BlockNumber, Balance: HasCompact> From<VestingSchedule<BlockNumber, Balance>>
+	for VestingGrant<AccountId, BlockNumber, Balance>
+{
+	fn from(schedule: VestingSchedule<BlockNumber, Balance>) -> Self {
+		VestingGrant {
+			schedule,
+			grantor: None,
+		}
 	}
 }
 
@@ -111,6 +415,8 @@ pub mod module {
 	pub(crate) type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 	pub(crate) type VestingScheduleOf<T> = VestingSchedule<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+	pub(crate) type VestingGrantOf<T> =
+		VestingGrant<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
 	pub type ScheduledItem<T> = (
 		<T as frame_system::Config>::AccountId,
 		<T as frame_system::Config>::BlockNumber,
@@ -138,6 +444,10 @@ pub mod module {
 		/// The maximum vesting schedules
 		type MaxVestingSchedules: Get<u32>;
 
+		/// The maximum steps of a `Stepped` vesting schedule
+		#[pallet::constant]
+		type MaxVestingSteps: Get<u32>;
+
 		// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
 	}
@@ -156,6 +466,18 @@ pub mod module {
 		AmountLow,
 		/// Failed because the maximum vesting schedules was exceeded
 		MaxVestingSchedulesExceeded,
+		/// The steps of a vesting are empty, unsorted or too many
+		InvalidVestingSteps,
+		/// The vesting schedule does not exist
+		VestingScheduleNotFound,
+		/// The origin is not the grantor of the vesting schedule
+		NotGrantor,
+		/// The recipient has not consented to the schedule transfer
+		TransferNotConsented,
+		/// The amount can't be split off the vesting schedule exactly
+		InvalidSplitAmount,
+		/// The vesting schedules can't be merged exactly
+		CannotMergeSchedules,
 	}
 
 	#[pallet::event]
@@ -171,21 +493,53 @@ pub mod module {
 		Claimed { who: T::AccountId, amount: BalanceOf<T> },
 		/// Updated vesting schedules.
 		VestingSchedulesUpdated { who: T::AccountId },
+		/// Revoked a vesting schedule, the unvested amount is returned to the
+		/// grantor.
+		VestingScheduleRevoked {
+			grantor: T::AccountId,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A recipient consented to or refused the schedule transfers of a
+		/// sender.
+		ScheduleTransferConsentUpdated {
+			who: T::AccountId,
+			from: T::AccountId,
+			consented: bool,
+		},
+		/// Transferred a vesting schedule with its locked amount.
+		VestingScheduleTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Split a vesting schedule in two.
+		VestingScheduleSplit { who: T::AccountId, amount: BalanceOf<T> },
+		/// Merged two vesting schedules.
+		VestingSchedulesMerged { who: T::AccountId },
 	}
 
 	/// Vesting schedules of an account.
 	///
-	/// VestingSchedules: map AccountId => Vec<VestingSchedule>
+	/// VestingSchedules: map AccountId => Vec<VestingGrant>
 	#[pallet::storage]
 	#[pallet::getter(fn vesting_schedules)]
 	pub type VestingSchedules<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		BoundedVec<VestingGrantOf<T>, T::MaxVestingSchedules>,
 		ValueQuery,
 	>;
 
+	/// The consents of recipients to receive a vesting schedule transfer from
+	/// a sender. Each consent allows one transfer.
+	///
+	/// ScheduleTransferConsents: double_map AccountId, AccountId => Option<()>
+	#[pallet::storage]
+	pub type ScheduleTransferConsents<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub vesting: Vec<ScheduledItem<T>>,
@@ -206,17 +560,20 @@ pub mod module {
 				.for_each(|(who, start, period, period_count, per_period)| {
 					let mut bounded_schedules = VestingSchedules::<T>::get(who);
 					bounded_schedules
-						.try_push(VestingSchedule {
-							start: *start,
-							period: *period,
-							period_count: *period_count,
-							per_period: *per_period,
-						})
+						.try_push(
+							VestingSchedule::Linear {
+								start: *start,
+								period: *period,
+								period_count: *period_count,
+								per_period: *per_period,
+							}
+							.into(),
+						)
 						.expect("Max vesting schedules exceeded");
 					let total_amount = bounded_schedules
 						.iter()
-						.try_fold::<_, _, Result<BalanceOf<T>, DispatchError>>(Zero::zero(), |acc_amount, schedule| {
-							let amount = ensure_valid_vesting_schedule::<T>(schedule)?;
+						.try_fold::<_, _, Result<BalanceOf<T>, DispatchError>>(Zero::zero(), |acc_amount, grant| {
+							let amount = ensure_valid_vesting_schedule::<T>(&grant.schedule)?;
 							Ok(acc_amount + amount)
 						})
 						.expect("Invalid vesting schedule");
@@ -233,6 +590,7 @@ pub mod module {
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -260,7 +618,7 @@ pub mod module {
 		) -> DispatchResult {
 			let from = T::VestedTransferOrigin::ensure_origin(origin)?;
 			let to = T::Lookup::lookup(dest)?;
-			Self::do_vested_transfer(&from, &to, schedule.clone())?;
+			Self::do_vested_transfer(&from, &to, schedule.clone().into())?;
 
 			Self::deposit_event(Event::VestingScheduleAdded {
 				from,
@@ -270,11 +628,122 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Like `vested_transfer`, but the sender can revoke the unvested
+		/// remainder with `revoke`.
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		pub fn revocable_vested_transfer(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let from = T::VestedTransferOrigin::ensure_origin(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_vested_transfer(
+				&from,
+				&to,
+				VestingGrant {
+					schedule: schedule.clone(),
+					grantor: Some(from.clone()),
+				},
+			)?;
+
+			Self::deposit_event(Event::VestingScheduleAdded {
+				from,
+				to,
+				vesting_schedule: schedule,
+			});
+			Ok(())
+		}
+
+		/// Revoke the vesting schedule at `index` of `who`, the unvested
+		/// remainder is transferred back to the grantor.
+		///
+		/// The dispatch origin for this call must be `Signed` by the grantor.
+		#[pallet::weight(T::WeightInfo::revoke())]
+		pub fn revoke(origin: OriginFor<T>, who: <T::Lookup as StaticLookup>::Source, index: u32) -> DispatchResult {
+			let grantor = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let amount = Self::do_revoke(&grantor, &who, index)?;
+
+			Self::deposit_event(Event::VestingScheduleRevoked { grantor, who, amount });
+			Ok(())
+		}
+
+		/// Consent to, or refuse, receiving a vesting schedule from `from`
+		/// by `transfer_schedule`.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// recipient, or its proxy.
+		#[pallet::weight(T::WeightInfo::consent_schedule_transfer())]
+		pub fn consent_schedule_transfer(
+			origin: OriginFor<T>,
+			from: <T::Lookup as StaticLookup>::Source,
+			consented: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let from = T::Lookup::lookup(from)?;
+
+			if consented {
+				ScheduleTransferConsents::<T>::insert(&who, &from, ());
+			} else {
+				ScheduleTransferConsents::<T>::remove(&who, &from);
+			}
+			Self::deposit_event(Event::ScheduleTransferConsentUpdated { who, from, consented });
+			Ok(())
+		}
+
+		/// Transfer the vesting schedule at `index` to `dest`, together with
+		/// its locked amount. The grantor of the schedule can revoke it from
+		/// `dest`.
+		///
+		/// `dest` must have consented with `consent_schedule_transfer`.
+		#[pallet::weight(T::WeightInfo::transfer_schedule())]
+		pub fn transfer_schedule(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			index: u32,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			let amount = Self::do_transfer_schedule(&from, &to, index)?;
+
+			Self::deposit_event(Event::VestingScheduleTransferred { from, to, amount });
+			Ok(())
+		}
+
+		/// Split `amount` of the vesting schedule at `index` off into a new
+		/// schedule, appended to the schedules of the origin. The locked
+		/// amount is unchanged.
+		///
+		/// See [`VestingSchedule::split`] for the amounts can be split.
+		#[pallet::weight(T::WeightInfo::split_schedule())]
+		pub fn split_schedule(origin: OriginFor<T>, index: u32, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_split_schedule(&who, index, amount)?;
+
+			Self::deposit_event(Event::VestingScheduleSplit { who, amount });
+			Ok(())
+		}
+
+		/// Merge the vesting schedule at `other_index` into the one at
+		/// `index`. The locked amount is unchanged.
+		///
+		/// See [`VestingSchedule::merge`] for the schedules can be merged,
+		/// they must also have the same grantor.
+		#[pallet::weight(T::WeightInfo::merge_schedules())]
+		pub fn merge_schedules(origin: OriginFor<T>, index: u32, other_index: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_merge_schedules(&who, index, other_index)?;
+
+			Self::deposit_event(Event::VestingSchedulesMerged { who });
+			Ok(())
+		}
+
 		#[pallet::weight(T::WeightInfo::update_vesting_schedules(vesting_schedules.len() as u32))]
 		pub fn update_vesting_schedules(
 			origin: OriginFor<T>,
 			who: <T::Lookup as StaticLookup>::Source,
-			vesting_schedules: Vec<VestingScheduleOf<T>>,
+			vesting_schedules: Vec<VestingGrantOf<T>>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
@@ -319,8 +788,8 @@ impl<T: Config> Pallet<T> {
 		<VestingSchedules<T>>::mutate_exists(who, |maybe_schedules| {
 			let total = if let Some(schedules) = maybe_schedules.as_mut() {
 				let mut total: BalanceOf<T> = Zero::zero();
-				schedules.retain(|s| {
-					let amount = s.locked_amount(now);
+				schedules.retain(|grant| {
+					let amount = grant.schedule.locked_amount(now);
 					total = total.saturating_add(amount);
 					!amount.is_zero()
 				});
@@ -336,8 +805,8 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[transactional]
-	fn do_vested_transfer(from: &T::AccountId, to: &T::AccountId, schedule: VestingScheduleOf<T>) -> DispatchResult {
-		let schedule_amount = ensure_valid_vesting_schedule::<T>(&schedule)?;
+	fn do_vested_transfer(from: &T::AccountId, to: &T::AccountId, grant: VestingGrantOf<T>) -> DispatchResult {
+		let schedule_amount = ensure_valid_vesting_schedule::<T>(&grant.schedule)?;
 
 		let total_amount = Self::locked_balance(to)
 			.checked_add(&schedule_amount)
@@ -345,12 +814,103 @@ impl<T: Config> Pallet<T> {
 
 		T::Currency::transfer(from, to, schedule_amount, ExistenceRequirement::AllowDeath)?;
 		T::Currency::set_lock(VESTING_LOCK_ID, to, total_amount, WithdrawReasons::all());
-		<VestingSchedules<T>>::try_append(to, schedule).map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+		<VestingSchedules<T>>::try_append(to, grant).map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
 		Ok(())
 	}
 
-	fn do_update_vesting_schedules(who: &T::AccountId, schedules: Vec<VestingScheduleOf<T>>) -> DispatchResult {
-		let bounded_schedules: BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules> = schedules
+	#[transactional]
+	fn do_revoke(grantor: &T::AccountId, who: &T::AccountId, index: u32) -> Result<BalanceOf<T>, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let unvested = <VestingSchedules<T>>::try_mutate_exists(who, |maybe_schedules| -> Result<_, DispatchError> {
+			let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::VestingScheduleNotFound)?;
+			let grant = schedules
+				.get(index as usize)
+				.ok_or(Error::<T>::VestingScheduleNotFound)?;
+			ensure!(grant.grantor.as_ref() == Some(grantor), Error::<T>::NotGrantor);
+
+			let unvested = grant.schedule.locked_amount(now);
+			schedules.remove(index as usize);
+			Ok(unvested)
+		})?;
+
+		// update the lock before moving the unvested amount
+		Self::do_claim(who);
+		T::Currency::transfer(who, grantor, unvested, ExistenceRequirement::AllowDeath)?;
+		Ok(unvested)
+	}
+
+	#[transactional]
+	fn do_transfer_schedule(from: &T::AccountId, to: &T::AccountId, index: u32) -> Result<BalanceOf<T>, DispatchError> {
+		ScheduleTransferConsents::<T>::take(to, from).ok_or(Error::<T>::TransferNotConsented)?;
+
+		let grant = <VestingSchedules<T>>::try_mutate_exists(from, |maybe_schedules| -> Result<_, DispatchError> {
+			let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::VestingScheduleNotFound)?;
+			ensure!((index as usize) < schedules.len(), Error::<T>::VestingScheduleNotFound);
+			Ok(schedules.remove(index as usize))
+		})?;
+		let amount = grant
+			.schedule
+			.locked_amount(T::BlockNumberProvider::current_block_number());
+
+		// update the lock before moving the locked amount
+		Self::do_claim(from);
+		let total_amount = Self::locked_balance(to)
+			.checked_add(&amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::Currency::transfer(from, to, amount, ExistenceRequirement::AllowDeath)?;
+		T::Currency::set_lock(VESTING_LOCK_ID, to, total_amount, WithdrawReasons::all());
+		<VestingSchedules<T>>::try_append(to, grant).map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+		Ok(amount)
+	}
+
+	fn do_split_schedule(who: &T::AccountId, index: u32, amount: BalanceOf<T>) -> DispatchResult {
+		<VestingSchedules<T>>::try_mutate(who, |schedules| -> DispatchResult {
+			let grant = schedules
+				.get_mut(index as usize)
+				.ok_or(Error::<T>::VestingScheduleNotFound)?;
+			let (rest, split) = grant.schedule.split(amount).ok_or(Error::<T>::InvalidSplitAmount)?;
+			ensure_valid_vesting_schedule::<T>(&rest)?;
+			ensure_valid_vesting_schedule::<T>(&split)?;
+
+			grant.schedule = rest;
+			let split_grant = VestingGrant {
+				schedule: split,
+				grantor: grant.grantor.clone(),
+			};
+			schedules
+				.try_push(split_grant)
+				.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+			Ok(())
+		})
+	}
+
+	fn do_merge_schedules(who: &T::AccountId, index: u32, other_index: u32) -> DispatchResult {
+		<VestingSchedules<T>>::try_mutate(who, |schedules| -> DispatchResult {
+			ensure!(index != other_index, Error::<T>::CannotMergeSchedules);
+			let grant = schedules
+				.get(index as usize)
+				.ok_or(Error::<T>::VestingScheduleNotFound)?;
+			let other_grant = schedules
+				.get(other_index as usize)
+				.ok_or(Error::<T>::VestingScheduleNotFound)?;
+			ensure!(grant.grantor == other_grant.grantor, Error::<T>::CannotMergeSchedules);
+			let merged = grant
+				.schedule
+				.merge(&other_grant.schedule)
+				.ok_or(Error::<T>::CannotMergeSchedules)?;
+			ensure_valid_vesting_schedule::<T>(&merged)?;
+
+			if let Some(grant) = schedules.get_mut(index as usize) {
+				grant.schedule = merged;
+			}
+			schedules.remove(other_index as usize);
+			Ok(())
+		})
+	}
+
+	fn do_update_vesting_schedules(who: &T::AccountId, schedules: Vec<VestingGrantOf<T>>) -> DispatchResult {
+		let bounded_schedules: BoundedVec<VestingGrantOf<T>, T::MaxVestingSchedules> = schedules
 			.try_into()
 			.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
 
@@ -363,8 +923,8 @@ impl<T: Config> Pallet<T> {
 
 		let total_amount = bounded_schedules
 			.iter()
-			.try_fold::<_, _, Result<BalanceOf<T>, DispatchError>>(Zero::zero(), |acc_amount, schedule| {
-				let amount = ensure_valid_vesting_schedule::<T>(schedule)?;
+			.try_fold::<_, _, Result<BalanceOf<T>, DispatchError>>(Zero::zero(), |acc_amount, grant| {
+				let amount = ensure_valid_vesting_schedule::<T>(&grant.schedule)?;
 				Ok(acc_amount + amount)
 			})?;
 		ensure!(
@@ -381,8 +941,25 @@ impl<T: Config> Pallet<T> {
 
 /// Returns `Ok(total_total)` if valid schedule, or error.
 fn ensure_valid_vesting_schedule<T: Config>(schedule: &VestingScheduleOf<T>) -> Result<BalanceOf<T>, DispatchError> {
-	ensure!(!schedule.period.is_zero(), Error::<T>::ZeroVestingPeriod);
-	ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+	match schedule {
+		VestingSchedule::Linear {
+			period, period_count, ..
+		}
+		| VestingSchedule::CliffLinear {
+			period, period_count, ..
+		} => {
+			ensure!(!period.is_zero(), Error::<T>::ZeroVestingPeriod);
+			ensure!(!period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+		}
+		VestingSchedule::Stepped { steps } => {
+			ensure!(
+				!steps.is_empty()
+					&& steps.len() <= T::MaxVestingSteps::get() as usize
+					&& steps.windows(2).all(|w| w[0].0 < w[1].0),
+				Error::<T>::InvalidVestingSteps
+			);
+		}
+	}
 	ensure!(schedule.end().is_some(), ArithmeticError::Overflow);
 
 	let total_total = schedule.total_amount().ok_or(ArithmeticError::Overflow)?;
@@ -0,0 +1,61 @@
+//! Storage migrations for the vesting module.
+
+use crate::{BalanceOf, Config, Pallet, VestingGrantOf, VestingSchedule, VestingSchedules, STORAGE_VERSION};
+use codec::{Decode, Encode, HasCompact};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The vesting schedule before the schedules were generalised.
+#[derive(Encode, Decode)]
+struct OldVestingSchedule<BlockNumber, Balance: HasCompact> {
+	start: BlockNumber,
+	period: BlockNumber,
+	period_count: u32,
+	#[codec(compact)]
+	per_period: Balance,
+}
+
+type OldVestingScheduleOf<T> = OldVestingSchedule<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+
+/// Migrate the `VestingSchedules` to irrevocable `Linear` vesting grants.
+pub struct MigrateToVestingGrants<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToVestingGrants<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut count: Weight = 0;
+		VestingSchedules::<T>::translate::<BoundedVec<OldVestingScheduleOf<T>, T::MaxVestingSchedules>, _>(
+			|who, schedules| {
+				count = count.saturating_add(1);
+				let grants: Vec<VestingGrantOf<T>> = schedules
+					.into_iter()
+					.map(|schedule| {
+						VestingSchedule::Linear {
+							start: schedule.start,
+							period: schedule.period,
+							period_count: schedule.period_count,
+							per_period: schedule.per_period,
+						}
+						.into()
+					})
+					.collect();
+				let grants = BoundedVec::try_from(grants).ok();
+				if grants.is_none() {
+					log::warn!(target: "vesting", "Failed to migrate the vesting schedules of {:?}", who);
+				}
+				grants
+			},
+		);
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+}
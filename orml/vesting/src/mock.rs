@@ -83,6 +83,7 @@ impl EnsureOrigin<Origin> for EnsureAliceOrBob {
 
 parameter_types! {
 	pub const MaxVestingSchedule: u32 = 2;
+	pub const MaxVestingSteps: u32 = 3;
 	pub const MinVestedTransfer: u64 = 5;
 	pub static MockBlockNumberProvider: u64 = 0;
 }
@@ -102,6 +103,7 @@ impl Config for Runtime {
 	type VestedTransferOrigin = EnsureAliceOrBob;
 	type WeightInfo = ();
 	type MaxVestingSchedules = MaxVestingSchedule;
+	type MaxVestingSteps = MaxVestingSteps;
 	type BlockNumberProvider = MockBlockNumberProvider;
 }
 
@@ -21,18 +21,20 @@ fn vesting_from_chain_spec_works() {
 		assert_eq!(
 			Vesting::vesting_schedules(&CHARLIE),
 			vec![
-				VestingSchedule {
+				VestingSchedule::Linear {
 					start: 2u64,
 					period: 3u64,
 					period_count: 1u32,
 					per_period: 5u64,
-				},
-				VestingSchedule {
+				}
+				.into(),
+				VestingSchedule::Linear {
 					start: 2u64 + 3u64,
 					period: 3u64,
 					period_count: 3u32,
 					per_period: 5u64,
 				}
+				.into()
 			]
 		);
 
@@ -66,14 +68,14 @@ fn vested_transfer_works() {
 	ExtBuilder::build().execute_with(|| {
 		System::set_block_number(1);
 
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 1u32,
 			per_period: 100u64,
 		};
 		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule.clone()));
-		assert_eq!(Vesting::vesting_schedules(&BOB), vec![schedule.clone()]);
+		assert_eq!(Vesting::vesting_schedules(&BOB), vec![schedule.clone().into()]);
 		System::assert_last_event(Event::Vesting(crate::Event::VestingScheduleAdded {
 			from: ALICE,
 			to: BOB,
@@ -85,7 +87,7 @@ fn vested_transfer_works() {
 #[test]
 fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
@@ -95,7 +97,7 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 
 		MockBlockNumberProvider::set(12);
 
-		let another_schedule = VestingSchedule {
+		let another_schedule = VestingSchedule::Linear {
 			start: 10u64,
 			period: 13u64,
 			period_count: 1u32,
@@ -117,7 +119,7 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 #[test]
 fn cannot_use_fund_if_not_claimed() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 10u64,
 			period: 10u64,
 			period_count: 1u32,
@@ -131,7 +133,7 @@ fn cannot_use_fund_if_not_claimed() {
 #[test]
 fn vested_transfer_fails_if_zero_period_or_count() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 1u64,
 			period: 0u64,
 			period_count: 1u32,
@@ -142,7 +144,7 @@ fn vested_transfer_fails_if_zero_period_or_count() {
 			Error::<Runtime>::ZeroVestingPeriod
 		);
 
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 1u64,
 			period: 1u64,
 			period_count: 0u32,
@@ -158,7 +160,7 @@ fn vested_transfer_fails_if_zero_period_or_count() {
 #[test]
 fn vested_transfer_fails_if_transfer_err() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 1u64,
 			period: 1u64,
 			period_count: 1u32,
@@ -174,7 +176,7 @@ fn vested_transfer_fails_if_transfer_err() {
 #[test]
 fn vested_transfer_fails_if_overflow() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 1u64,
 			period: 1u64,
 			period_count: 2u32,
@@ -185,7 +187,7 @@ fn vested_transfer_fails_if_overflow() {
 			ArithmeticError::Overflow,
 		);
 
-		let another_schedule = VestingSchedule {
+		let another_schedule = VestingSchedule::Linear {
 			start: u64::MAX,
 			period: 1u64,
 			period_count: 2u32,
@@ -201,7 +203,7 @@ fn vested_transfer_fails_if_overflow() {
 #[test]
 fn vested_transfer_fails_if_bad_origin() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 1u32,
@@ -217,7 +219,7 @@ fn vested_transfer_fails_if_bad_origin() {
 #[test]
 fn claim_works() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
@@ -251,7 +253,7 @@ fn claim_works() {
 #[test]
 fn claim_for_works() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
@@ -284,7 +286,7 @@ fn claim_for_works() {
 #[test]
 fn update_vesting_schedules_works() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
@@ -292,7 +294,7 @@ fn update_vesting_schedules_works() {
 		};
 		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule));
 
-		let updated_schedule = VestingSchedule {
+		let updated_schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 20u64,
 			period_count: 2u32,
@@ -301,7 +303,7 @@ fn update_vesting_schedules_works() {
 		assert_ok!(Vesting::update_vesting_schedules(
 			Origin::root(),
 			BOB,
-			vec![updated_schedule]
+			vec![updated_schedule.into()]
 		));
 
 		MockBlockNumberProvider::set(11);
@@ -339,7 +341,7 @@ fn update_vesting_schedules_fails_if_unexpected_existing_locks() {
 #[test]
 fn vested_transfer_check_for_min() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 1u64,
 			period: 1u64,
 			period_count: 1u32,
@@ -355,7 +357,7 @@ fn vested_transfer_check_for_min() {
 #[test]
 fn multiple_vesting_schedule_claim_works() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
@@ -363,7 +365,7 @@ fn multiple_vesting_schedule_claim_works() {
 		};
 		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule.clone()));
 
-		let schedule2 = VestingSchedule {
+		let schedule2 = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 3u32,
@@ -371,13 +373,16 @@ fn multiple_vesting_schedule_claim_works() {
 		};
 		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule2.clone()));
 
-		assert_eq!(Vesting::vesting_schedules(&BOB), vec![schedule, schedule2.clone()]);
+		assert_eq!(
+			Vesting::vesting_schedules(&BOB),
+			vec![schedule.into(), schedule2.clone().into()]
+		);
 
 		MockBlockNumberProvider::set(21);
 
 		assert_ok!(Vesting::claim(Origin::signed(BOB)));
 
-		assert_eq!(Vesting::vesting_schedules(&BOB), vec![schedule2]);
+		assert_eq!(Vesting::vesting_schedules(&BOB), vec![schedule2.into()]);
 
 		MockBlockNumberProvider::set(31);
 
@@ -392,7 +397,7 @@ fn multiple_vesting_schedule_claim_works() {
 #[test]
 fn exceeding_maximum_schedules_should_fail() {
 	ExtBuilder::build().execute_with(|| {
-		let schedule = VestingSchedule {
+		let schedule = VestingSchedule::Linear {
 			start: 0u64,
 			period: 10u64,
 			period_count: 2u32,
@@ -405,7 +410,7 @@ fn exceeding_maximum_schedules_should_fail() {
 			Error::<Runtime>::MaxVestingSchedulesExceeded
 		);
 
-		let schedules = vec![schedule.clone(), schedule.clone(), schedule];
+		let schedules = vec![schedule.clone().into(), schedule.clone().into(), schedule.into()];
 
 		assert_noop!(
 			Vesting::update_vesting_schedules(Origin::root(), BOB, schedules),
@@ -420,7 +425,7 @@ fn cliff_vesting_works() {
 	const VESTING_PERIOD: u64 = 20;
 
 	ExtBuilder::build().execute_with(|| {
-		let cliff_schedule = VestingSchedule {
+		let cliff_schedule = VestingSchedule::Linear {
 			start: VESTING_PERIOD - 1,
 			period: 1,
 			period_count: 1,
@@ -455,3 +460,501 @@ fn cliff_vesting_works() {
 		assert_ok!(PalletBalances::transfer(Origin::signed(BOB), CHARLIE, VESTING_AMOUNT));
 	});
 }
+
+#[test]
+fn cliff_linear_vesting_works() {
+	ExtBuilder::build().execute_with(|| {
+		let schedule = VestingSchedule::CliffLinear {
+			start: 0u64,
+			cliff: 25u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 10u64,
+		};
+		assert_eq!(schedule.end(), Some(40));
+		assert_eq!(schedule.total_amount(), Some(40));
+		assert_eq!(schedule.locked_amount(0), 40);
+		assert_eq!(schedule.locked_amount(24), 40);
+		assert_eq!(schedule.locked_amount(25), 20);
+		assert_eq!(schedule.locked_amount(39), 10);
+		assert_eq!(schedule.locked_amount(40), 0);
+
+		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule));
+
+		MockBlockNumberProvider::set(24);
+		assert_ok!(Vesting::claim(Origin::signed(BOB)));
+		assert!(PalletBalances::transfer(Origin::signed(BOB), CHARLIE, 1).is_err());
+
+		MockBlockNumberProvider::set(25);
+		assert_ok!(Vesting::claim(Origin::signed(BOB)));
+		assert_ok!(PalletBalances::transfer(Origin::signed(BOB), CHARLIE, 20));
+		assert!(PalletBalances::transfer(Origin::signed(BOB), CHARLIE, 1).is_err());
+	});
+}
+
+#[test]
+fn stepped_vesting_works() {
+	ExtBuilder::build().execute_with(|| {
+		let schedule = VestingSchedule::Stepped {
+			steps: vec![(10u64, 5u64), (20, 15), (30, 10)],
+		};
+		assert_eq!(schedule.end(), Some(30));
+		assert_eq!(schedule.total_amount(), Some(30));
+		assert_eq!(schedule.locked_amount(9), 30);
+		assert_eq!(schedule.locked_amount(10), 25);
+		assert_eq!(schedule.locked_amount(29), 10);
+		assert_eq!(schedule.locked_amount(30), 0);
+
+		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule));
+		assert_eq!(
+			PalletBalances::locks(&BOB).get(0),
+			Some(&BalanceLock {
+				id: VESTING_LOCK_ID,
+				amount: 30u64,
+				reasons: Reasons::All,
+			})
+		);
+
+		MockBlockNumberProvider::set(20);
+		assert_ok!(Vesting::claim(Origin::signed(BOB)));
+		assert_eq!(
+			PalletBalances::locks(&BOB).get(0),
+			Some(&BalanceLock {
+				id: VESTING_LOCK_ID,
+				amount: 10u64,
+				reasons: Reasons::All,
+			})
+		);
+
+		MockBlockNumberProvider::set(30);
+		assert_ok!(Vesting::claim(Origin::signed(BOB)));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB));
+		assert_eq!(PalletBalances::locks(&BOB), vec![]);
+	});
+}
+
+#[test]
+fn stepped_vesting_fails_if_invalid_steps() {
+	ExtBuilder::build().execute_with(|| {
+		for steps in [
+			vec![],
+			vec![(10u64, 10u64), (10, 10)],
+			vec![(20, 10), (10, 10)],
+			vec![(10, 10), (20, 10), (30, 10), (40, 10)],
+		] {
+			assert_noop!(
+				Vesting::vested_transfer(Origin::signed(ALICE), BOB, VestingSchedule::Stepped { steps }),
+				Error::<Runtime>::InvalidVestingSteps
+			);
+		}
+		assert_noop!(
+			Vesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				VestingSchedule::Stepped {
+					steps: vec![(10, u64::MAX), (20, 1)]
+				}
+			),
+			ArithmeticError::Overflow
+		);
+	});
+}
+
+#[test]
+fn revoke_works() {
+	ExtBuilder::build().execute_with(|| {
+		let schedule = VestingSchedule::Linear {
+			start: 0u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 10u64,
+		};
+		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule.clone()));
+		assert_ok!(Vesting::revocable_vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			schedule.clone()
+		));
+		assert_eq!(
+			Vesting::vesting_schedules(&BOB),
+			vec![
+				schedule.clone().into(),
+				VestingGrant {
+					schedule: schedule.clone(),
+					grantor: Some(ALICE),
+				}
+			]
+		);
+		assert_eq!(PalletBalances::free_balance(ALICE), 20);
+
+		// irrevocable
+		assert_noop!(
+			Vesting::revoke(Origin::signed(ALICE), BOB, 0),
+			Error::<Runtime>::NotGrantor
+		);
+		assert_noop!(
+			Vesting::revoke(Origin::signed(CHARLIE), BOB, 1),
+			Error::<Runtime>::NotGrantor
+		);
+		assert_noop!(
+			Vesting::revoke(Origin::signed(ALICE), BOB, 2),
+			Error::<Runtime>::VestingScheduleNotFound
+		);
+
+		MockBlockNumberProvider::set(15);
+		assert_ok!(Vesting::revoke(Origin::signed(ALICE), BOB, 1));
+		System::assert_last_event(Event::Vesting(crate::Event::VestingScheduleRevoked {
+			grantor: ALICE,
+			who: BOB,
+			amount: 30,
+		}));
+		assert_eq!(Vesting::vesting_schedules(&BOB), vec![schedule.into()]);
+		assert_eq!(PalletBalances::free_balance(ALICE), 50);
+		assert_eq!(PalletBalances::free_balance(BOB), 50);
+		// the vested amount of the revoked schedule is unlocked
+		assert_eq!(
+			PalletBalances::locks(&BOB).get(0),
+			Some(&BalanceLock {
+				id: VESTING_LOCK_ID,
+				amount: 30u64,
+				reasons: Reasons::All,
+			})
+		);
+		assert_ok!(PalletBalances::transfer(Origin::signed(BOB), CHARLIE, 20));
+		assert!(PalletBalances::transfer(Origin::signed(BOB), CHARLIE, 1).is_err());
+	});
+}
+
+#[test]
+fn migrate_to_vesting_grants_works() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	#[derive(Encode)]
+	struct OldVestingSchedule {
+		start: u64,
+		period: u64,
+		period_count: u32,
+		#[codec(compact)]
+		per_period: u64,
+	}
+
+	ExtBuilder::build().execute_with(|| {
+		StorageVersion::new(0).put::<Vesting>();
+		frame_support::storage::unhashed::put(
+			&VestingSchedules::<Runtime>::hashed_key_for(&BOB),
+			&vec![OldVestingSchedule {
+				start: 2,
+				period: 3,
+				period_count: 4,
+				per_period: 5,
+			}],
+		);
+
+		migrations::MigrateToVestingGrants::<Runtime>::on_runtime_upgrade();
+
+		assert_eq!(Vesting::on_chain_storage_version(), 1);
+		assert_eq!(
+			Vesting::vesting_schedules(&BOB),
+			vec![VestingSchedule::Linear {
+				start: 2u64,
+				period: 3u64,
+				period_count: 4u32,
+				per_period: 5u64,
+			}
+			.into()]
+		);
+	});
+}
+
+const DAVE: AccountId = 4;
+
+fn locked(who: AccountId) -> u64 {
+	PalletBalances::locks(&who).get(0).map_or(0, |lock| lock.amount)
+}
+
+fn test_schedules() -> Vec<VestingScheduleOf<Runtime>> {
+	vec![
+		VestingSchedule::Linear {
+			start: 0u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 10u64,
+		},
+		VestingSchedule::CliffLinear {
+			start: 0u64,
+			cliff: 25u64,
+			period: 10u64,
+			period_count: 4u32,
+			per_period: 10u64,
+		},
+		VestingSchedule::Stepped {
+			steps: vec![(10u64, 5u64), (20, 15), (30, 20)],
+		},
+	]
+}
+
+#[test]
+fn split_and_merge_preserve_locked_amounts() {
+	for schedule in test_schedules() {
+		for amount in [4u64, 8, 20, 32, 36] {
+			let (rest, split) = schedule.split(amount).unwrap();
+			assert_eq!(split.total_amount(), Some(amount));
+			assert_eq!(rest.total_amount(), Some(40 - amount));
+			for time in 0..=schedule.end().unwrap() + 1 {
+				assert_eq!(
+					rest.locked_amount(time) + split.locked_amount(time),
+					schedule.locked_amount(time)
+				);
+			}
+			assert_eq!(rest.merge(&split), Some(schedule.clone()));
+			assert_eq!(split.merge(&rest), Some(schedule.clone()));
+		}
+
+		assert_eq!(schedule.split(0), None);
+		assert_eq!(schedule.split(40), None);
+		assert_eq!(schedule.split(41), None);
+	}
+
+	// linear schedules are split by period
+	assert_eq!(test_schedules()[0].split(5), None);
+	assert_eq!(test_schedules()[1].split(5), None);
+
+	// only schedules of the same shape can be merged
+	let schedules = test_schedules();
+	for (i, schedule) in schedules.iter().enumerate() {
+		for (j, other) in schedules.iter().enumerate() {
+			assert_eq!(schedule.merge(other).is_some(), i == j);
+		}
+	}
+	let other_linear = VestingSchedule::Linear {
+		start: 1u64,
+		period: 10u64,
+		period_count: 4u32,
+		per_period: 10u64,
+	};
+	assert_eq!(schedules[0].merge(&other_linear), None);
+}
+
+#[test]
+fn split_schedule_works() {
+	for schedule in test_schedules() {
+		ExtBuilder::build().execute_with(|| {
+			assert_ok!(Vesting::revocable_vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				schedule.clone()
+			));
+			assert_noop!(
+				Vesting::split_schedule(Origin::signed(BOB), 1, 20),
+				Error::<Runtime>::VestingScheduleNotFound
+			);
+			assert_noop!(
+				Vesting::split_schedule(Origin::signed(BOB), 0, 0),
+				Error::<Runtime>::InvalidSplitAmount
+			);
+			// below `MinVestedTransfer`
+			assert_noop!(
+				Vesting::split_schedule(Origin::signed(BOB), 0, 4),
+				Error::<Runtime>::AmountLow
+			);
+
+			assert_ok!(Vesting::split_schedule(Origin::signed(BOB), 0, 12));
+			System::assert_last_event(Event::Vesting(crate::Event::VestingScheduleSplit {
+				who: BOB,
+				amount: 12,
+			}));
+			let (rest, split) = schedule.split(12).unwrap();
+			assert_eq!(
+				Vesting::vesting_schedules(&BOB),
+				vec![
+					VestingGrant {
+						schedule: rest,
+						grantor: Some(ALICE),
+					},
+					VestingGrant {
+						schedule: split,
+						grantor: Some(ALICE),
+					}
+				]
+			);
+
+			// too many schedules
+			assert_noop!(
+				Vesting::split_schedule(Origin::signed(BOB), 0, 8),
+				Error::<Runtime>::MaxVestingSchedulesExceeded
+			);
+
+			for time in [0, 10, 15, 20, 25, 30, 40] {
+				MockBlockNumberProvider::set(time);
+				assert_ok!(Vesting::claim(Origin::signed(BOB)));
+				assert_eq!(locked(BOB), schedule.locked_amount(time));
+			}
+		});
+	}
+}
+
+#[test]
+fn merge_schedules_works() {
+	for schedule in test_schedules() {
+		ExtBuilder::build().execute_with(|| {
+			let (rest, split) = schedule.split(20).unwrap();
+			assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, rest.clone()));
+			assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, split));
+
+			assert_noop!(
+				Vesting::merge_schedules(Origin::signed(BOB), 0, 0),
+				Error::<Runtime>::CannotMergeSchedules
+			);
+			assert_noop!(
+				Vesting::merge_schedules(Origin::signed(BOB), 0, 2),
+				Error::<Runtime>::VestingScheduleNotFound
+			);
+
+			assert_ok!(Vesting::merge_schedules(Origin::signed(BOB), 1, 0));
+			System::assert_last_event(Event::Vesting(crate::Event::VestingSchedulesMerged { who: BOB }));
+			assert_eq!(Vesting::vesting_schedules(&BOB), vec![schedule.clone().into()]);
+
+			for time in [0, 10, 15, 20, 25, 30, 40] {
+				MockBlockNumberProvider::set(time);
+				assert_ok!(Vesting::claim(Origin::signed(BOB)));
+				assert_eq!(locked(BOB), schedule.locked_amount(time));
+			}
+		});
+	}
+
+	ExtBuilder::build().execute_with(|| {
+		let schedules = test_schedules();
+		// different shapes
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			schedules[0].clone()
+		));
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			schedules[1].clone()
+		));
+		assert_noop!(
+			Vesting::merge_schedules(Origin::signed(BOB), 0, 1),
+			Error::<Runtime>::CannotMergeSchedules
+		);
+	});
+
+	ExtBuilder::build().execute_with(|| {
+		let schedule = test_schedules()[0].clone();
+		// different grantors
+		assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, schedule.clone()));
+		assert_ok!(Vesting::revocable_vested_transfer(Origin::signed(ALICE), BOB, schedule));
+		assert_noop!(
+			Vesting::merge_schedules(Origin::signed(BOB), 0, 1),
+			Error::<Runtime>::CannotMergeSchedules
+		);
+	});
+}
+
+#[test]
+fn transfer_schedule_requires_consent() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			test_schedules()[0].clone()
+		));
+		assert_noop!(
+			Vesting::transfer_schedule(Origin::signed(BOB), DAVE, 0),
+			Error::<Runtime>::TransferNotConsented
+		);
+
+		assert_ok!(Vesting::consent_schedule_transfer(Origin::signed(DAVE), BOB, true));
+		System::assert_last_event(Event::Vesting(crate::Event::ScheduleTransferConsentUpdated {
+			who: DAVE,
+			from: BOB,
+			consented: true,
+		}));
+		assert_ok!(Vesting::consent_schedule_transfer(Origin::signed(DAVE), BOB, false));
+		assert_noop!(
+			Vesting::transfer_schedule(Origin::signed(BOB), DAVE, 0),
+			Error::<Runtime>::TransferNotConsented
+		);
+
+		assert_ok!(Vesting::consent_schedule_transfer(Origin::signed(DAVE), BOB, true));
+		assert_noop!(
+			Vesting::transfer_schedule(Origin::signed(BOB), DAVE, 1),
+			Error::<Runtime>::VestingScheduleNotFound
+		);
+		assert_ok!(Vesting::transfer_schedule(Origin::signed(BOB), DAVE, 0));
+		System::assert_last_event(Event::Vesting(crate::Event::VestingScheduleTransferred {
+			from: BOB,
+			to: DAVE,
+			amount: 40,
+		}));
+
+		// the consent is used up
+		assert!(!ScheduleTransferConsents::<Runtime>::contains_key(DAVE, BOB));
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			test_schedules()[0].clone()
+		));
+		assert_noop!(
+			Vesting::transfer_schedule(Origin::signed(BOB), DAVE, 0),
+			Error::<Runtime>::TransferNotConsented
+		);
+	});
+}
+
+#[test]
+fn transfer_schedule_preserves_locked_amounts() {
+	for schedule in test_schedules() {
+		for transfer_time in [0u64, 5, 15, 25, 35, 45] {
+			ExtBuilder::build().execute_with(|| {
+				assert_ok!(Vesting::revocable_vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					schedule.clone()
+				));
+				assert_ok!(Vesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					test_schedules()[0].clone()
+				));
+
+				MockBlockNumberProvider::set(transfer_time);
+				assert_ok!(Vesting::consent_schedule_transfer(Origin::signed(DAVE), BOB, true));
+				assert_ok!(Vesting::transfer_schedule(Origin::signed(BOB), DAVE, 0));
+
+				let transferred = schedule.locked_amount(transfer_time);
+				assert_eq!(PalletBalances::free_balance(DAVE), transferred);
+				assert_eq!(PalletBalances::free_balance(BOB), 80 - transferred);
+
+				for time in transfer_time..=45 {
+					MockBlockNumberProvider::set(time);
+					assert_ok!(Vesting::claim(Origin::signed(BOB)));
+					assert_ok!(Vesting::claim(Origin::signed(DAVE)));
+					assert_eq!(locked(BOB), test_schedules()[0].locked_amount(time));
+					assert_eq!(locked(DAVE), schedule.locked_amount(time));
+				}
+			});
+		}
+	}
+}
+
+#[test]
+fn transferred_schedule_can_be_revoked() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(Vesting::revocable_vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			test_schedules()[0].clone()
+		));
+		assert_ok!(Vesting::consent_schedule_transfer(Origin::signed(DAVE), BOB, true));
+		assert_ok!(Vesting::transfer_schedule(Origin::signed(BOB), DAVE, 0));
+
+		MockBlockNumberProvider::set(15);
+		assert_ok!(Vesting::revoke(Origin::signed(ALICE), DAVE, 0));
+		assert_eq!(PalletBalances::free_balance(ALICE), 90);
+		assert_eq!(PalletBalances::free_balance(DAVE), 10);
+		assert_eq!(locked(DAVE), 0);
+	});
+}
@@ -0,0 +1,38 @@
+//! Runtime API definition for xtokens module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+// The `unnecessary_mut_passed` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError;
+use xcm::{VersionedMultiAsset, VersionedMultiLocation};
+
+pub use orml_traits::{XcmTransferEstimation, XcmTransferHop};
+
+sp_api::decl_runtime_apis! {
+	pub trait XtokensApi<CurrencyId, Balance> where
+		CurrencyId: Codec,
+		Balance: Codec,
+	{
+		/// Estimate the `transfer` call without dispatching it.
+		fn estimate_transfer(
+			currency_id: CurrencyId,
+			amount: Balance,
+			dest: VersionedMultiLocation,
+			dest_weight: Weight,
+		) -> Result<XcmTransferEstimation, DispatchError>;
+
+		/// Estimate the `transfer_multiasset_with_fee` call without dispatching
+		/// it.
+		fn estimate_transfer_multiasset_with_fee(
+			asset: VersionedMultiAsset,
+			fee: VersionedMultiAsset,
+			dest: VersionedMultiLocation,
+			dest_weight: Weight,
+		) -> Result<XcmTransferEstimation, DispatchError>;
+	}
+}
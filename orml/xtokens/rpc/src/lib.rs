@@ -0,0 +1,191 @@
+//! RPC interface for the xtokens module.
+
+use std::sync::Arc;
+
+use codec::{Codec, Decode, Encode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use self::gen_client::Client as XtokensClient;
+pub use orml_xtokens_rpc_runtime_api::{XcmTransferEstimation, XcmTransferHop, XtokensApi as XtokensRuntimeApi};
+
+/// An [`XcmTransferHop`] with the XCM values SCALE encoded.
+#[derive(Eq, PartialEq, Encode, Decode, Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferHop {
+	pub location: Bytes,
+	pub message: Bytes,
+	pub weight: u64,
+	pub fee: Option<Bytes>,
+}
+
+/// An [`XcmTransferEstimation`] with the XCM values SCALE encoded.
+#[derive(Eq, PartialEq, Encode, Decode, Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferEstimation {
+	pub local_message: Bytes,
+	pub local_weight: u64,
+	pub hops: Vec<TransferHop>,
+}
+
+impl From<XcmTransferEstimation> for TransferEstimation {
+	fn from(estimation: XcmTransferEstimation) -> Self {
+		Self {
+			local_message: estimation.local_message.encode().into(),
+			local_weight: estimation.local_weight,
+			hops: estimation
+				.hops
+				.into_iter()
+				.map(|hop| TransferHop {
+					location: hop.location.encode().into(),
+					message: hop.message.encode().into(),
+					weight: hop.weight,
+					fee: hop.fee.map(|fee| fee.encode().into()),
+				})
+				.collect(),
+		}
+	}
+}
+
+#[rpc]
+pub trait XtokensApi<BlockHash, CurrencyId, Balance> {
+	/// Validates the `transfer` call and returns its XCM messages with the
+	/// estimated weights and fees, without dispatching it. `dest` is the
+	/// SCALE encoded `VersionedMultiLocation`.
+	#[rpc(name = "xtokens_estimateTransfer")]
+	fn estimate_transfer(
+		&self,
+		currency_id: CurrencyId,
+		amount: Balance,
+		dest: Bytes,
+		dest_weight: u64,
+		at: Option<BlockHash>,
+	) -> Result<TransferEstimation>;
+
+	/// Validates the `transfer_multiasset_with_fee` call and returns its XCM
+	/// messages with the estimated weights and fees, without dispatching it.
+	/// `asset` and `fee` are the SCALE encoded `VersionedMultiAsset`, `dest`
+	/// is the SCALE encoded `VersionedMultiLocation`.
+	#[rpc(name = "xtokens_estimateTransferMultiassetWithFee")]
+	fn estimate_transfer_multiasset_with_fee(
+		&self,
+		asset: Bytes,
+		fee: Bytes,
+		dest: Bytes,
+		dest_weight: u64,
+		at: Option<BlockHash>,
+	) -> Result<TransferEstimation>;
+}
+
+/// A struct that implements the [`XtokensApi`].
+pub struct Xtokens<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Xtokens<C, B> {
+	/// Create new `Xtokens` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Xtokens {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The XCM values were not decodable.
+	DecodeError,
+	/// The transfer is invalid.
+	InvalidTransfer,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+			Error::InvalidTransfer => 3,
+		}
+	}
+}
+
+fn decode<T: Decode>(encoded: &Bytes) -> Result<T> {
+	Decode::decode(&mut &encoded[..]).map_err(|e| RpcError {
+		code: ErrorCode::ServerError(Error::DecodeError.into()),
+		message: "Unable to decode XCM value.".into(),
+		data: Some(format!("{:?}", e).into()),
+	})
+}
+
+fn into_rpc_result<E1: std::fmt::Debug, E2: std::fmt::Debug>(
+	result: std::result::Result<std::result::Result<XcmTransferEstimation, E2>, E1>,
+) -> Result<TransferEstimation> {
+	result
+		.map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to estimate transfer.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?
+		.map(Into::into)
+		.map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::InvalidTransfer.into()),
+			message: "Invalid transfer.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+}
+
+impl<C, Block, CurrencyId, Balance> XtokensApi<<Block as BlockT>::Hash, CurrencyId, Balance> for Xtokens<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: XtokensRuntimeApi<Block, CurrencyId, Balance>,
+	CurrencyId: Codec,
+	Balance: Codec,
+{
+	fn estimate_transfer(
+		&self,
+		currency_id: CurrencyId,
+		amount: Balance,
+		dest: Bytes,
+		dest_weight: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<TransferEstimation> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+		into_rpc_result(api.estimate_transfer(&at, currency_id, amount, decode(&dest)?, dest_weight))
+	}
+
+	fn estimate_transfer_multiasset_with_fee(
+		&self,
+		asset: Bytes,
+		fee: Bytes,
+		dest: Bytes,
+		dest_weight: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<TransferEstimation> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+		into_rpc_result(api.estimate_transfer_multiasset_with_fee(
+			&at,
+			decode(&asset)?,
+			decode(&fee)?,
+			decode(&dest)?,
+			dest_weight,
+		))
+	}
+}
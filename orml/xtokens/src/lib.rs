@@ -16,32 +16,73 @@
 //!
 //! - `transfer`: Transfer local assets with given `CurrencyId` and `Amount`.
 //! - `transfer_multiasset`: Transfer `MultiAsset` assets.
+//!
+//! - `claim_assets`: Claim the assets of a failed transfer which are trapped on
+//!   the chain they were sent to.
+//! - `force_claim_assets`: Claim the assets of a timed out transfer, by
+//!   `ForceClaimOrigin`.
+//!
+//! ### Transfer tracking
+//!
+//! If `TransferReportWeight` is set, each transfer is assigned a query id and
+//! the receiving chains are asked to report the result of their execution by
+//! `ReportError`, and the assets left in their holding by `QueryHolding`. The
+//! reports are handled by the `OnResponse` implementation of the pallet, which
+//! should be used as the `ResponseHandler` of the XCM executor, and update the
+//! transfer in `Transfers`. A `TransferDeposit` is reserved from the sender
+//! for each tracked transfer, and returned when the transfer completes or its
+//! assets are claimed.
+//!
+//! The claims are built from the assets tracked for the first hop: the sent
+//! assets, replaced by the holding reported by the first hop if any. Since the
+//! asset trap of the first hop is shared by all the senders of this chain, the
+//! claimed assets are never taken from the caller.
+//!
+//! Note the reports are requested after `BuyExecution`, so a transfer failing
+//! to buy execution stays `Pending`, and can only be claimed by
+//! `ForceClaimOrigin` after `TransferTimeout`. The report instructions are
+//! charged from `dest_weight`.
+//!
+//! ### Estimations
+//!
+//! - `estimate_transfer`, `estimate_transfer_multiasset_with_fee`: Validate a
+//!   transfer and build its XCM messages without dispatching it, with the
+//!   estimated weight and fee of each message. Exposed by `XtokensApi`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::from_over_into)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::large_enum_variant)]
 
-use frame_support::{log, pallet_prelude::*, require_transactional, traits::Get, transactional, Parameter};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	require_transactional,
+	traits::{Currency, Get, ReservableCurrency},
+	transactional, Parameter,
+};
 use frame_system::{ensure_signed, pallet_prelude::*};
+use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, Convert, MaybeSerializeDeserialize, Member, Zero},
-	DispatchError,
+	traits::{AtLeast32BitUnsigned, Convert, MaybeSerializeDeserialize, Member, Saturating, Zero},
+	DispatchError, RuntimeDebug,
 };
 use sp_std::{prelude::*, result::Result};
 
-use xcm::prelude::*;
-use xcm_executor::traits::{InvertLocation, WeightBounds};
+use xcm::{latest::QueryId, prelude::*};
+use xcm_executor::traits::{InvertLocation, OnResponse, WeightBounds, WeightTrader};
 
 pub use module::*;
 use orml_traits::{
 	location::{Parse, Reserve},
-	XcmTransfer,
+	XcmTransfer, XcmTransferEstimation, XcmTransferHop,
 };
 
 mod mock;
 mod tests;
 
+pub type DepositBalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 enum TransferKind {
 	/// Transfer self reserve asset.
 	SelfReserveAsset,
@@ -52,6 +93,40 @@ enum TransferKind {
 }
 use TransferKind::*;
 
+/// The status of a tracked transfer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum TransferStatus {
+	/// Waiting for the report of the destination chain.
+	Pending,
+	/// The execution failed on `location`, the assets may be trapped there.
+	Failed {
+		location: MultiLocation,
+		index: u32,
+		error: XcmError,
+	},
+}
+
+/// A transfer tracked by a query id.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TransferInfo<AccountId, Balance, BlockNumber> {
+	/// The sender of the transfer.
+	pub sender: AccountId,
+	/// The chain receiving the assets from this chain, where they are trapped
+	/// if its execution fails.
+	pub first_hop: MultiLocation,
+	/// The assets which may be trapped on the first hop, as seen by the first
+	/// hop.
+	pub assets: MultiAssets,
+	/// The destination chain.
+	pub dest: MultiLocation,
+	/// The deposit reserved from the sender.
+	pub deposit: Balance,
+	/// The block number when the transfer was sent.
+	pub sent_at: BlockNumber,
+	/// The status of the transfer.
+	pub status: TransferStatus,
+}
+
 #[frame_support::pallet]
 pub mod module {
 
@@ -102,6 +177,29 @@ pub mod module {
 		/// The maximum number of distinct assets allowed to be transferred in a
 		/// single helper extrinsic.
 		type MaxAssetsForTransfer: Get<usize>;
+
+		/// The means of sending the asset claims.
+		type XcmSender: SendXcm;
+
+		/// The maximum weight of the transfer reports requested from the
+		/// receiving chains, `None` to not track transfers.
+		#[pallet::constant]
+		type TransferReportWeight: Get<Option<Weight>>;
+
+		/// The number of blocks after which a `Pending` transfer can be
+		/// claimed by `ForceClaimOrigin`.
+		#[pallet::constant]
+		type TransferTimeout: Get<Self::BlockNumber>;
+
+		/// The currency of the transfer deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the sender for each tracked transfer.
+		#[pallet::constant]
+		type TransferDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// The origin which may claim the assets of timed out transfers.
+		type ForceClaimOrigin: EnsureOrigin<Self::Origin>;
 	}
 
 	#[pallet::event]
@@ -147,6 +245,27 @@ pub mod module {
 			assets: MultiAssets,
 			dest: MultiLocation,
 		},
+		/// A transfer is tracked by `query_id`.
+		TransferTracked {
+			query_id: QueryId,
+			sender: T::AccountId,
+			dest: MultiLocation,
+		},
+		/// A tracked transfer completed.
+		TransferCompleted { query_id: QueryId },
+		/// A tracked transfer failed on `location`.
+		TransferFailed {
+			query_id: QueryId,
+			location: MultiLocation,
+			error: XcmError,
+		},
+		/// The trapped assets of a transfer were claimed.
+		AssetsClaimed {
+			query_id: QueryId,
+			sender: T::AccountId,
+			location: MultiLocation,
+			assets: MultiAssets,
+		},
 	}
 
 	#[pallet::error]
@@ -186,8 +305,37 @@ pub mod module {
 		TooManyAssetsBeingSent,
 		/// The specified index does not exist in a MultiAssets struct
 		AssetIndexNonExistent,
+		/// The tracked transfer does not exist
+		TransferNotFound,
+		/// The origin is not the sender of the transfer
+		NotSender,
+		/// The transfer is not failed on its first hop or timed out, or no
+		/// assets are trapped on its first hop
+		CannotClaim,
+		/// Sending the claim failed
+		SendFailure,
 	}
 
+	/// The next query id of the tracked transfers.
+	///
+	/// NextQueryId: QueryId
+	#[pallet::storage]
+	#[pallet::getter(fn next_query_id)]
+	pub type NextQueryId<T: Config> = StorageValue<_, QueryId, ValueQuery>;
+
+	/// The tracked transfers.
+	///
+	/// Transfers: map QueryId => Option<TransferInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn transfers)]
+	pub type Transfers<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		QueryId,
+		TransferInfo<T::AccountId, DepositBalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
 
@@ -394,9 +542,92 @@ pub mod module {
 
 			Self::do_transfer_multiassets(who, assets.clone(), fee.clone(), dest, dest_weight, true)
 		}
+
+		/// Claim the assets of a tracked transfer trapped on its first hop,
+		/// and deposit them to the sender's account there.
+		///
+		/// The origin must be the sender, and the transfer must have failed on
+		/// its first hop. The first of the tracked assets pays for the
+		/// execution of `dest_weight`.
+		#[pallet::weight(Pallet::<T>::weight_of_claim_assets())]
+		#[transactional]
+		pub fn claim_assets(origin: OriginFor<T>, query_id: QueryId, dest_weight: Weight) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let transfer = Self::transfers(query_id).ok_or(Error::<T>::TransferNotFound)?;
+			ensure!(transfer.sender == who, Error::<T>::NotSender);
+			ensure!(
+				matches!(&transfer.status, TransferStatus::Failed { location, .. } if *location == transfer.first_hop),
+				Error::<T>::CannotClaim
+			);
+			Self::do_claim_assets(query_id, transfer, dest_weight)
+		}
+
+		/// Claim the assets of a tracked transfer `Pending` for
+		/// `TransferTimeout` blocks, and deposit them to the sender's account
+		/// on its first hop.
+		///
+		/// The dispatch origin of this call must be `ForceClaimOrigin`.
+		#[pallet::weight(Pallet::<T>::weight_of_claim_assets())]
+		#[transactional]
+		pub fn force_claim_assets(origin: OriginFor<T>, query_id: QueryId, dest_weight: Weight) -> DispatchResult {
+			T::ForceClaimOrigin::ensure_origin(origin)?;
+			let transfer = Self::transfers(query_id).ok_or(Error::<T>::TransferNotFound)?;
+			ensure!(
+				transfer.status == TransferStatus::Pending
+					&& frame_system::Pallet::<T>::block_number()
+						>= transfer.sent_at.saturating_add(T::TransferTimeout::get()),
+				Error::<T>::CannotClaim
+			);
+			Self::do_claim_assets(query_id, transfer, dest_weight)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		fn do_claim_assets(
+			query_id: QueryId,
+			transfer: TransferInfo<T::AccountId, DepositBalanceOf<T>, T::BlockNumber>,
+			dest_weight: Weight,
+		) -> DispatchResult {
+			let TransferInfo {
+				sender,
+				first_hop: location,
+				assets,
+				deposit,
+				..
+			} = transfer;
+			let fee = assets.get(0).ok_or(Error::<T>::CannotClaim)?.clone();
+
+			let msg = Xcm(vec![
+				ClaimAsset {
+					assets: assets.clone(),
+					ticket: Here.into(),
+				},
+				BuyExecution {
+					fees: fee,
+					weight_limit: WeightLimit::Limited(dest_weight),
+				},
+				Self::deposit_asset(
+					T::AccountIdToMultiLocation::convert(sender.clone()),
+					assets.len() as u32,
+				),
+			]);
+			T::XcmSender::send_xcm(location.clone(), msg).map_err(|error| {
+				log::error!("Failed to send claim message with {:?}", error);
+				Error::<T>::SendFailure
+			})?;
+
+			Transfers::<T>::remove(query_id);
+			T::Currency::unreserve(&sender, deposit);
+
+			Self::deposit_event(Event::<T>::AssetsClaimed {
+				query_id,
+				sender,
+				location,
+				assets,
+			});
+			Ok(())
+		}
+
 		fn do_transfer(
 			who: T::AccountId,
 			currency_id: T::CurrencyId,
@@ -567,35 +798,20 @@ pub mod module {
 			dest_weight: Weight,
 			deposit_event: bool,
 		) -> DispatchResult {
-			ensure!(
-				assets.len() <= T::MaxAssetsForTransfer::get(),
-				Error::<T>::TooManyAssetsBeingSent
-			);
-
-			// We check that all assets are valid and share the same reserve
-			for i in 0..assets.len() {
-				let asset = assets.get(i).ok_or(Error::<T>::AssetIndexNonExistent)?;
-				if !asset.is_fungible(None) {
-					return Err(Error::<T>::NotFungible.into());
-				}
-				if fungible_amount(asset).is_zero() {
-					return Ok(());
-				}
-				ensure!(
-					fee.reserve() == asset.reserve(),
-					Error::<T>::DistinctReserveForAssetAndFee
-				);
-			}
-
-			let (transfer_kind, dest, reserve, recipient) = Self::transfer_kind(&fee, &dest)?;
-			let mut msg = match transfer_kind {
-				SelfReserveAsset => {
-					Self::transfer_self_reserve_asset(assets.clone(), fee, dest.clone(), recipient, dest_weight)?
-				}
-				ToReserve => Self::transfer_to_reserve(assets.clone(), fee, dest.clone(), recipient, dest_weight)?,
-				ToNonReserve => {
-					Self::transfer_to_non_reserve(assets.clone(), fee, reserve, dest.clone(), recipient, dest_weight)?
+			let query_id = T::TransferReportWeight::get().map(|_| Self::next_query_id());
+			let (dest, first_hop, mut msg) =
+				match Self::build_transfer_message(&assets, fee, &dest, dest_weight, query_id)? {
+					Some(built) => built,
+					None => return Ok(()),
+				};
+
+			let tracked = match query_id {
+				Some(query_id) => {
+					let deposit = T::TransferDeposit::get();
+					T::Currency::reserve(&who, deposit)?;
+					Some((query_id, Self::reanchored_assets(&assets, &first_hop)?, deposit))
 				}
+				None => None,
 			};
 
 			let origin_location = T::AccountIdToMultiLocation::convert(who.clone());
@@ -607,6 +823,27 @@ pub mod module {
 					Error::<T>::XcmExecutionFailed
 				})?;
 
+			if let Some((query_id, tracked_assets, deposit)) = tracked {
+				NextQueryId::<T>::put(query_id.saturating_add(1));
+				Transfers::<T>::insert(
+					query_id,
+					TransferInfo {
+						sender: who.clone(),
+						first_hop,
+						assets: tracked_assets,
+						dest: dest.clone(),
+						deposit,
+						sent_at: frame_system::Pallet::<T>::block_number(),
+						status: TransferStatus::Pending,
+					},
+				);
+				Self::deposit_event(Event::<T>::TransferTracked {
+					query_id,
+					sender: who.clone(),
+					dest: dest.clone(),
+				});
+			}
+
 			if deposit_event {
 				Self::deposit_event(Event::<T>::TransferredMultiAssets {
 					sender: who,
@@ -618,12 +855,80 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Validate the transfer of `assets` to `dest` and build the XCM
+		/// message to execute locally.
+		///
+		/// Returns `None` if there is nothing to transfer, else the `dest`
+		/// chain location, the location of the chain receiving the message
+		/// and the message. If `report` is set, the receiving chains are
+		/// asked to report the execution result with it.
+		fn build_transfer_message(
+			assets: &MultiAssets,
+			fee: MultiAsset,
+			dest: &MultiLocation,
+			dest_weight: Weight,
+			report: Option<QueryId>,
+		) -> Result<Option<(MultiLocation, MultiLocation, Xcm<T::Call>)>, DispatchError> {
+			ensure!(
+				assets.len() <= T::MaxAssetsForTransfer::get(),
+				Error::<T>::TooManyAssetsBeingSent
+			);
+
+			// We check that all assets are valid and share the same reserve
+			for i in 0..assets.len() {
+				let asset = assets.get(i).ok_or(Error::<T>::AssetIndexNonExistent)?;
+				if !asset.is_fungible(None) {
+					return Err(Error::<T>::NotFungible.into());
+				}
+				if fungible_amount(asset).is_zero() {
+					return Ok(None);
+				}
+				ensure!(
+					fee.reserve() == asset.reserve(),
+					Error::<T>::DistinctReserveForAssetAndFee
+				);
+			}
+
+			let (transfer_kind, dest, reserve, recipient) = Self::transfer_kind(&fee, dest)?;
+			let (first_hop, msg) = match transfer_kind {
+				SelfReserveAsset => (
+					dest.clone(),
+					Self::transfer_self_reserve_asset(
+						assets.clone(),
+						fee,
+						dest.clone(),
+						recipient,
+						dest_weight,
+						report,
+					)?,
+				),
+				ToReserve => (
+					dest.clone(),
+					Self::transfer_to_reserve(assets.clone(), fee, dest.clone(), recipient, dest_weight, report)?,
+				),
+				ToNonReserve => (
+					reserve.clone(),
+					Self::transfer_to_non_reserve(
+						assets.clone(),
+						fee,
+						reserve,
+						dest.clone(),
+						recipient,
+						dest_weight,
+						report,
+					)?,
+				),
+			};
+			Ok(Some((dest, first_hop, msg)))
+		}
+
 		fn transfer_self_reserve_asset(
 			assets: MultiAssets,
 			fee: MultiAsset,
 			dest: MultiLocation,
 			recipient: MultiLocation,
 			dest_weight: Weight,
+			report: Option<QueryId>,
 		) -> Result<Xcm<T::Call>, DispatchError> {
 			Ok(Xcm(vec![
 				WithdrawAsset(assets.clone()),
@@ -631,10 +936,14 @@ pub mod module {
 					assets: All.into(),
 					max_assets: assets.len() as u32,
 					dest: dest.clone(),
-					xcm: Xcm(vec![
-						Self::buy_execution(fee, &dest, dest_weight)?,
-						Self::deposit_asset(recipient, assets.len() as u32),
-					]),
+					xcm: Self::with_report(
+						Xcm(vec![
+							Self::buy_execution(fee, &dest, dest_weight)?,
+							Self::deposit_asset(recipient, assets.len() as u32),
+						]),
+						report,
+						&dest,
+					)?,
 				},
 			]))
 		}
@@ -645,16 +954,21 @@ pub mod module {
 			reserve: MultiLocation,
 			recipient: MultiLocation,
 			dest_weight: Weight,
+			report: Option<QueryId>,
 		) -> Result<Xcm<T::Call>, DispatchError> {
 			Ok(Xcm(vec![
 				WithdrawAsset(assets.clone()),
 				InitiateReserveWithdraw {
 					assets: All.into(),
 					reserve: reserve.clone(),
-					xcm: Xcm(vec![
-						Self::buy_execution(fee, &reserve, dest_weight)?,
-						Self::deposit_asset(recipient, assets.len() as u32),
-					]),
+					xcm: Self::with_report(
+						Xcm(vec![
+							Self::buy_execution(fee, &reserve, dest_weight)?,
+							Self::deposit_asset(recipient, assets.len() as u32),
+						]),
+						report,
+						&reserve,
+					)?,
 				},
 			]))
 		}
@@ -666,6 +980,7 @@ pub mod module {
 			dest: MultiLocation,
 			recipient: MultiLocation,
 			dest_weight: Weight,
+			report: Option<QueryId>,
 		) -> Result<Xcm<T::Call>, DispatchError> {
 			let mut reanchored_dest = dest.clone();
 			if reserve == MultiLocation::parent() {
@@ -685,22 +1000,75 @@ pub mod module {
 				InitiateReserveWithdraw {
 					assets: All.into(),
 					reserve: reserve.clone(),
-					xcm: Xcm(vec![
-						Self::buy_execution(half(&fee), &reserve, dest_weight)?,
-						DepositReserveAsset {
-							assets: All.into(),
-							max_assets: assets.len() as u32,
-							dest: reanchored_dest,
-							xcm: Xcm(vec![
-								Self::buy_execution(half(&fee), &dest, dest_weight)?,
-								Self::deposit_asset(recipient, assets.len() as u32),
-							]),
-						},
-					]),
+					xcm: Self::with_report(
+						Xcm(vec![
+							Self::buy_execution(half(&fee), &reserve, dest_weight)?,
+							DepositReserveAsset {
+								assets: All.into(),
+								max_assets: assets.len() as u32,
+								dest: reanchored_dest,
+								xcm: Self::with_report(
+									Xcm(vec![
+										Self::buy_execution(half(&fee), &dest, dest_weight)?,
+										Self::deposit_asset(recipient, assets.len() as u32),
+									]),
+									report,
+									&dest,
+								)?,
+							},
+						]),
+						report,
+						&reserve,
+					)?,
 				},
 			]))
 		}
 
+		/// Ask the chain at `at` executing `xcm` to report the result to this
+		/// chain, if `report` is set.
+		fn with_report(
+			mut xcm: Xcm<()>,
+			report: Option<QueryId>,
+			at: &MultiLocation,
+		) -> Result<Xcm<()>, DispatchError> {
+			if let (Some(query_id), Some(max_response_weight)) = (report, T::TransferReportWeight::get()) {
+				let ancestry = T::LocationInverter::ancestry();
+				let dest = MultiLocation::here()
+					.reanchored(at, &ancestry)
+					.map_err(|_| Error::<T>::DestinationNotInvertible)?;
+				// `BuyExecution` must stay the first instruction after the origin is cleared.
+				xcm.0.insert(
+					1,
+					SetAppendix(Xcm(vec![
+						ReportError {
+							query_id,
+							dest: dest.clone(),
+							max_response_weight,
+						},
+						QueryHolding {
+							query_id,
+							dest,
+							assets: All.into(),
+							max_response_weight,
+						},
+					])),
+				);
+			}
+			Ok(xcm)
+		}
+
+		/// Reanchor `assets` to the view of the chain at `at`.
+		fn reanchored_assets(assets: &MultiAssets, at: &MultiLocation) -> Result<MultiAssets, DispatchError> {
+			let ancestry = T::LocationInverter::ancestry();
+			let assets = assets
+				.inner()
+				.iter()
+				.map(|asset| asset.clone().reanchored(at, &ancestry))
+				.collect::<Result<Vec<MultiAsset>, ()>>()
+				.map_err(|()| Error::<T>::CannotReanchor)?;
+			Ok(assets.into())
+		}
+
 		fn deposit_asset(recipient: MultiLocation, max_assets: u32) -> Instruction<()> {
 			DepositAsset {
 				assets: All.into(),
@@ -764,6 +1132,21 @@ pub mod module {
 
 	// weights
 	impl<T: Config> Pallet<T> {
+		/// Returns weight of tracking a transfer: the query id, the transfer
+		/// and the reserve of the deposit.
+		fn weight_of_tracking() -> Weight {
+			if T::TransferReportWeight::get().is_some() {
+				T::DbWeight::get().reads_writes(2, 3)
+			} else {
+				0
+			}
+		}
+
+		/// Returns weight of `claim_assets` and `force_claim_assets` calls.
+		fn weight_of_claim_assets() -> Weight {
+			T::BaseXcmWeight::get().saturating_add(T::DbWeight::get().reads_writes(2, 2))
+		}
+
 		/// Returns weight of `transfer_multiasset` call.
 		fn weight_of_transfer_multiasset(asset: &VersionedMultiAsset, dest: &VersionedMultiLocation) -> Weight {
 			let asset = asset.clone().try_into();
@@ -790,8 +1173,11 @@ pub mod module {
 							},
 						]),
 					};
-					return T::Weigher::weight(&mut msg)
-						.map_or(Weight::max_value(), |w| T::BaseXcmWeight::get().saturating_add(w));
+					return T::Weigher::weight(&mut msg).map_or(Weight::max_value(), |w| {
+						T::BaseXcmWeight::get()
+							.saturating_add(w)
+							.saturating_add(Self::weight_of_tracking())
+					});
 				}
 			}
 			0
@@ -858,8 +1244,11 @@ pub mod module {
 								},
 							]),
 						};
-						return T::Weigher::weight(&mut msg)
-							.map_or(Weight::max_value(), |w| T::BaseXcmWeight::get().saturating_add(w));
+						return T::Weigher::weight(&mut msg).map_or(Weight::max_value(), |w| {
+							T::BaseXcmWeight::get()
+								.saturating_add(w)
+								.saturating_add(Self::weight_of_tracking())
+						});
 					}
 				}
 			}
@@ -867,6 +1256,198 @@ pub mod module {
 		}
 	}
 
+	// estimations
+	impl<T: Config> Pallet<T> {
+		/// Estimate the `transfer` call without dispatching it.
+		///
+		/// See [`Self::estimate_transfer_multiassets`].
+		pub fn estimate_transfer<Trader: WeightTrader>(
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			dest: VersionedMultiLocation,
+			dest_weight: Weight,
+		) -> Result<XcmTransferEstimation, DispatchError> {
+			let dest: MultiLocation = dest.try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let location: MultiLocation =
+				T::CurrencyIdConvert::convert(currency_id).ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+
+			let asset: MultiAsset = (location, amount.into()).into();
+			Self::estimate_transfer_multiassets::<Trader>(vec![asset.clone()].into(), asset, dest, dest_weight)
+		}
+
+		/// Estimate the `transfer_multiasset_with_fee` call without
+		/// dispatching it.
+		///
+		/// See [`Self::estimate_transfer_multiassets`].
+		pub fn estimate_transfer_multiasset_with_fee<Trader: WeightTrader>(
+			asset: VersionedMultiAsset,
+			fee: VersionedMultiAsset,
+			dest: VersionedMultiLocation,
+			dest_weight: Weight,
+		) -> Result<XcmTransferEstimation, DispatchError> {
+			let asset: MultiAsset = asset.try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let fee: MultiAsset = fee.try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let dest: MultiLocation = dest.try_into().map_err(|()| Error::<T>::BadVersion)?;
+			if fungible_amount(&fee).is_zero() {
+				return Err(Error::<T>::FeeCannotBeZero.into());
+			}
+			if !asset.is_fungible(None) || !fee.is_fungible(None) {
+				return Err(Error::<T>::NotFungible.into());
+			}
+
+			let mut assets = MultiAssets::new();
+			assets.push(asset);
+			assets.push(fee.clone());
+			Self::estimate_transfer_multiassets::<Trader>(assets, fee, dest, dest_weight)
+		}
+
+		/// Validate the transfer of `assets` to `dest` and build its XCM
+		/// messages, without executing them.
+		///
+		/// The messages received by the reserve and destination chains are
+		/// weighed by `T::Weigher` and priced in `fee` by `Trader`, as this
+		/// chain would do. The actual weight and fee depend on the
+		/// configuration of the receiving chains.
+		pub fn estimate_transfer_multiassets<Trader: WeightTrader>(
+			assets: MultiAssets,
+			fee: MultiAsset,
+			dest: MultiLocation,
+			dest_weight: Weight,
+		) -> Result<XcmTransferEstimation, DispatchError> {
+			let mut msg = match Self::build_transfer_message(&assets, fee.clone(), &dest, dest_weight, None)? {
+				Some((_, _, msg)) => msg,
+				None => {
+					return Ok(XcmTransferEstimation {
+						local_message: VersionedXcm::from(Xcm::<()>(vec![])),
+						local_weight: 0,
+						hops: vec![],
+					})
+				}
+			};
+
+			let local_weight = T::Weigher::weight(&mut msg).map_err(|()| Error::<T>::UnweighableMessage)?;
+			let local_message = Xcm::<()>::from(msg);
+			let mut hops = vec![];
+			Self::collect_hops::<Trader>(&local_message, &assets, &fee, &mut hops);
+
+			Ok(XcmTransferEstimation {
+				local_message: VersionedXcm::from(local_message),
+				local_weight,
+				hops,
+			})
+		}
+
+		/// Collect the messages sent to remote chains by `xcm`, including the
+		/// ones they send further.
+		fn collect_hops<Trader: WeightTrader>(
+			xcm: &Xcm<()>,
+			assets: &MultiAssets,
+			fee: &MultiAsset,
+			hops: &mut Vec<XcmTransferHop>,
+		) {
+			for instruction in xcm.0.iter() {
+				let (location, received, xcm) = match instruction {
+					DepositReserveAsset { dest, xcm, .. } => (dest, ReserveAssetDeposited(assets.clone()), xcm),
+					InitiateReserveWithdraw { reserve, xcm, .. } => (reserve, WithdrawAsset(assets.clone()), xcm),
+					_ => continue,
+				};
+
+				let mut message: Xcm<T::Call> = Xcm(vec![received, ClearOrigin]);
+				message.0.extend(Xcm::<T::Call>::from(xcm.clone()).0);
+				let weight = T::Weigher::weight(&mut message).unwrap_or(Weight::max_value());
+
+				hops.push(XcmTransferHop {
+					location: VersionedMultiLocation::from(location.clone()),
+					message: VersionedXcm::from(Xcm::<()>::from(message)),
+					weight,
+					fee: Self::estimate_fee::<Trader>(fee, weight).map(Into::into),
+				});
+				Self::collect_hops::<Trader>(xcm, assets, fee, hops);
+			}
+		}
+
+		/// The amount of the `fee` asset `Trader` charges for `weight`, `None`
+		/// if `Trader` can't price it.
+		fn estimate_fee<Trader: WeightTrader>(fee: &MultiAsset, weight: Weight) -> Option<MultiAsset> {
+			let payment = MultiAsset {
+				id: fee.id.clone(),
+				fun: Fungible(u128::max_value()),
+			};
+			let mut trader = Trader::new();
+			let unused = trader.buy_weight(weight, payment.into()).ok()?;
+			// refund all the weight, so the trader takes no revenue when dropped.
+			let _ = trader.refund_weight(weight);
+
+			let unused_amount = unused.fungible.get(&fee.id).copied().unwrap_or_default();
+			Some(MultiAsset {
+				id: fee.id.clone(),
+				fun: Fungible(u128::max_value().saturating_sub(unused_amount)),
+			})
+		}
+	}
+
+	impl<T: Config> OnResponse for Pallet<T> {
+		fn expecting_response(origin: &MultiLocation, query_id: QueryId) -> bool {
+			Self::transfers(query_id).map_or(false, |transfer| match transfer.status {
+				TransferStatus::Pending => *origin == transfer.first_hop || *origin == transfer.dest,
+				// the holding report of the failed first hop
+				TransferStatus::Failed { location, .. } => *origin == location && location == transfer.first_hop,
+			})
+		}
+
+		fn on_response(origin: &MultiLocation, query_id: QueryId, response: Response, _max_weight: Weight) -> Weight {
+			let result = match response {
+				Response::ExecutionResult(result) => result,
+				Response::Assets(holding) => {
+					Transfers::<T>::mutate(query_id, |maybe_transfer| {
+						if let Some(transfer) = maybe_transfer {
+							if *origin != transfer.first_hop {
+								return;
+							}
+							match Self::reanchored_assets(&holding, &transfer.first_hop) {
+								Ok(assets) => transfer.assets = assets,
+								Err(_) => log::error!("Failed to reanchor the holding of transfer {:?}", query_id),
+							}
+						}
+					});
+					return T::DbWeight::get().reads_writes(1, 1);
+				}
+				_ => return T::DbWeight::get().reads(1),
+			};
+			Transfers::<T>::mutate_exists(query_id, |maybe_transfer| {
+				if let Some(transfer) = maybe_transfer {
+					if transfer.status != TransferStatus::Pending {
+						return;
+					}
+					match result {
+						Some((index, error)) => {
+							transfer.status = TransferStatus::Failed {
+								location: origin.clone(),
+								index,
+								error,
+							};
+							Self::deposit_event(Event::<T>::TransferFailed {
+								query_id,
+								location: origin.clone(),
+								error,
+							});
+						}
+						// the reserve of a transfer to non-reserve reports before the
+						// destination
+						None if *origin == transfer.dest => {
+							// nothing is left to claim once completed
+							T::Currency::unreserve(&transfer.sender, transfer.deposit);
+							*maybe_transfer = None;
+							Self::deposit_event(Event::<T>::TransferCompleted { query_id });
+						}
+						None => {}
+					}
+				}
+			});
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+	}
+
 	impl<T: Config> XcmTransfer<T::AccountId, T::Balance, T::CurrencyId> for Pallet<T> {
 		#[require_transactional]
 		fn transfer(
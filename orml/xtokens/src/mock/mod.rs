@@ -151,6 +151,12 @@ pub fn para_ext(para_id: u32) -> TestExternalities {
 	<parachain_info::GenesisConfig as GenesisBuild<Runtime, _>>::assimilate_storage(&parachain_info_config, &mut t)
 		.unwrap();
 
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(ALICE, 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
 	orml_tokens::GenesisConfig::<Runtime> {
 		balances: vec![(ALICE, CurrencyId::R, 1_000)],
 	}
@@ -13,14 +13,15 @@ use sp_runtime::{
 	traits::{Convert, IdentityLookup, Zero},
 	AccountId32,
 };
+use std::cell::RefCell;
 
 use cumulus_primitives_core::{ChannelStatus, GetChannelInfo, ParaId};
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
 use xcm::latest::prelude::*;
 use xcm_builder::{
-	AccountId32Aliases, AllowTopLevelPaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds, LocationInverter,
-	ParentIsDefault, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+	AccountId32Aliases, AllowKnownQueryResponses, AllowTopLevelPaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds,
+	LocationInverter, ParentIsDefault, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
 	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
 };
 use xcm_executor::{traits::WeightTrader, Assets, Config, XcmExecutor};
@@ -85,6 +86,10 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_types! {
+	pub const TokensApprovalReserveId: [u8; 8] = *b"tkn/apvl";
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -93,7 +98,12 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
+	type TransferHooks = ();
 	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type ApprovalDeposit = ExistentialDeposits;
+	type ApprovalReserveId = TokensApprovalReserveId;
 	type DustRemovalWhitelist = Everything;
 }
 
@@ -142,7 +152,11 @@ pub type LocalAssetTransactor = MultiCurrencyAdapter<
 >;
 
 pub type XcmRouter = ParachainXcmRouter<ParachainInfo>;
-pub type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<Everything>);
+pub type Barrier = (
+	TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom<Everything>,
+	AllowKnownQueryResponses<XTokens>,
+);
 
 /// A trader who believes all tokens are created equal to "weight" of any chain,
 /// which is not true, but good enough to mock the fee payment of XCM execution.
@@ -199,7 +213,7 @@ impl Config for XcmConfig {
 	type Barrier = Barrier;
 	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
 	type Trader = AllTokensAreCreatedEqualToWeight;
-	type ResponseHandler = ();
+	type ResponseHandler = XTokens;
 	type AssetTrap = PolkadotXcm;
 	type AssetClaims = PolkadotXcm;
 	type SubscriptionService = PolkadotXcm;
@@ -268,6 +282,24 @@ parameter_types! {
 	pub SelfLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(ParachainInfo::get().into())));
 	pub const BaseXcmWeight: Weight = 100_000_000;
 	pub const MaxAssetsForTransfer: usize = 2;
+	pub const TransferTimeout: u64 = 10;
+	pub const TransferDeposit: Balance = 10;
+}
+
+thread_local! {
+	static TRANSFER_REPORT_WEIGHT: RefCell<Option<Weight>> = RefCell::new(None);
+}
+
+/// Transfers are not tracked unless enabled by `set_transfer_report_weight`.
+pub struct TransferReportWeight;
+impl Get<Option<Weight>> for TransferReportWeight {
+	fn get() -> Option<Weight> {
+		TRANSFER_REPORT_WEIGHT.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_transfer_report_weight(weight: Option<Weight>) {
+	TRANSFER_REPORT_WEIGHT.with(|v| *v.borrow_mut() = weight);
 }
 
 impl orml_xtokens::Config for Runtime {
@@ -282,6 +314,12 @@ impl orml_xtokens::Config for Runtime {
 	type BaseXcmWeight = BaseXcmWeight;
 	type LocationInverter = LocationInverter<Ancestry>;
 	type MaxAssetsForTransfer = MaxAssetsForTransfer;
+	type XcmSender = XcmRouter;
+	type TransferReportWeight = TransferReportWeight;
+	type TransferTimeout = TransferTimeout;
+	type Currency = Balances;
+	type TransferDeposit = TransferDeposit;
+	type ForceClaimOrigin = EnsureRoot<AccountId>;
 }
 
 impl orml_xcm::Config for Runtime {
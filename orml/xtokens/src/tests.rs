@@ -1048,3 +1048,369 @@ fn specifying_a_non_existent_asset_index_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn estimate_transfer_to_reserve_should_work() {
+	TestNet::reset();
+
+	ParaA::execute_with(|| {
+		let estimation = ParaXTokens::estimate_transfer::<para::AllTokensAreCreatedEqualToWeight>(
+			CurrencyId::R,
+			500,
+			MultiLocation::new(
+				1,
+				X1(Junction::AccountId32 {
+					network: NetworkId::Any,
+					id: BOB.into(),
+				}),
+			)
+			.into(),
+			40,
+		)
+		.unwrap();
+
+		// WithdrawAsset, InitiateReserveWithdraw
+		assert_eq!(estimation.local_weight, 20);
+		assert_eq!(estimation.hops.len(), 1);
+		let hop = &estimation.hops[0];
+		assert_eq!(hop.location, MultiLocation::parent().into());
+		// WithdrawAsset, ClearOrigin, BuyExecution, DepositAsset
+		assert_eq!(hop.weight, 40);
+		assert_eq!(hop.fee, Some((Parent, 40).into()));
+
+		// nothing is executed
+		assert_eq!(ParaTokens::free_balance(CurrencyId::R, &ALICE), 1_000);
+	});
+}
+
+#[test]
+fn estimate_transfer_to_non_reserve_should_work() {
+	TestNet::reset();
+
+	ParaA::execute_with(|| {
+		let estimation = ParaXTokens::estimate_transfer::<para::AllTokensAreCreatedEqualToWeight>(
+			CurrencyId::B,
+			500,
+			MultiLocation::new(
+				1,
+				X2(
+					Parachain(3),
+					Junction::AccountId32 {
+						network: NetworkId::Any,
+						id: BOB.into(),
+					},
+				),
+			)
+			.into(),
+			40,
+		)
+		.unwrap();
+
+		assert_eq!(estimation.local_weight, 20);
+		assert_eq!(estimation.hops.len(), 2);
+		assert_eq!(estimation.hops[0].location, (Parent, Parachain(2)).into());
+		assert_eq!(estimation.hops[0].weight, 40);
+		assert_eq!(estimation.hops[1].location, (Parent, Parachain(3)).into());
+		assert_eq!(estimation.hops[1].weight, 40);
+	});
+}
+
+#[test]
+fn estimate_transfer_with_zero_amount_is_empty() {
+	TestNet::reset();
+
+	ParaA::execute_with(|| {
+		let estimation = ParaXTokens::estimate_transfer::<para::AllTokensAreCreatedEqualToWeight>(
+			CurrencyId::R,
+			0,
+			MultiLocation::new(
+				1,
+				X1(Junction::AccountId32 {
+					network: NetworkId::Any,
+					id: BOB.into(),
+				}),
+			)
+			.into(),
+			40,
+		)
+		.unwrap();
+		assert_eq!(estimation.local_weight, 0);
+		assert!(estimation.hops.is_empty());
+	});
+}
+
+#[test]
+fn estimate_transfer_fails_on_invalid_transfer() {
+	TestNet::reset();
+
+	ParaA::execute_with(|| {
+		assert_err!(
+			ParaXTokens::estimate_transfer::<para::AllTokensAreCreatedEqualToWeight>(
+				CurrencyId::A,
+				100,
+				MultiLocation::new(
+					1,
+					X2(
+						Parachain(1),
+						Junction::AccountId32 {
+							network: NetworkId::Any,
+							id: BOB.into(),
+						},
+					),
+				)
+				.into(),
+				40,
+			),
+			Error::<para::Runtime>::NotCrossChainTransfer
+		);
+		assert_err!(
+			ParaXTokens::estimate_transfer_multiasset_with_fee::<para::AllTokensAreCreatedEqualToWeight>(
+				(Parent, 100).into(),
+				(Parent, 0).into(),
+				MultiLocation::new(
+					1,
+					X1(Junction::AccountId32 {
+						network: NetworkId::Any,
+						id: BOB.into(),
+					}),
+				)
+				.into(),
+				40,
+			),
+			Error::<para::Runtime>::FeeCannotBeZero
+		);
+	});
+}
+
+#[test]
+fn tracked_transfer_should_complete() {
+	TestNet::reset();
+	para::set_transfer_report_weight(Some(10));
+
+	Relay::execute_with(|| {
+		let _ = RelayBalances::deposit_creating(&para_a_account(), 1_000);
+	});
+
+	ParaA::execute_with(|| {
+		assert_ok!(ParaXTokens::transfer(
+			Some(ALICE).into(),
+			CurrencyId::R,
+			500,
+			Box::new(
+				MultiLocation::new(
+					1,
+					X1(Junction::AccountId32 {
+						network: NetworkId::Any,
+						id: BOB.into(),
+					})
+				)
+				.into()
+			),
+			70,
+		));
+		para::System::assert_has_event(para::Event::XTokens(crate::Event::TransferTracked {
+			query_id: 0,
+			sender: ALICE,
+			dest: Parent.into(),
+		}));
+		assert_eq!(ParaXTokens::next_query_id(), 1);
+	});
+
+	Relay::execute_with(|| {
+		assert_eq!(RelayBalances::free_balance(&BOB), 430);
+	});
+
+	ParaA::execute_with(|| {
+		// the completed transfer is removed and its deposit returned
+		assert_eq!(ParaXTokens::transfers(0), None);
+		assert_eq!(para::Balances::reserved_balance(&ALICE), 0);
+		para::System::assert_has_event(para::Event::XTokens(crate::Event::TransferCompleted { query_id: 0 }));
+	});
+}
+
+#[test]
+fn tracked_transfer_to_non_reserve_should_complete() {
+	TestNet::reset();
+	para::set_transfer_report_weight(Some(10));
+
+	ParaA::execute_with(|| {
+		assert_ok!(ParaTokens::deposit(CurrencyId::B, &ALICE, 1_000));
+	});
+
+	ParaB::execute_with(|| {
+		assert_ok!(ParaTokens::deposit(CurrencyId::B, &sibling_a_account(), 1_000));
+	});
+
+	ParaA::execute_with(|| {
+		assert_ok!(ParaXTokens::transfer(
+			Some(ALICE).into(),
+			CurrencyId::B,
+			500,
+			Box::new(
+				MultiLocation::new(
+					1,
+					X2(
+						Parachain(3),
+						Junction::AccountId32 {
+							network: NetworkId::Any,
+							id: BOB.into(),
+						}
+					)
+				)
+				.into()
+			),
+			70
+		));
+
+		let transfer = ParaXTokens::transfers(0).unwrap();
+		assert_eq!(transfer.first_hop, (Parent, Parachain(2)).into());
+		assert_eq!(transfer.dest, (Parent, Parachain(3)).into());
+		assert_eq!(
+			transfer.assets,
+			vec![((Parent, Parachain(2), GeneralKey("B".into())), 500).into()].into()
+		);
+		assert_eq!(transfer.deposit, 10);
+		assert_eq!(para::Balances::reserved_balance(&ALICE), 10);
+	});
+
+	ParaC::execute_with(|| {
+		assert_eq!(ParaTokens::free_balance(CurrencyId::B, &BOB), 360);
+	});
+
+	ParaA::execute_with(|| {
+		assert_eq!(ParaXTokens::transfers(0), None);
+		assert_eq!(para::Balances::reserved_balance(&ALICE), 0);
+		para::System::assert_has_event(para::Event::XTokens(crate::Event::TransferCompleted { query_id: 0 }));
+	});
+}
+
+#[test]
+fn failed_transfer_assets_can_be_claimed() {
+	TestNet::reset();
+	para::set_transfer_report_weight(Some(10));
+
+	ParaA::execute_with(|| {
+		assert_ok!(ParaTokens::deposit(CurrencyId::A, &ALICE, 1_000));
+
+		// the recipient can't hold assets on the destination
+		assert_ok!(ParaXTokens::transfer(
+			Some(ALICE).into(),
+			CurrencyId::A,
+			500,
+			Box::new((Parent, Parachain(2), GeneralKey("bob".into())).into()),
+			70,
+		));
+		assert_eq!(ParaTokens::free_balance(CurrencyId::A, &sibling_b_account()), 500);
+		assert_eq!(para::Balances::reserved_balance(&ALICE), 10);
+	});
+
+	ParaA::execute_with(|| {
+		let transfer = ParaXTokens::transfers(0).unwrap();
+		assert!(matches!(
+			transfer.status,
+			TransferStatus::Failed { location, index: 4, .. } if location == (Parent, Parachain(2)).into()
+		));
+
+		// 70 is paid for execution and the rest is trapped, as reported by the
+		// first hop
+		let trapped: MultiAssets = vec![((Parent, Parachain(1), GeneralKey("A".into())), 430).into()].into();
+		assert_eq!(transfer.assets, trapped);
+
+		assert_noop!(
+			ParaXTokens::claim_assets(Some(BOB).into(), 0, 30),
+			Error::<para::Runtime>::NotSender
+		);
+		assert_noop!(
+			ParaXTokens::claim_assets(Some(ALICE).into(), 1, 30),
+			Error::<para::Runtime>::TransferNotFound
+		);
+		// only timed out transfers can be force claimed
+		assert_noop!(
+			ParaXTokens::force_claim_assets(para::Origin::root(), 0, 30),
+			Error::<para::Runtime>::CannotClaim
+		);
+		assert_ok!(ParaXTokens::claim_assets(Some(ALICE).into(), 0, 30));
+		para::System::assert_last_event(para::Event::XTokens(crate::Event::AssetsClaimed {
+			query_id: 0,
+			sender: ALICE,
+			location: (Parent, Parachain(2)).into(),
+			assets: trapped,
+		}));
+		assert_eq!(ParaXTokens::transfers(0), None);
+		assert_eq!(para::Balances::reserved_balance(&ALICE), 0);
+		assert_noop!(
+			ParaXTokens::claim_assets(Some(ALICE).into(), 0, 30),
+			Error::<para::Runtime>::TransferNotFound
+		);
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(ParaTokens::free_balance(CurrencyId::A, &ALICE), 400);
+	});
+}
+
+#[test]
+fn pending_transfer_can_be_force_claimed_after_timeout() {
+	TestNet::reset();
+	para::set_transfer_report_weight(Some(10));
+
+	ParaA::execute_with(|| {
+		assert_ok!(ParaTokens::deposit(CurrencyId::A, &ALICE, 1_000));
+
+		// the assets don't cover `dest_weight`, the execution fails before the
+		// reports are requested
+		assert_ok!(ParaXTokens::transfer(
+			Some(ALICE).into(),
+			CurrencyId::A,
+			500,
+			Box::new(
+				(
+					Parent,
+					Parachain(2),
+					Junction::AccountId32 {
+						network: NetworkId::Any,
+						id: BOB.into(),
+					},
+				)
+					.into()
+			),
+			600,
+		));
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(ParaTokens::free_balance(CurrencyId::A, &BOB), 0);
+	});
+
+	ParaA::execute_with(|| {
+		let transfer = ParaXTokens::transfers(0).unwrap();
+		assert_eq!(transfer.status, TransferStatus::Pending);
+		assert_eq!(
+			transfer.assets,
+			vec![((Parent, Parachain(1), GeneralKey("A".into())), 500).into()].into()
+		);
+
+		// a pending transfer can't be claimed by the sender
+		assert_noop!(
+			ParaXTokens::claim_assets(Some(ALICE).into(), 0, 30),
+			Error::<para::Runtime>::CannotClaim
+		);
+		assert_noop!(
+			ParaXTokens::force_claim_assets(Some(ALICE).into(), 0, 30),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ParaXTokens::force_claim_assets(para::Origin::root(), 0, 30),
+			Error::<para::Runtime>::CannotClaim
+		);
+
+		para::System::set_block_number(11);
+		assert_ok!(ParaXTokens::force_claim_assets(para::Origin::root(), 0, 30));
+		assert_eq!(ParaXTokens::transfers(0), None);
+		assert_eq!(para::Balances::reserved_balance(&ALICE), 0);
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(ParaTokens::free_balance(CurrencyId::A, &ALICE), 470);
+	});
+}